@@ -0,0 +1,161 @@
+//! # Attestation Registry
+//!
+//! Reference KYC attestation registry used by `bounty-escrow` to gate large
+//! payouts. An admin issues attestations for subject addresses; each
+//! attestation carries a verification level and an optional expiry.
+//!
+//! Escrow contracts only depend on the read-only entrypoint
+//!
+//! ```text
+//! is_verified(subject: Address, level: u32) -> bool
+//! ```
+//!
+//! so any registry exposing the same signature can be plugged in instead.
+
+#![no_std]
+
+use soroban_sdk::{
+    contract, contracterror, contractimpl, contracttype, symbol_short, Address, Env,
+};
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum Error {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    /// Returned when the expiry is not in the future
+    InvalidExpiry = 3,
+    /// Returned when revoking a subject without an attestation
+    AttestationNotFound = 4,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Attestation {
+    /// Verification level reached by the subject (higher is stronger).
+    pub level: u32,
+    pub issued_by: Address,
+    pub issued_at: u64,
+    /// Ledger timestamp after which the attestation is ignored; 0 = never.
+    pub expires_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationIssued {
+    pub subject: Address,
+    pub level: u32,
+    pub expires_at: u64,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationRevoked {
+    pub subject: Address,
+    pub timestamp: u64,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Admin,
+    Attestation(Address),
+}
+
+#[contract]
+pub struct AttestationRegistry;
+
+#[contractimpl]
+impl AttestationRegistry {
+    pub fn init(env: Env, admin: Address) -> Result<(), Error> {
+        if env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::AlreadyInitialized);
+        }
+        env.storage().instance().set(&DataKey::Admin, &admin);
+        Ok(())
+    }
+
+    /// Issue or replace the attestation for `subject` (admin only).
+    pub fn attest(env: Env, subject: Address, level: u32, expires_at: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let now = env.ledger().timestamp();
+        if expires_at != 0 && expires_at <= now {
+            return Err(Error::InvalidExpiry);
+        }
+
+        let attestation = Attestation {
+            level,
+            issued_by: admin,
+            issued_at: now,
+            expires_at,
+        };
+        env.storage()
+            .persistent()
+            .set(&DataKey::Attestation(subject.clone()), &attestation);
+
+        env.events().publish(
+            (symbol_short!("attest"), subject.clone()),
+            AttestationIssued {
+                subject,
+                level,
+                expires_at,
+                timestamp: now,
+            },
+        );
+        Ok(())
+    }
+
+    /// Remove the attestation for `subject` (admin only).
+    pub fn revoke(env: Env, subject: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let key = DataKey::Attestation(subject.clone());
+        if !env.storage().persistent().has(&key) {
+            return Err(Error::AttestationNotFound);
+        }
+        env.storage().persistent().remove(&key);
+
+        env.events().publish(
+            (symbol_short!("revoke"), subject.clone()),
+            AttestationRevoked {
+                subject,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        Ok(())
+    }
+
+    /// True when `subject` holds an unexpired attestation of at least `level`.
+    pub fn is_verified(env: Env, subject: Address, level: u32) -> bool {
+        match Self::get_attestation(env.clone(), subject) {
+            Some(attestation) => {
+                attestation.level >= level
+                    && (attestation.expires_at == 0
+                        || attestation.expires_at > env.ledger().timestamp())
+            }
+            None => false,
+        }
+    }
+
+    /// View: stored attestation for `subject`, expired or not.
+    pub fn get_attestation(env: Env, subject: Address) -> Option<Attestation> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::Attestation(subject))
+    }
+}
+
+#[cfg(test)]
+mod test;
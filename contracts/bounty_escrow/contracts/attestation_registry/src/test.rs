@@ -0,0 +1,72 @@
+#![cfg(test)]
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+
+fn setup() -> (Env, AttestationRegistryClient<'static>, Address) {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let contract_id = env.register_contract(None, AttestationRegistry);
+    let client = AttestationRegistryClient::new(&env, &contract_id);
+    client.init(&admin);
+    (env, client, admin)
+}
+
+#[test]
+fn test_init_twice_fails() {
+    let (_env, client, admin) = setup();
+    assert_eq!(client.try_init(&admin), Err(Ok(Error::AlreadyInitialized)));
+}
+
+#[test]
+fn test_attest_and_verify_levels() {
+    let (env, client, admin) = setup();
+    let subject = Address::generate(&env);
+
+    assert!(!client.is_verified(&subject, &1));
+
+    client.attest(&subject, &2, &0);
+    assert!(client.is_verified(&subject, &1));
+    assert!(client.is_verified(&subject, &2));
+    assert!(!client.is_verified(&subject, &3));
+
+    let attestation = client.get_attestation(&subject).unwrap();
+    assert_eq!(attestation.level, 2);
+    assert_eq!(attestation.issued_by, admin);
+}
+
+#[test]
+fn test_attestation_expires() {
+    let (env, client, _admin) = setup();
+    let subject = Address::generate(&env);
+    env.ledger().set_timestamp(100);
+
+    assert_eq!(
+        client.try_attest(&subject, &1, &100),
+        Err(Ok(Error::InvalidExpiry))
+    );
+
+    client.attest(&subject, &1, &200);
+    assert!(client.is_verified(&subject, &1));
+
+    env.ledger().set_timestamp(200);
+    assert!(!client.is_verified(&subject, &1));
+    assert!(client.get_attestation(&subject).is_some());
+}
+
+#[test]
+fn test_revoke() {
+    let (env, client, _admin) = setup();
+    let subject = Address::generate(&env);
+
+    assert_eq!(
+        client.try_revoke(&subject),
+        Err(Ok(Error::AttestationNotFound))
+    );
+
+    client.attest(&subject, &1, &0);
+    client.revoke(&subject);
+    assert!(!client.is_verified(&subject, &1));
+    assert_eq!(client.get_attestation(&subject), None);
+}
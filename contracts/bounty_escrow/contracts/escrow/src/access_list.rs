@@ -0,0 +1,116 @@
+//! # Participant Access Lists
+//!
+//! Admin-managed admission control for the two sides of an escrow:
+//! depositors, checked when funds are locked, and recipients, checked on
+//! every release, claim and capability payout. Each role has its own mode
+//! and its own allow and deny lists:
+//!
+//! - [`AccessMode::Open`]: anyone may take part; both lists are ignored.
+//! - [`AccessMode::AllowList`]: only addresses on the role's allow list.
+//! - [`AccessMode::DenyList`]: anyone except addresses on the role's deny
+//!   list.
+//!
+//! Rejected participants get `Error::ParticipantNotAllowed`. Lists can be
+//! filled before the mode is switched, so turning a list on is atomic.
+//!
+//! Refunds are never gated: funds always go back to the depositor they came
+//! from.
+//!
+//! ## Why recipients have two deny stores
+//!
+//! The recipient deny list here overlaps with the compliance deny-list in
+//! [`crate::deny_list`], but the two answer different questions and are kept
+//! apart on purpose:
+//!
+//! - The compliance list is a hard block. It applies whatever the access
+//!   mode, covers every outbound transfer (refund destinations included),
+//!   records a reason code for auditors, and fails with
+//!   `Error::RecipientBlocked`.
+//! - This list is admission policy. It only takes effect in
+//!   [`AccessMode::DenyList`], can be switched off wholesale by changing the
+//!   mode, and fails with `Error::ParticipantNotAllowed`.
+//!
+//! Folding one into the other would either make a policy toggle lift a
+//! sanctions block or make routine admission changes look like compliance
+//! actions, so payees are checked against both.
+
+use crate::{DataKey, Error};
+use soroban_sdk::{contracttype, Address, Env};
+
+/// Side of an escrow an access list governs.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessRole {
+    Depositor,
+    Recipient,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessMode {
+    Open,
+    AllowList,
+    DenyList,
+}
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum AccessListKind {
+    Allow,
+    Deny,
+}
+
+pub fn get_mode(env: &Env, role: AccessRole) -> AccessMode {
+    env.storage()
+        .instance()
+        .get(&DataKey::AccessMode(role))
+        .unwrap_or(AccessMode::Open)
+}
+
+pub fn set_mode(env: &Env, role: AccessRole, mode: AccessMode) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AccessMode(role), &mode);
+}
+
+pub fn is_listed(env: &Env, role: AccessRole, list: AccessListKind, address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::AccessListed(role, list, address.clone()))
+}
+
+/// Add or remove `address`. Returns whether the list changed.
+pub fn set_listed(
+    env: &Env,
+    role: AccessRole,
+    list: AccessListKind,
+    address: &Address,
+    listed: bool,
+) -> bool {
+    if is_listed(env, role, list, address) == listed {
+        return false;
+    }
+    let key = DataKey::AccessListed(role, list, address.clone());
+    if listed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+    true
+}
+
+/// Whether `address` may act as `role` under the current mode.
+pub fn is_allowed(env: &Env, role: AccessRole, address: &Address) -> bool {
+    match get_mode(env, role) {
+        AccessMode::Open => true,
+        AccessMode::AllowList => is_listed(env, role, AccessListKind::Allow, address),
+        AccessMode::DenyList => !is_listed(env, role, AccessListKind::Deny, address),
+    }
+}
+
+pub fn ensure_allowed(env: &Env, role: AccessRole, address: &Address) -> Result<(), Error> {
+    if !is_allowed(env, role, address) {
+        return Err(Error::ParticipantNotAllowed);
+    }
+    Ok(())
+}
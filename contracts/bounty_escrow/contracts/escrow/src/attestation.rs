@@ -0,0 +1,73 @@
+//! # Attestation-Gated Payouts
+//!
+//! Optional compliance gate for large bounties. When configured, every
+//! contributor payout from a bounty whose amount is at or above `threshold`
+//! requires the recipient to hold an attestation of at least
+//! `required_level` in an external registry contract (see the
+//! `attestation-registry` crate). The gate looks at the bounty's size, not
+//! the payout's, so splitting a large bounty into partial releases or
+//! partial claims does not get around it.
+//!
+//! The registry is consulted through a cross-contract call to
+//! `is_verified(subject, level) -> bool` during the CHECKS phase, before any
+//! state is written. Payouts from bounties below the threshold never call the registry.
+
+use crate::{DataKey, Error};
+use soroban_sdk::{contractclient, contracttype, Address, Env};
+
+/// Minimal interface an attestation registry must expose.
+#[allow(dead_code)]
+#[contractclient(name = "AttestationRegistryClient")]
+pub trait AttestationRegistryInterface {
+    fn is_verified(env: Env, subject: Address, level: u32) -> bool;
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationConfig {
+    /// Registry contract queried for `is_verified`.
+    pub registry: Address,
+    /// Payouts from bounties of this amount or more require an attestation.
+    pub threshold: i128,
+    /// Minimum verification level the recipient must hold.
+    pub required_level: u32,
+}
+
+pub fn get_config(env: &Env) -> Option<AttestationConfig> {
+    env.storage().instance().get(&DataKey::AttestationConfig)
+}
+
+pub fn set_config(env: &Env, config: &AttestationConfig) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AttestationConfig, config);
+}
+
+pub fn clear_config(env: &Env) {
+    env.storage().instance().remove(&DataKey::AttestationConfig);
+}
+
+/// Whether a payout to `recipient` from a bounty of `bounty_amount` passes
+/// the attestation gate.
+pub fn is_payout_attested(env: &Env, recipient: &Address, bounty_amount: i128) -> bool {
+    match get_config(env) {
+        Some(config) if bounty_amount >= config.threshold => {
+            AttestationRegistryClient::new(env, &config.registry)
+                .is_verified(recipient, &config.required_level)
+        }
+        _ => true,
+    }
+}
+
+/// Reject payouts from bounties at or above the threshold to unattested
+/// recipients.
+pub fn ensure_payout_attested(
+    env: &Env,
+    recipient: &Address,
+    bounty_amount: i128,
+) -> Result<(), Error> {
+    if !is_payout_attested(env, recipient, bounty_amount) {
+        return Err(Error::AttestationRequired);
+    }
+    Ok(())
+}
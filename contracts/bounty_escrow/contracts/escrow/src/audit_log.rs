@@ -0,0 +1,206 @@
+//! # Per-Bounty Audit Log
+//!
+//! Append-only record of every state-changing action taken on a bounty, so
+//! "who did what to bounty 42 and when" can be answered on-chain without an
+//! external indexer.
+//!
+//! Entries are numbered with a per-bounty sequence and stored in persistent
+//! pages of `AUDIT_PAGE_SIZE` entries. Retention is bounded: once a bounty
+//! holds more than `MAX_AUDIT_ENTRIES` entries, the oldest page is deleted,
+//! so each bounty keeps between `MAX_AUDIT_ENTRIES - AUDIT_PAGE_SIZE + 1`
+//! and `MAX_AUDIT_ENTRIES` of its most recent entries and storage rent stays
+//! predictable.
+//!
+//! Entries are written in the same transaction as the action they describe,
+//! so a reverted call never leaves a stale entry behind.
+
+use crate::DataKey;
+use soroban_sdk::{contracttype, Address, Env, Vec};
+
+/// Number of entries stored under a single persistent key.
+pub const AUDIT_PAGE_SIZE: u64 = 16;
+/// Upper bound on retained entries per bounty.
+pub const MAX_AUDIT_ENTRIES: u64 = 128;
+/// Upper bound on entries returned by a single `get_audit_log` call.
+pub const MAX_AUDIT_QUERY_LIMIT: u32 = 50;
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum AuditAction {
+    Lock,
+    /// An escrow carried over from the legacy contract by `import_escrows`.
+    Imported,
+    Release,
+    PartialRelease,
+    Refund,
+    RefundApproved,
+    ReleaseApproved,
+    ClaimAuthorized,
+    Claimed,
+    ClaimCancelled,
+    /// A void claim deleted by `sweep_expired_claims`.
+    ClaimExpired,
+    CapabilityIssued,
+    CapabilityRevoked,
+    MetadataUpdated,
+    /// Deadline mode replaced by `set_deadline_mode`.
+    DeadlineModeUpdated,
+    /// Contributor (the counterparty) assigned by `assign_contributor`.
+    ContributorAssigned,
+    Frozen,
+    Unfrozen,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditEntry {
+    pub action: AuditAction,
+    /// Address whose authorization performed the action; `None` for actions
+    /// anyone may trigger (a refund after the deadline, a claim sweep).
+    pub actor: Option<Address>,
+    /// Token amount moved or affected; 0 for actions without an amount.
+    pub amount: i128,
+    /// Other party of the action (recipient, delegate), if any.
+    pub counterparty: Option<Address>,
+    pub timestamp: u64,
+    pub ledger: u32,
+}
+
+/// One page of `get_audit_log` results.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuditLogPage {
+    pub entries: Vec<AuditEntry>,
+    /// Sequence number of `entries[0]`.
+    pub first_seq: u64,
+    /// Cursor for the following call, or `None` when the log is exhausted.
+    pub next_cursor: Option<u64>,
+}
+
+/// Range of sequence numbers currently retained for a bounty.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct AuditCursor {
+    /// Oldest retained sequence number.
+    pub start: u64,
+    /// Sequence number the next entry will receive.
+    pub next: u64,
+}
+
+fn get_cursor(env: &Env, bounty_id: u64) -> AuditCursor {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AuditCursor(bounty_id))
+        .unwrap_or_default()
+}
+
+fn load_page(env: &Env, bounty_id: u64, page: u64) -> Vec<AuditEntry> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AuditPage(bounty_id, page))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Append an entry performed by `actor` to the bounty's log.
+pub fn record(
+    env: &Env,
+    bounty_id: u64,
+    action: AuditAction,
+    actor: &Address,
+    amount: i128,
+    counterparty: Option<Address>,
+) {
+    append(
+        env,
+        bounty_id,
+        action,
+        Some(actor.clone()),
+        amount,
+        counterparty,
+    );
+}
+
+/// Append an entry for a permissionless action, which names no actor.
+pub fn record_unattributed(
+    env: &Env,
+    bounty_id: u64,
+    action: AuditAction,
+    amount: i128,
+    counterparty: Option<Address>,
+) {
+    append(env, bounty_id, action, None, amount, counterparty);
+}
+
+/// Append an entry, evicting the oldest page if the retention bound is
+/// exceeded.
+fn append(
+    env: &Env,
+    bounty_id: u64,
+    action: AuditAction,
+    actor: Option<Address>,
+    amount: i128,
+    counterparty: Option<Address>,
+) {
+    let mut cursor = get_cursor(env, bounty_id);
+
+    let page = cursor.next / AUDIT_PAGE_SIZE;
+    let mut entries = load_page(env, bounty_id, page);
+    entries.push_back(AuditEntry {
+        action,
+        actor,
+        amount,
+        counterparty,
+        timestamp: env.ledger().timestamp(),
+        ledger: env.ledger().sequence(),
+    });
+    env.storage()
+        .persistent()
+        .set(&DataKey::AuditPage(bounty_id, page), &entries);
+    cursor.next += 1;
+
+    while cursor.next - cursor.start > MAX_AUDIT_ENTRIES {
+        let oldest = cursor.start / AUDIT_PAGE_SIZE;
+        env.storage()
+            .persistent()
+            .remove(&DataKey::AuditPage(bounty_id, oldest));
+        cursor.start = (oldest + 1) * AUDIT_PAGE_SIZE;
+    }
+
+    env.storage()
+        .persistent()
+        .set(&DataKey::AuditCursor(bounty_id), &cursor);
+}
+
+/// Read up to `limit` entries starting at sequence `cursor`. Cursors that
+/// point at evicted entries resume from the oldest retained one.
+pub fn query(env: &Env, bounty_id: u64, cursor: u64, limit: u32) -> AuditLogPage {
+    let bounds = get_cursor(env, bounty_id);
+    let limit = limit.min(MAX_AUDIT_QUERY_LIMIT) as u64;
+
+    let first_seq = cursor.max(bounds.start);
+    let end = bounds.next.min(first_seq.saturating_add(limit));
+
+    let mut entries = Vec::new(env);
+    let mut seq = first_seq;
+    while seq < end {
+        let page = seq / AUDIT_PAGE_SIZE;
+        let page_entries = load_page(env, bounty_id, page);
+        let page_end = end.min((page + 1) * AUDIT_PAGE_SIZE);
+        while seq < page_end {
+            if let Some(entry) = page_entries.get((seq % AUDIT_PAGE_SIZE) as u32) {
+                entries.push_back(entry);
+            }
+            seq += 1;
+        }
+    }
+
+    AuditLogPage {
+        entries,
+        first_seq,
+        next_cursor: if seq < bounds.next { Some(seq) } else { None },
+    }
+}
+
+pub fn bounds(env: &Env, bounty_id: u64) -> AuditCursor {
+    get_cursor(env, bounty_id)
+}
@@ -0,0 +1,129 @@
+//! # Claim Expiry
+//!
+//! A pending claim is live until its `expires_at`. After that, if it was not
+//! executed, it is void: it no longer blocks `refund` or `release_funds`,
+//! and `get_refund_eligibility` ignores it. Void claims stay in storage
+//! until an admin cancels them, a new claim replaces them, or anyone runs
+//! `sweep_expired_claims`, which deletes them page by page and publishes a
+//! `claim/expired` event for each. The sweep skips frozen escrows; their
+//! claims are kept until the escrow is unfrozen.
+//!
+//! The window of a new claim is the bounty's own override when one is set,
+//! else the global `ClaimWindow`.
+
+use crate::audit_log::{self, AuditAction};
+use crate::events;
+use crate::{ClaimRecord, DataKey};
+use soroban_sdk::{contracttype, Env, Vec};
+
+/// Maximum number of escrows examined by one `sweep_expired_claims` call.
+pub const MAX_SWEEP_LIMIT: u32 = 50;
+
+/// Result of one `sweep_expired_claims` page.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimSweepReport {
+    /// Bounty ids whose expired claim was deleted.
+    pub swept: Vec<u64>,
+    /// Number of escrows examined on this page.
+    pub scanned: u32,
+    /// Index position for the next page, or `None` after the last escrow.
+    pub next_cursor: Option<u32>,
+}
+
+/// An unexecuted claim whose window has closed.
+pub fn is_void(env: &Env, claim: &ClaimRecord) -> bool {
+    !claim.claimed && env.ledger().timestamp() > claim.expires_at
+}
+
+pub fn get_window_override(env: &Env, bounty_id: u64) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClaimWindowOverride(bounty_id))
+}
+
+pub fn set_window_override(env: &Env, bounty_id: u64, claim_window: Option<u64>) {
+    let key = DataKey::ClaimWindowOverride(bounty_id);
+    match claim_window {
+        Some(claim_window) => env.storage().persistent().set(&key, &claim_window),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Window, in seconds, of a claim authorized now on `bounty_id`.
+pub fn window(env: &Env, bounty_id: u64) -> u64 {
+    get_window_override(env, bounty_id).unwrap_or_else(|| {
+        env.storage()
+            .instance()
+            .get(&DataKey::ClaimWindow)
+            .unwrap_or(0)
+    })
+}
+
+/// Delete the void claims of up to `limit` escrows from position `cursor`
+/// of the escrow index, skipping frozen escrows.
+pub fn sweep(env: &Env, cursor: u32, limit: u32) -> ClaimSweepReport {
+    let limit = limit.min(MAX_SWEEP_LIMIT);
+    let index: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::EscrowIndex)
+        .unwrap_or(Vec::new(env));
+
+    let end = index.len().min(cursor.saturating_add(limit));
+    let mut swept = Vec::new(env);
+    let mut i = cursor;
+    while i < end {
+        let bounty_id = index.get(i).unwrap();
+        i += 1;
+        // Frozen escrows are under investigation and left untouched.
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::EscrowFreeze(bounty_id))
+        {
+            continue;
+        }
+        let claim: Option<ClaimRecord> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::PendingClaim(bounty_id));
+        if let Some(claim) = claim.filter(|claim| is_void(env, claim)) {
+            expire(env, &claim);
+            swept.push_back(bounty_id);
+        }
+    }
+
+    ClaimSweepReport {
+        swept,
+        scanned: end.saturating_sub(cursor),
+        next_cursor: if end < index.len() { Some(end) } else { None },
+    }
+}
+
+fn expire(env: &Env, claim: &ClaimRecord) {
+    let bounty_id = claim.bounty_id;
+    env.storage()
+        .persistent()
+        .remove(&DataKey::PendingClaim(bounty_id));
+    env.storage()
+        .persistent()
+        .remove(&DataKey::ClaimRedirect(bounty_id));
+    audit_log::record_unattributed(
+        env,
+        bounty_id,
+        AuditAction::ClaimExpired,
+        claim.amount,
+        Some(claim.recipient.clone()),
+    );
+    events::emit_claim_expired(
+        env,
+        events::ClaimExpired {
+            bounty_id,
+            recipient: claim.recipient.clone(),
+            amount: claim.amount,
+            expires_at: claim.expires_at,
+            swept_at: env.ledger().timestamp(),
+        },
+    );
+}
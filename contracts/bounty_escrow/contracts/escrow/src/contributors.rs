@@ -0,0 +1,118 @@
+//! # Contributor Ledger
+//!
+//! The recipient-side counterpart of `DepositorIndex`. Every payout path
+//! records the payout here in its effects phase, next to the audit log, so
+//! leaderboards and reputation can be read from the contract directly:
+//!
+//! - `ContributorIndex(address)` lists each bounty the address has been paid
+//!   from, once, in order of first payout.
+//! - `ContributorStats(address)` keeps running totals.
+//!
+//! A *completed* bounty is one whose payout to the contributor left the
+//! escrow fully released. A *lost dispute* is a pending claim for the
+//! contributor that the admin rejected with `reject_pending_claim`; routine
+//! cancellations are not counted.
+
+use crate::events::FeeOperationType;
+use crate::{fees, DataKey};
+use soroban_sdk::{contracttype, Address, Env, Map, Vec};
+
+/// Running payout statistics for one contributor.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributorStats {
+    /// Total received after release fees, keyed by token contract.
+    pub total_earned: Map<Address, i128>,
+    pub bounties_completed: u32,
+    /// Payouts of part of an escrow (`partial_release`, capability releases).
+    pub partial_payouts: u32,
+    pub disputes_lost: u32,
+    pub first_payout_at: Option<u64>,
+    pub last_payout_at: Option<u64>,
+}
+
+/// Kind of payout being recorded.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Payout {
+    /// The whole escrow (`release_funds`, whole-escrow claims, batch
+    /// releases).
+    Full,
+    /// Part of the escrow; `completes` when nothing is left afterwards.
+    Partial { completes: bool },
+}
+
+pub fn get_stats(env: &Env, contributor: &Address) -> ContributorStats {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ContributorStats(contributor.clone()))
+        .unwrap_or(ContributorStats {
+            total_earned: Map::new(env),
+            bounties_completed: 0,
+            partial_payouts: 0,
+            disputes_lost: 0,
+            first_payout_at: None,
+            last_payout_at: None,
+        })
+}
+
+fn set_stats(env: &Env, contributor: &Address, stats: &ContributorStats) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ContributorStats(contributor.clone()), stats);
+}
+
+pub fn get_index(env: &Env, contributor: &Address) -> Vec<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ContributorIndex(contributor.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+/// Record a payout of `amount` of the contract's token to `contributor`
+/// from `bounty_id`, an escrow of `depositor`. The contributor is credited
+/// with what they receive: `amount` less the release fee.
+pub fn record_payout(
+    env: &Env,
+    contributor: &Address,
+    depositor: &Address,
+    bounty_id: u64,
+    amount: i128,
+    payout: Payout,
+) {
+    let token: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+    let now = env.ledger().timestamp();
+    let received = fees::quote(env, depositor, amount, FeeOperationType::Release).net;
+
+    let mut stats = get_stats(env, contributor);
+    let earned = stats.total_earned.get(token.clone()).unwrap_or(0);
+    stats.total_earned.set(token, earned + received);
+    match payout {
+        Payout::Full => stats.bounties_completed += 1,
+        Payout::Partial { completes } => {
+            stats.partial_payouts += 1;
+            if completes {
+                stats.bounties_completed += 1;
+            }
+        }
+    }
+    if stats.first_payout_at.is_none() {
+        stats.first_payout_at = Some(now);
+    }
+    stats.last_payout_at = Some(now);
+    set_stats(env, contributor, &stats);
+
+    let mut index = get_index(env, contributor);
+    if !index.contains(bounty_id) {
+        index.push_back(bounty_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::ContributorIndex(contributor.clone()), &index);
+    }
+}
+
+/// Record that a pending claim for `contributor` was rejected.
+pub fn record_dispute_lost(env: &Env, contributor: &Address) {
+    let mut stats = get_stats(env, contributor);
+    stats.disputes_lost += 1;
+    set_stats(env, contributor, &stats);
+}
@@ -0,0 +1,134 @@
+//! # Deadline Modes
+//!
+//! When a depositor may take back an escrow without an admin approval.
+//! `lock_funds` takes a fixed timestamp, which is stored on the escrow and
+//! is [`DeadlineMode::Absolute`]. `lock_funds_with_deadline_mode` can pick
+//! one of the other modes instead:
+//!
+//! - [`DeadlineMode::OpenEnded`]: never refundable on its own; only an
+//!   approved refund returns the funds.
+//! - [`DeadlineMode::AfterAssignment`]: refundable a fixed period after a
+//!   contributor is assigned. Until then only an approved refund works.
+//! - [`DeadlineMode::Inactivity`]: refundable once the escrow has been idle
+//!   for the period. Locking, assignment, claim authorization or execution
+//!   and partial payouts all restart the clock.
+//!
+//! Escrows with a non-absolute mode store `u64::MAX` as their `deadline`,
+//! so range queries and older clients treat them as having no fixed
+//! deadline. [`refundable_at`] is the source of truth.
+
+use crate::{DataKey, Error, Escrow};
+use soroban_sdk::{contracttype, Address, Env};
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DeadlineMode {
+    /// Refundable from this timestamp.
+    Absolute(u64),
+    /// Refundable only through an approved refund.
+    OpenEnded,
+    /// Refundable this many seconds after the latest assignment.
+    AfterAssignment(u64),
+    /// Refundable after this many seconds without activity.
+    Inactivity(u64),
+}
+
+/// Contributor currently assigned to a bounty.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Assignment {
+    pub contributor: Address,
+    pub assigned_at: u64,
+}
+
+/// Reject relative modes with a zero period.
+pub fn validate(mode: &DeadlineMode) -> Result<(), Error> {
+    match mode {
+        DeadlineMode::AfterAssignment(0) | DeadlineMode::Inactivity(0) => {
+            Err(Error::InvalidDeadline)
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Value kept in `Escrow::deadline` for an escrow locked with `mode`.
+pub fn stored_deadline(mode: &DeadlineMode) -> u64 {
+    match mode {
+        DeadlineMode::Absolute(deadline) => *deadline,
+        _ => u64::MAX,
+    }
+}
+
+pub fn get_mode(env: &Env, bounty_id: u64, escrow: &Escrow) -> DeadlineMode {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DeadlineMode(bounty_id))
+        .unwrap_or(DeadlineMode::Absolute(escrow.deadline))
+}
+
+/// Store `mode` for `bounty_id` and start its clock. Absolute modes are
+/// kept on the escrow itself and leave no separate entry.
+pub fn set_mode(env: &Env, bounty_id: u64, mode: &DeadlineMode) {
+    let key = DataKey::DeadlineMode(bounty_id);
+    match mode {
+        DeadlineMode::Absolute(_) => env.storage().persistent().remove(&key),
+        _ => env.storage().persistent().set(&key, mode),
+    }
+    let activity = DataKey::LastActivity(bounty_id);
+    match mode {
+        DeadlineMode::Inactivity(_) => env
+            .storage()
+            .persistent()
+            .set(&activity, &env.ledger().timestamp()),
+        _ => env.storage().persistent().remove(&activity),
+    }
+}
+
+pub fn get_assignment(env: &Env, bounty_id: u64) -> Option<Assignment> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Assignment(bounty_id))
+}
+
+/// Assign `contributor`, restarting any assignment or inactivity clock.
+pub fn assign(env: &Env, bounty_id: u64, contributor: &Address) -> Assignment {
+    let assignment = Assignment {
+        contributor: contributor.clone(),
+        assigned_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::Assignment(bounty_id), &assignment);
+    touch(env, bounty_id);
+    assignment
+}
+
+/// Record activity on `bounty_id`. Only inactivity-mode escrows track it.
+pub fn touch(env: &Env, bounty_id: u64) {
+    let key = DataKey::LastActivity(bounty_id);
+    if env.storage().persistent().has(&key) {
+        env.storage()
+            .persistent()
+            .set(&key, &env.ledger().timestamp());
+    }
+}
+
+/// Timestamp from which the depositor may refund without approval, or
+/// `None` while no such time is known.
+pub fn refundable_at(env: &Env, bounty_id: u64, escrow: &Escrow) -> Option<u64> {
+    match get_mode(env, bounty_id, escrow) {
+        DeadlineMode::Absolute(deadline) => Some(deadline),
+        DeadlineMode::OpenEnded => None,
+        DeadlineMode::AfterAssignment(period) => get_assignment(env, bounty_id)
+            .map(|assignment| assignment.assigned_at.saturating_add(period)),
+        DeadlineMode::Inactivity(period) => env
+            .storage()
+            .persistent()
+            .get::<DataKey, u64>(&DataKey::LastActivity(bounty_id))
+            .map(|last| last.saturating_add(period)),
+    }
+}
+
+pub fn has_passed(env: &Env, bounty_id: u64, escrow: &Escrow) -> bool {
+    refundable_at(env, bounty_id, escrow).is_some_and(|at| env.ledger().timestamp() >= at)
+}
@@ -0,0 +1,57 @@
+//! # Recipient Deny-List
+//!
+//! Admin-managed list of addresses that must never receive a payout
+//! (sanctioned or banned accounts). Every outbound transfer path checks the
+//! recipient against this list and fails with `Error::RecipientBlocked`.
+//!
+//! Entries carry a numeric reason code chosen by the operator (e.g. a
+//! sanctions-list identifier) so off-chain tooling can explain the block.
+//! Funds already destined for a listed contributor are not stranded: a
+//! pending claim whose recipient is listed no longer blocks `refund`.
+
+use crate::{DataKey, Error};
+use soroban_sdk::{contracttype, Address, Env};
+
+/// Why and when an address was placed on the deny-list.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DenyListEntry {
+    pub reason_code: u32,
+    pub listed_by: Address,
+    pub listed_at: u64,
+}
+
+pub fn get_entry(env: &Env, address: &Address) -> Option<DenyListEntry> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DenyListed(address.clone()))
+}
+
+pub fn is_listed(env: &Env, address: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::DenyListed(address.clone()))
+}
+
+pub fn add(env: &Env, address: &Address, entry: &DenyListEntry) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::DenyListed(address.clone()), entry);
+}
+
+pub fn remove(env: &Env, address: &Address) -> bool {
+    let key = DataKey::DenyListed(address.clone());
+    if !env.storage().persistent().has(&key) {
+        return false;
+    }
+    env.storage().persistent().remove(&key);
+    true
+}
+
+/// Reject payouts to listed addresses.
+pub fn ensure_recipient_allowed(env: &Env, recipient: &Address) -> Result<(), Error> {
+    if is_listed(env, recipient) {
+        return Err(Error::RecipientBlocked);
+    }
+    Ok(())
+}
@@ -1,7 +1,45 @@
-use crate::CapabilityAction;
-use soroban_sdk::{contracttype, symbol_short, Address, Env};
+use crate::access_list::{AccessListKind, AccessMode, AccessRole};
+use crate::deadlines::DeadlineMode;
+use crate::{migration, CapabilityAction, DataKey, Escrow, EscrowStatus, RateLimitOperation};
+use soroban_sdk::{contracttype, symbol_short, Address, BytesN, Env, String, Symbol};
 
 pub const EVENT_VERSION_V2: u32 = 2;
+pub const EVENT_VERSION_V3: u32 = 3;
+
+/// Which event schemas the contract publishes.
+///
+/// `V2` is the default and matches the events emitted before schema v3
+/// existed. `Dual` publishes both so v2 consumers can migrate at their own
+/// pace, and `V3` drops the v2 lifecycle events (lock, release, refund,
+/// claim, freeze) that have a v3 replacement. Events without a v3
+/// counterpart (init, fees, pause, capabilities, deny-list, batch summaries)
+/// are published under every schema.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum EventSchema {
+    V2,
+    Dual,
+    V3,
+}
+
+pub fn get_event_schema(env: &Env) -> EventSchema {
+    env.storage()
+        .instance()
+        .get(&DataKey::EventSchema)
+        .unwrap_or(EventSchema::V2)
+}
+
+pub fn set_event_schema(env: &Env, schema: EventSchema) {
+    env.storage().instance().set(&DataKey::EventSchema, &schema);
+}
+
+fn emits_v2(env: &Env) -> bool {
+    get_event_schema(env) != EventSchema::V3
+}
+
+fn emits_v3(env: &Env) -> bool {
+    get_event_schema(env) != EventSchema::V2
+}
 
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -28,6 +66,9 @@ pub struct FundsLocked {
 }
 
 pub fn emit_funds_locked(env: &Env, event: FundsLocked) {
+    if !emits_v2(env) {
+        return;
+    }
     let topics = (symbol_short!("f_lock"), event.bounty_id);
     env.events().publish(topics, event.clone());
 }
@@ -43,6 +84,9 @@ pub struct FundsReleased {
 }
 
 pub fn emit_funds_released(env: &Env, event: FundsReleased) {
+    if !emits_v2(env) {
+        return;
+    }
     let topics = (symbol_short!("f_rel"), event.bounty_id);
     env.events().publish(topics, event.clone());
 }
@@ -58,6 +102,9 @@ pub struct FundsRefunded {
 }
 
 pub fn emit_funds_refunded(env: &Env, event: FundsRefunded) {
+    if !emits_v2(env) {
+        return;
+    }
     let topics = (symbol_short!("f_ref"), event.bounty_id);
     env.events().publish(topics, event.clone());
 }
@@ -69,6 +116,15 @@ pub enum FeeOperationType {
     Release,
 }
 
+/// Party a share of a split fee is credited to.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum FeeParty {
+    Platform,
+    Maintainer,
+    Referrer,
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct FeeCollected {
@@ -112,6 +168,170 @@ pub fn emit_fee_config_updated(env: &Env, event: FeeConfigUpdated) {
     env.events().publish(topics, event.clone());
 }
 
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeScheduleUpdated {
+    pub version: u32,
+    /// Number of tiers; 0 when the schedule was cleared.
+    pub tier_count: u32,
+    pub min_fee: i128,
+    pub max_fee: Option<i128>,
+    pub cleared: bool,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_fee_schedule_updated(env: &Env, event: FeeScheduleUpdated) {
+    let topics = (symbol_short!("fee_sched"),);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeOverrideUpdated {
+    pub version: u32,
+    pub depositor: Address,
+    /// `None` when the override was removed.
+    pub lock_fee_rate: Option<i128>,
+    pub release_fee_rate: Option<i128>,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_fee_override_updated(env: &Env, event: FeeOverrideUpdated) {
+    let topics = (symbol_short!("fee_ovr"), event.depositor.clone());
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeSplitUpdated {
+    pub version: u32,
+    /// Shares in basis points; all zero when the split was cleared.
+    pub platform_bps: i128,
+    pub maintainer_bps: i128,
+    pub referrer_bps: i128,
+    pub cleared: bool,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_fee_split_updated(env: &Env, event: FeeSplitUpdated) {
+    let topics = (symbol_short!("fee_split"),);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeShareAccrued {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub owner: Address,
+    pub party: FeeParty,
+    pub amount: i128,
+    pub operation_type: FeeOperationType,
+    pub timestamp: u64,
+}
+
+pub fn emit_fee_share_accrued(env: &Env, event: FeeShareAccrued) {
+    let topics = (symbol_short!("fee_share"), event.owner.clone());
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesWithdrawn {
+    pub version: u32,
+    pub owner: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_fees_withdrawn(env: &Env, event: FeesWithdrawn) {
+    let topics = (symbol_short!("fee_wdraw"), event.owner.clone());
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RepoTreasuryUpdated {
+    pub version: u32,
+    pub repo_id: u64,
+    /// `None` when the treasury was removed.
+    pub treasury: Option<Address>,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_repo_treasury_updated(env: &Env, event: RepoTreasuryUpdated) {
+    let topics = (symbol_short!("repo_trsy"), event.repo_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReferrerAttached {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub referrer: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_referrer_attached(env: &Env, event: ReferrerAttached) {
+    let topics = (symbol_short!("referral"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DeadlineModeUpdated {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub mode: DeadlineMode,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_deadline_mode_updated(env: &Env, event: DeadlineModeUpdated) {
+    let topics = (symbol_short!("dl_mode"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributorAssigned {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub assigned_by: Address,
+    pub assigned_at: u64,
+}
+
+pub fn emit_contributor_assigned(env: &Env, event: ContributorAssigned) {
+    let topics = (symbol_short!("assigned"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowImported {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    pub deadline: u64,
+    /// Legacy contract the escrow came from.
+    pub source: Address,
+    pub imported_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_imported(env: &Env, event: EscrowImported) {
+    let topics = (symbol_short!("imported"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct BatchFundsReleased {
@@ -125,6 +345,87 @@ pub fn emit_batch_funds_released(env: &Env, event: BatchFundsReleased) {
     env.events().publish(topics, event.clone());
 }
 
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BatchFundsRefunded {
+    pub count: u32,
+    pub total_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_batch_funds_refunded(env: &Env, event: BatchFundsRefunded) {
+    let topics = (symbol_short!("b_ref"),);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BatchPartialReleased {
+    pub count: u32,
+    pub total_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_batch_partial_released(env: &Env, event: BatchPartialReleased) {
+    let topics = (symbol_short!("b_prel"),);
+    env.events().publish(topics, event.clone());
+}
+
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BatchClaimsAuthorized {
+    pub count: u32,
+    /// Sum of the authorised claim amounts.
+    pub total_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_batch_claims_authorized(env: &Env, event: BatchClaimsAuthorized) {
+    let topics = (symbol_short!("b_claims"),);
+    env.events().publish(topics, event.clone());
+}
+
+/// Outcome of one item of a best-effort batch. Items that succeed also
+/// publish their usual lock or release events.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BatchItemProcessed {
+    /// `lock` or `release`.
+    pub operation: Symbol,
+    pub bounty_id: u64,
+    pub ok: bool,
+    /// The `Error` code the item was skipped with, or zero.
+    pub error_code: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_batch_item_processed(env: &Env, event: BatchItemProcessed) {
+    let topics = (
+        symbol_short!("b_item"),
+        event.operation.clone(),
+        event.bounty_id,
+    );
+    env.events().publish(topics, event.clone());
+}
+
+/// Summary of a best-effort batch, published once after all its items.
+#[contracttype]
+#[derive(Clone, Debug)]
+pub struct BatchSummary {
+    pub operation: Symbol,
+    pub attempted: u32,
+    pub succeeded: u32,
+    pub failed: u32,
+    /// Total moved by the items that succeeded.
+    pub total_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_batch_summary(env: &Env, event: BatchSummary) {
+    let topics = (symbol_short!("b_summary"), event.operation.clone());
+    env.events().publish(topics, event.clone());
+}
+
 #[contracttype]
 #[derive(Clone, Debug)]
 pub struct ApprovalAdded {
@@ -167,6 +468,81 @@ pub struct ClaimCancelled {
     pub cancelled_by: Address,
 }
 
+pub fn emit_claim_created(env: &Env, event: ClaimCreated) {
+    if !emits_v2(env) {
+        return;
+    }
+    let topics = (symbol_short!("claim"), symbol_short!("created"));
+    env.events().publish(topics, event);
+}
+
+pub fn emit_claim_executed(env: &Env, event: ClaimExecuted) {
+    if !emits_v2(env) {
+        return;
+    }
+    let topics = (symbol_short!("claim"), symbol_short!("done"));
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimRedirected {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    /// Address the claim now pays out to.
+    pub payout_to: Address,
+    pub redirected_at: u64,
+}
+
+pub fn emit_claim_redirected(env: &Env, event: ClaimRedirected) {
+    if !emits_v2(env) {
+        return;
+    }
+    let topics = (symbol_short!("claim"), symbol_short!("redirect"));
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimExpired {
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+    pub expires_at: u64,
+    pub swept_at: u64,
+}
+
+/// Published under every schema: there is no v3 lifecycle kind for expiry.
+pub fn emit_claim_expired(env: &Env, event: ClaimExpired) {
+    let topics = (symbol_short!("claim"), symbol_short!("expired"));
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyClaimWindowUpdated {
+    pub version: u32,
+    pub bounty_id: u64,
+    /// `None` when the bounty went back to the global window.
+    pub claim_window: Option<u64>,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_bounty_claim_window_updated(env: &Env, event: BountyClaimWindowUpdated) {
+    let topics = (symbol_short!("claim_win"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+pub fn emit_claim_cancelled(env: &Env, event: ClaimCancelled) {
+    if !emits_v2(env) {
+        return;
+    }
+    let topics = (symbol_short!("claim"), symbol_short!("cancel"));
+    env.events().publish(topics, event);
+}
+
 pub fn emit_pause_state_changed(env: &Env, event: crate::PauseStateChanged) {
     let topics = (symbol_short!("pause"), event.operation.clone());
     env.events().publish(topics, event);
@@ -235,3 +611,436 @@ pub fn emit_capability_revoked(env: &Env, event: CapabilityRevoked) {
     let topics = (symbol_short!("cap_rev"), event.capability_id);
     env.events().publish(topics, event);
 }
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowFrozen {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub reason: String,
+    pub frozen_by: Address,
+    pub remaining_amount: i128,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_frozen(env: &Env, event: EscrowFrozen) {
+    if !emits_v2(env) {
+        return;
+    }
+    let topics = (symbol_short!("frozen"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowUnfrozen {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub reason: String,
+    pub unfrozen_by: Address,
+    pub restored_status: EscrowStatus,
+    pub frozen_at: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_escrow_unfrozen(env: &Env, event: EscrowUnfrozen) {
+    if !emits_v2(env) {
+        return;
+    }
+    let topics = (symbol_short!("unfrozen"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DenyListUpdated {
+    pub version: u32,
+    pub address: Address,
+    pub listed: bool,
+    pub reason_code: u32,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_deny_list_updated(env: &Env, event: DenyListUpdated) {
+    let topics = (symbol_short!("deny"), event.address.clone());
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessModeUpdated {
+    pub version: u32,
+    pub role: AccessRole,
+    pub mode: AccessMode,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_access_mode_updated(env: &Env, event: AccessModeUpdated) {
+    let topics = (symbol_short!("acc_mode"),);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AccessListUpdated {
+    pub version: u32,
+    pub role: AccessRole,
+    pub list: AccessListKind,
+    pub address: Address,
+    pub listed: bool,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_access_list_updated(env: &Env, event: AccessListUpdated) {
+    let topics = (symbol_short!("acc_list"), event.address.clone());
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AttestationConfigUpdated {
+    pub version: u32,
+    /// Registry of the new gate, or `None` when the gate was cleared.
+    pub registry: Option<Address>,
+    pub threshold: i128,
+    pub required_level: u32,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_attestation_config_updated(env: &Env, event: AttestationConfigUpdated) {
+    let topics = (symbol_short!("attest_cf"),);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HookUpdated {
+    pub version: u32,
+    pub hook: Address,
+    /// `true` when registered, `false` when removed.
+    pub registered: bool,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_hook_updated(env: &Env, event: HookUpdated) {
+    let topics = (symbol_short!("hook"), event.hook.clone());
+    env.events().publish(topics, event);
+}
+
+/// A payout hook failed; the payout itself went through.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HookFailed {
+    pub hook: Address,
+    pub kind: Symbol,
+    pub bounty_id: u64,
+    pub timestamp: u64,
+}
+
+pub fn emit_hook_failed(env: &Env, event: HookFailed) {
+    let topics = (symbol_short!("hook_fail"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeApproved {
+    pub version: u32,
+    pub wasm_hash: BytesN<32>,
+    pub approver: Address,
+    /// Approvals collected for `wasm_hash`, including this one.
+    pub approvals: u32,
+    pub timestamp: u64,
+}
+
+pub fn emit_upgrade_approved(env: &Env, event: UpgradeApproved) {
+    let topics = (symbol_short!("upg_appr"), event.wasm_hash.clone());
+    env.events().publish(topics, event);
+}
+
+/// Published just before the contract code is replaced.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgraded {
+    pub version: u32,
+    pub wasm_hash: BytesN<32>,
+    pub from_version: u32,
+    pub upgraded_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_contract_upgraded(env: &Env, event: ContractUpgraded) {
+    let topics = (symbol_short!("upgraded"),);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractMigrated {
+    pub version: u32,
+    pub from_version: u32,
+    pub to_version: u32,
+    pub total_liabilities: i128,
+    pub migrated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_contract_migrated(env: &Env, event: ContractMigrated) {
+    let topics = (symbol_short!("migrated"),);
+    env.events().publish(topics, event);
+}
+
+// ---------------------------------------------------------------------------
+// Schema v3
+//
+// Every bounty state change publishes a `BountyEventV3` whose topics are
+// `(kind, bounty_id, depositor, recipient)`, so indexers can filter on any
+// party without decoding the payload. Configuration changes publish their own
+// typed events. All v3 events carry `version == EVENT_VERSION_V3` and are
+// only published when the schema is `Dual` or `V3`.
+// ---------------------------------------------------------------------------
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BountyEventKind {
+    Locked,
+    Released,
+    PartiallyReleased,
+    Refunded,
+    RefundApproved,
+    ClaimAuthorized,
+    Claimed,
+    ClaimCancelled,
+    Frozen,
+    Unfrozen,
+}
+
+impl BountyEventKind {
+    fn topic(self) -> Symbol {
+        match self {
+            BountyEventKind::Locked => symbol_short!("lock_v3"),
+            BountyEventKind::Released => symbol_short!("rel_v3"),
+            BountyEventKind::PartiallyReleased => symbol_short!("prel_v3"),
+            BountyEventKind::Refunded => symbol_short!("ref_v3"),
+            BountyEventKind::RefundApproved => symbol_short!("refapr_v3"),
+            BountyEventKind::ClaimAuthorized => symbol_short!("clmaut_v3"),
+            BountyEventKind::Claimed => symbol_short!("clm_v3"),
+            BountyEventKind::ClaimCancelled => symbol_short!("clmcan_v3"),
+            BountyEventKind::Frozen => symbol_short!("frz_v3"),
+            BountyEventKind::Unfrozen => symbol_short!("unfrz_v3"),
+        }
+    }
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BountyEventV3 {
+    pub version: u32,
+    pub kind: BountyEventKind,
+    pub bounty_id: u64,
+    pub depositor: Address,
+    /// Party receiving (or designated to receive) funds, if any.
+    pub recipient: Option<Address>,
+    /// Address whose authorization performed the change; `None` for a
+    /// permissionless refund after the deadline.
+    pub actor: Option<Address>,
+    /// Amount moved or affected by this change.
+    pub amount: i128,
+    /// Escrow state after the change.
+    pub remaining_amount: i128,
+    pub status: EscrowStatus,
+    pub timestamp: u64,
+}
+
+/// Publish a v3 lifecycle event for `bounty_id`. The escrow must already be
+/// written to storage; its post-change state fills the payload.
+pub fn emit_bounty_event_v3(
+    env: &Env,
+    kind: BountyEventKind,
+    bounty_id: u64,
+    actor: Option<&Address>,
+    recipient: Option<Address>,
+    amount: i128,
+) {
+    if !emits_v3(env) {
+        return;
+    }
+    let escrow: Escrow = match migration::load_escrow(env, bounty_id) {
+        Some(escrow) => escrow,
+        None => return,
+    };
+    let topics = (
+        kind.topic(),
+        bounty_id,
+        escrow.depositor.clone(),
+        recipient.clone(),
+    );
+    env.events().publish(
+        topics,
+        BountyEventV3 {
+            version: EVENT_VERSION_V3,
+            kind,
+            bounty_id,
+            depositor: escrow.depositor,
+            recipient,
+            actor: actor.cloned(),
+            amount,
+            remaining_amount: escrow.remaining_amount,
+            status: escrow.status,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MetadataUpdated {
+    pub version: u32,
+    pub bounty_id: u64,
+    pub repo_id: u64,
+    pub issue_id: u64,
+    pub bounty_type: String,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_metadata_updated(env: &Env, event: MetadataUpdated) {
+    if !emits_v3(env) {
+        return;
+    }
+    let topics = (symbol_short!("meta_v3"), event.bounty_id);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmountPolicyUpdated {
+    pub version: u32,
+    pub min_amount: i128,
+    pub max_amount: i128,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_amount_policy_updated(env: &Env, event: AmountPolicyUpdated) {
+    if !emits_v3(env) {
+        return;
+    }
+    let topics = (symbol_short!("amtpol_v3"),);
+    env.events().publish(topics, event);
+}
+
+/// Published under every schema; limits are in the token's base units.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenAmountPolicyUpdated {
+    pub version: u32,
+    pub token: Address,
+    pub decimals: u32,
+    pub min_amount: i128,
+    pub max_amount: i128,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_token_amount_policy_updated(env: &Env, event: TokenAmountPolicyUpdated) {
+    let topics = (symbol_short!("amtpol_tk"), event.token.clone());
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimWindowUpdated {
+    pub version: u32,
+    pub claim_window: u64,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_claim_window_updated(env: &Env, event: ClaimWindowUpdated) {
+    if !emits_v3(env) {
+        return;
+    }
+    let topics = (symbol_short!("clmwin_v3"),);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AntiAbuseConfigUpdated {
+    pub version: u32,
+    pub window_size: u64,
+    pub max_operations: u32,
+    pub cooldown_period: u64,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_anti_abuse_config_updated(env: &Env, event: AntiAbuseConfigUpdated) {
+    if !emits_v3(env) {
+        return;
+    }
+    let topics = (symbol_short!("abuse_v3"),);
+    env.events().publish(topics, event);
+}
+
+/// A per-operation rate limit was set or cleared. A cleared override
+/// reports zeroes and falls back to the default limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationRateLimitUpdated {
+    pub version: u32,
+    pub operation: RateLimitOperation,
+    pub window_size: u64,
+    pub max_operations: u32,
+    pub cooldown_period: u64,
+    pub cleared: bool,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_operation_rate_limit_updated(env: &Env, event: OperationRateLimitUpdated) {
+    if !emits_v3(env) {
+        return;
+    }
+    let topics = (symbol_short!("rlim_v3"),);
+    env.events().publish(topics, event);
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WhitelistUpdated {
+    pub version: u32,
+    pub address: Address,
+    pub whitelisted: bool,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_whitelist_updated(env: &Env, event: WhitelistUpdated) {
+    if !emits_v3(env) {
+        return;
+    }
+    let topics = (symbol_short!("wl_v3"), event.address.clone());
+    env.events().publish(topics, event);
+}
+
+/// Published under every schema so consumers see the switch itself.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventSchemaUpdated {
+    pub version: u32,
+    pub schema: EventSchema,
+    pub updated_by: Address,
+    pub timestamp: u64,
+}
+
+pub fn emit_event_schema_updated(env: &Env, event: EventSchemaUpdated) {
+    let topics = (symbol_short!("ev_schema"),);
+    env.events().publish(topics, event);
+}
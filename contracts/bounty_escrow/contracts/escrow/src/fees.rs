@@ -0,0 +1,446 @@
+//! # Fee Schedules
+//!
+//! Fees are charged when `FeeConfig::fee_enabled` is set: the lock fee is
+//! taken out of each deposit before it is escrowed, and the release fee out
+//! of each payout to a contributor. Refunds are never charged. Fees are paid
+//! straight to `FeeConfig::fee_recipient`.
+//!
+//! The rate for an operation is picked in this order:
+//!
+//! 1. the depositor's [`FeeOverride`], if the admin set one;
+//! 2. the [`FeeSchedule`] tier whose `min_amount` is the largest one not
+//!    above the amount;
+//! 3. the flat `FeeConfig` rates.
+//!
+//! `fee = floor(amount * rate / 10_000)` via [`token_math::calculate_fee`].
+//! A 0 bp rate is free; any other fee is then raised to the schedule's
+//! `min_fee`, lowered to its `max_fee`, and never exceeds the amount.
+//! `quote_fee` runs [`quote`], the same function execution uses.
+//!
+//! Lock events and the escrow record carry the net amount actually
+//! escrowed. Payout events, hooks and contributor stats report the gross
+//! amount released from the escrow; the fee is reported by the `fee` event.
+//!
+//! ## Splits
+//!
+//! Without a [`FeeSplit`] each fee is transferred to the fee recipient
+//! immediately. With one, the contract keeps the fee and credits its shares
+//! to claimable balances, withdrawn later with `withdraw_fees`:
+//!
+//! - the platform share to the fee recipient;
+//! - the maintainer share to the treasury registered for the escrow's
+//!   `repo_id` metadata;
+//! - the referrer share to the referrer attached at lock.
+//!
+//! Shares are computed with [`token_math::split_largest_remainder`], so they
+//! add up to the fee exactly. A share with nobody to receive it (no
+//! metadata, no treasury, no referrer) is credited to the platform.
+//! Unclaimed balances count as liabilities in the solvency check.
+
+use crate::events::{self, FeeOperationType, FeeParty, EVENT_VERSION_V2};
+use crate::{token_math, BountyEscrowContract, DataKey, Error, EscrowMetadata, MAX_FEE_RATE};
+use soroban_sdk::{contracttype, token, Address, Env, Vec};
+
+/// Maximum number of tiers in a schedule.
+pub const MAX_FEE_TIERS: u32 = 10;
+
+/// Rates for amounts from `min_amount` up to the next tier.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeTier {
+    pub min_amount: i128,
+    pub lock_fee_rate: i128,
+    pub release_fee_rate: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeSchedule {
+    /// Sorted by strictly increasing `min_amount`. Amounts below the first
+    /// tier use the flat `FeeConfig` rates.
+    pub tiers: Vec<FeeTier>,
+    /// Smallest fee charged on a non-zero rate, in base units.
+    pub min_fee: i128,
+    /// Largest fee charged, in base units; `None` for no cap.
+    pub max_fee: Option<i128>,
+}
+
+/// Rates that replace the schedule for one depositor's escrows.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeOverride {
+    pub lock_fee_rate: i128,
+    pub release_fee_rate: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeQuote {
+    pub amount: i128,
+    pub fee: i128,
+    /// `amount - fee`.
+    pub net: i128,
+    /// Rate selected for the amount, in basis points, before min/max caps.
+    pub fee_rate: i128,
+    /// `true` when the depositor's override supplied the rate.
+    pub overridden: bool,
+}
+
+/// Shares of each fee, in basis points summing to 10_000.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeSplit {
+    pub platform_bps: i128,
+    pub maintainer_bps: i128,
+    pub referrer_bps: i128,
+}
+
+pub fn get_schedule(env: &Env) -> Option<FeeSchedule> {
+    env.storage().instance().get(&DataKey::FeeSchedule)
+}
+
+pub fn set_schedule(env: &Env, schedule: &Option<FeeSchedule>) {
+    match schedule {
+        Some(schedule) => env
+            .storage()
+            .instance()
+            .set(&DataKey::FeeSchedule, schedule),
+        None => env.storage().instance().remove(&DataKey::FeeSchedule),
+    }
+}
+
+pub fn get_override(env: &Env, depositor: &Address) -> Option<FeeOverride> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeeOverride(depositor.clone()))
+}
+
+pub fn set_override(env: &Env, depositor: &Address, fee_override: &Option<FeeOverride>) {
+    let key = DataKey::FeeOverride(depositor.clone());
+    match fee_override {
+        Some(fee_override) => env.storage().persistent().set(&key, fee_override),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_split(env: &Env) -> Option<FeeSplit> {
+    env.storage().instance().get(&DataKey::FeeSplit)
+}
+
+pub fn set_split(env: &Env, split: &Option<FeeSplit>) {
+    match split {
+        Some(split) => env.storage().instance().set(&DataKey::FeeSplit, split),
+        None => env.storage().instance().remove(&DataKey::FeeSplit),
+    }
+}
+
+pub fn get_repo_treasury(env: &Env, repo_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::RepoTreasury(repo_id))
+}
+
+pub fn set_repo_treasury(env: &Env, repo_id: u64, treasury: &Option<Address>) {
+    let key = DataKey::RepoTreasury(repo_id);
+    match treasury {
+        Some(treasury) => env.storage().persistent().set(&key, treasury),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn get_referrer(env: &Env, bounty_id: u64) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Referrer(bounty_id))
+}
+
+pub fn set_referrer(env: &Env, bounty_id: u64, referrer: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::Referrer(bounty_id), referrer);
+}
+
+/// Treasury of the repository named in the escrow's metadata, if any.
+pub fn maintainer_treasury(env: &Env, bounty_id: u64) -> Option<Address> {
+    let metadata: EscrowMetadata = env
+        .storage()
+        .persistent()
+        .get(&DataKey::Metadata(bounty_id))?;
+    get_repo_treasury(env, metadata.repo_id)
+}
+
+pub fn balance(env: &Env, owner: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeeBalance(owner.clone()))
+        .unwrap_or(0)
+}
+
+/// Sum of all claimable fee balances; owed by the contract.
+pub fn unclaimed_total(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::UnclaimedFees)
+        .unwrap_or(0)
+}
+
+fn adjust_balance(env: &Env, owner: &Address, delta: i128) {
+    let key = DataKey::FeeBalance(owner.clone());
+    let balance = balance(env, owner)
+        .checked_add(delta)
+        .expect("fee balance overflow");
+    if balance == 0 {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, &balance);
+    }
+    let total = unclaimed_total(env)
+        .checked_add(delta)
+        .expect("fee balance overflow");
+    env.storage()
+        .instance()
+        .set(&DataKey::UnclaimedFees, &total);
+}
+
+/// Clear `owner`'s balance and return it; the caller transfers it.
+pub fn take_balance(env: &Env, owner: &Address) -> Result<i128, Error> {
+    let amount = balance(env, owner);
+    if amount <= 0 {
+        return Err(Error::NothingToWithdraw);
+    }
+    adjust_balance(env, owner, -amount);
+    Ok(amount)
+}
+
+fn valid_rate(rate: i128) -> bool {
+    (0..=MAX_FEE_RATE).contains(&rate)
+}
+
+pub fn validate_schedule(schedule: &FeeSchedule) -> Result<(), Error> {
+    if schedule.tiers.len() > MAX_FEE_TIERS || schedule.min_fee < 0 {
+        return Err(Error::InvalidFeeSchedule);
+    }
+    if let Some(max_fee) = schedule.max_fee {
+        if max_fee < schedule.min_fee {
+            return Err(Error::InvalidFeeSchedule);
+        }
+    }
+    let mut previous: Option<i128> = None;
+    for tier in schedule.tiers.iter() {
+        if !valid_rate(tier.lock_fee_rate) || !valid_rate(tier.release_fee_rate) {
+            return Err(Error::InvalidFeeRate);
+        }
+        if tier.min_amount < 0 || previous.is_some_and(|min| tier.min_amount <= min) {
+            return Err(Error::InvalidFeeSchedule);
+        }
+        previous = Some(tier.min_amount);
+    }
+    Ok(())
+}
+
+pub fn validate_override(fee_override: &FeeOverride) -> Result<(), Error> {
+    if !valid_rate(fee_override.lock_fee_rate) || !valid_rate(fee_override.release_fee_rate) {
+        return Err(Error::InvalidFeeRate);
+    }
+    Ok(())
+}
+
+pub fn validate_split(split: &FeeSplit) -> Result<(), Error> {
+    let shares = [split.platform_bps, split.maintainer_bps, split.referrer_bps];
+    if shares.iter().any(|bps| *bps < 0) || shares.iter().sum::<i128>() != token_math::BASIS_POINTS
+    {
+        return Err(Error::InvalidFeeSplit);
+    }
+    Ok(())
+}
+
+fn pick(op: &FeeOperationType, lock_fee_rate: i128, release_fee_rate: i128) -> i128 {
+    match op {
+        FeeOperationType::Lock => lock_fee_rate,
+        FeeOperationType::Release => release_fee_rate,
+    }
+}
+
+/// Fee owed on `amount` for an escrow of `depositor`.
+pub fn quote(env: &Env, depositor: &Address, amount: i128, op: FeeOperationType) -> FeeQuote {
+    let config = BountyEscrowContract::get_fee_config(env.clone());
+    let schedule = get_schedule(env);
+    let fee_override = get_override(env, depositor);
+
+    let fee_rate = if !config.fee_enabled {
+        0
+    } else if let Some(fee_override) = &fee_override {
+        pick(
+            &op,
+            fee_override.lock_fee_rate,
+            fee_override.release_fee_rate,
+        )
+    } else {
+        let tier = schedule.as_ref().and_then(|schedule| {
+            schedule
+                .tiers
+                .iter()
+                .filter(|tier| tier.min_amount <= amount)
+                .last()
+        });
+        match tier {
+            Some(tier) => pick(&op, tier.lock_fee_rate, tier.release_fee_rate),
+            None => pick(&op, config.lock_fee_rate, config.release_fee_rate),
+        }
+    };
+
+    let mut fee = token_math::calculate_fee(amount, fee_rate);
+    if fee_rate > 0 {
+        if let Some(schedule) = &schedule {
+            fee = fee.max(schedule.min_fee);
+            if let Some(max_fee) = schedule.max_fee {
+                fee = fee.min(max_fee);
+            }
+        }
+        fee = fee.clamp(0, amount.max(0));
+    }
+
+    FeeQuote {
+        amount,
+        fee,
+        net: amount - fee,
+        fee_rate,
+        overridden: config.fee_enabled && fee_override.is_some(),
+    }
+}
+
+/// Publish a collected fee.
+pub fn publish(env: &Env, quote: &FeeQuote, op: FeeOperationType, recipient: Address) {
+    events::emit_fee_collected(
+        env,
+        events::FeeCollected {
+            operation_type: op,
+            amount: quote.fee,
+            fee_rate: quote.fee_rate,
+            recipient,
+            timestamp: env.ledger().timestamp(),
+        },
+    );
+}
+
+/// Credit the shares of a fee the contract already holds to the balances
+/// of its parties.
+fn accrue(env: &Env, split: &FeeSplit, bounty_id: u64, quote: &FeeQuote, op: FeeOperationType) {
+    let platform = fee_recipient(env);
+    let weights = [split.platform_bps, split.maintainer_bps, split.referrer_bps];
+    let shares =
+        token_math::split_largest_remainder(quote.fee, weights).expect("invalid fee split");
+    let owners = [
+        (FeeParty::Platform, Some(platform.clone())),
+        (FeeParty::Maintainer, maintainer_treasury(env, bounty_id)),
+        (FeeParty::Referrer, get_referrer(env, bounty_id)),
+    ];
+    for ((party, owner), amount) in owners.into_iter().zip(shares) {
+        if amount == 0 {
+            continue;
+        }
+        let (party, owner) = match owner {
+            Some(owner) => (party, owner),
+            None => (FeeParty::Platform, platform.clone()),
+        };
+        adjust_balance(env, &owner, amount);
+        events::emit_fee_share_accrued(
+            env,
+            events::FeeShareAccrued {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                owner,
+                party,
+                amount,
+                operation_type: op.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+    }
+    publish(env, quote, op, env.current_contract_address());
+}
+
+/// Collect a quoted fee on `bounty_id` from `from`: straight to the fee
+/// recipient, or into the contract and the split balances.
+pub fn collect(
+    env: &Env,
+    client: &token::Client,
+    from: &Address,
+    bounty_id: u64,
+    quote: &FeeQuote,
+    op: FeeOperationType,
+) {
+    if quote.fee <= 0 {
+        return;
+    }
+    let contract = env.current_contract_address();
+    match get_split(env) {
+        Some(split) => {
+            if *from != contract {
+                client.transfer(from, &contract, &quote.fee);
+            }
+            accrue(env, &split, bounty_id, quote, op);
+        }
+        None => {
+            let recipient = fee_recipient(env);
+            client.transfer(from, &recipient, &quote.fee);
+            publish(env, quote, op, recipient);
+        }
+    }
+}
+
+pub fn fee_recipient(env: &Env) -> Address {
+    BountyEscrowContract::get_fee_config(env.clone()).fee_recipient
+}
+
+/// Pay `amount` out of the escrow `bounty_id` of `depositor` to
+/// `recipient`, less the release fee. Returns the quote that was applied.
+pub fn pay_out(
+    env: &Env,
+    client: &token::Client,
+    bounty_id: u64,
+    depositor: &Address,
+    recipient: &Address,
+    amount: i128,
+) -> FeeQuote {
+    let quote = quote(env, depositor, amount, FeeOperationType::Release);
+    let contract = env.current_contract_address();
+    if quote.net > 0 {
+        client.transfer(&contract, recipient, &quote.net);
+    }
+    collect(
+        env,
+        client,
+        &contract,
+        bounty_id,
+        &quote,
+        FeeOperationType::Release,
+    );
+    quote
+}
+
+/// Add the payout of `amount` to `recipient`, less the release fee, to a
+/// list paid by `transfer_aggregated`. The fee is paid or accrued with the
+/// same list and published right away.
+pub fn push_payout(
+    env: &Env,
+    payouts: &mut Vec<(Address, i128)>,
+    bounty_id: u64,
+    depositor: &Address,
+    recipient: &Address,
+    amount: i128,
+) {
+    let quote = quote(env, depositor, amount, FeeOperationType::Release);
+    payouts.push_back((recipient.clone(), quote.net));
+    if quote.fee <= 0 {
+        return;
+    }
+    match get_split(env) {
+        Some(split) => accrue(env, &split, bounty_id, &quote, FeeOperationType::Release),
+        None => {
+            let fee_recipient = fee_recipient(env);
+            payouts.push_back((fee_recipient.clone(), quote.fee));
+            publish(env, &quote, FeeOperationType::Release, fee_recipient);
+        }
+    }
+}
@@ -0,0 +1,81 @@
+//! # Payout Hooks
+//!
+//! Admin-registered receiver contracts notified after every contributor
+//! payout, so reputation, badge and matching contracts can react to
+//! releases without polling events.
+//!
+//! A hook must expose
+//!
+//! ```text
+//! on_escrow_event(kind: Symbol, bounty_id: u64, recipient: Address, amount: i128)
+//! ```
+//!
+//! where `kind` is `release`, `partial` or `claim`. Hooks run after the
+//! escrow state is written and the token transfer has completed, while the
+//! reentrancy guard is still held. Each call goes through
+//! `try_invoke_contract`: a hook that panics, returns an error or tries to
+//! re-enter the escrow has its own changes rolled back, a `hook_fail` event
+//! is published, and the payout stands. A hook that exhausts the
+//! transaction budget cannot be caught, which is why registration is
+//! admin-only and capped at `MAX_HOOKS`.
+
+use crate::{events, DataKey};
+use soroban_sdk::{contractclient, symbol_short, Address, Env, Symbol, Vec};
+
+/// Maximum number of registered hooks.
+pub const MAX_HOOKS: u32 = 5;
+
+/// Interface a payout hook contract must expose.
+#[allow(dead_code)]
+#[contractclient(name = "EscrowHookClient")]
+pub trait EscrowHookInterface {
+    fn on_escrow_event(env: Env, kind: Symbol, bounty_id: u64, recipient: Address, amount: i128);
+}
+
+/// Full release of an escrow (`release_funds`, batch releases).
+pub fn release_kind() -> Symbol {
+    symbol_short!("release")
+}
+
+/// Payout of part of an escrow (`partial_release`, capability releases).
+pub fn partial_kind() -> Symbol {
+    symbol_short!("partial")
+}
+
+/// Executed claim (`claim`, `claim_with_capability`).
+pub fn claim_kind() -> Symbol {
+    symbol_short!("claim")
+}
+
+pub fn get_hooks(env: &Env) -> Vec<Address> {
+    env.storage()
+        .instance()
+        .get(&DataKey::PayoutHooks)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_hooks(env: &Env, hooks: &Vec<Address>) {
+    env.storage().instance().set(&DataKey::PayoutHooks, hooks);
+}
+
+/// Call every registered hook; failures are reported, never propagated.
+pub fn notify(env: &Env, kind: Symbol, bounty_id: u64, recipient: &Address, amount: i128) {
+    for hook in get_hooks(env).iter() {
+        let delivered = matches!(
+            EscrowHookClient::new(env, &hook)
+                .try_on_escrow_event(&kind, &bounty_id, recipient, &amount),
+            Ok(Ok(()))
+        );
+        if !delivered {
+            events::emit_hook_failed(
+                env,
+                events::HookFailed {
+                    hook,
+                    kind: kind.clone(),
+                    bounty_id,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
+    }
+}
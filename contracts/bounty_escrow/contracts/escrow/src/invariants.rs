@@ -1,5 +1,5 @@
-use crate::{Escrow, EscrowStatus};
-use soroban_sdk::{symbol_short, Env, Symbol};
+use crate::{fees, migration, DataKey, Error, Escrow, EscrowStatus};
+use soroban_sdk::{contracttype, symbol_short, token, Address, Env, Symbol, Vec};
 
 const INV_CALLS: Symbol = symbol_short!("InvCalls");
 #[cfg(test)]
@@ -45,6 +45,395 @@ pub(crate) fn verify_escrow_invariants(escrow: &Escrow) -> bool {
     true
 }
 
+// ---------------------------------------------------------------------------
+// Status transitions
+//
+// Every legal move between `EscrowStatus` values is listed once in
+// `TRANSITIONS`. Entrypoints ask `allows` whether an action may start from
+// the current status (reporting their usual precondition error), and every
+// status write goes through `transition`, which rejects any move that is not
+// in the table. `Released` and `Refunded` are terminal. A new status only
+// becomes reachable once rows for it are added here.
+// ---------------------------------------------------------------------------
+
+/// Operation that moves an escrow between statuses.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum StatusAction {
+    /// Full payout of the escrow to a contributor.
+    Release,
+    /// Payout of part of the remaining amount (including capability-based
+    /// releases); ends in `Released` once nothing remains.
+    PartialRelease,
+    /// Execution of an authorized pending claim; ends in `Released` once
+    /// nothing remains.
+    Claim,
+    /// Full or partial return of funds to the depositor.
+    Refund,
+    Freeze,
+    Unfreeze,
+}
+
+/// One row of the transition table returned by `get_status_transitions`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StatusTransition {
+    pub from: EscrowStatus,
+    pub to: EscrowStatus,
+    pub action: StatusAction,
+}
+
+const TRANSITIONS: &[(EscrowStatus, EscrowStatus, StatusAction)] = &[
+    (
+        EscrowStatus::Locked,
+        EscrowStatus::Released,
+        StatusAction::Release,
+    ),
+    (
+        EscrowStatus::Locked,
+        EscrowStatus::Locked,
+        StatusAction::PartialRelease,
+    ),
+    (
+        EscrowStatus::Locked,
+        EscrowStatus::Released,
+        StatusAction::PartialRelease,
+    ),
+    (
+        EscrowStatus::Locked,
+        EscrowStatus::Locked,
+        StatusAction::Claim,
+    ),
+    (
+        EscrowStatus::Locked,
+        EscrowStatus::Released,
+        StatusAction::Claim,
+    ),
+    (
+        EscrowStatus::Locked,
+        EscrowStatus::PartiallyRefunded,
+        StatusAction::Refund,
+    ),
+    (
+        EscrowStatus::Locked,
+        EscrowStatus::Refunded,
+        StatusAction::Refund,
+    ),
+    (
+        EscrowStatus::PartiallyRefunded,
+        EscrowStatus::PartiallyRefunded,
+        StatusAction::Refund,
+    ),
+    (
+        EscrowStatus::PartiallyRefunded,
+        EscrowStatus::Refunded,
+        StatusAction::Refund,
+    ),
+    (
+        EscrowStatus::Locked,
+        EscrowStatus::Frozen,
+        StatusAction::Freeze,
+    ),
+    (
+        EscrowStatus::PartiallyRefunded,
+        EscrowStatus::Frozen,
+        StatusAction::Freeze,
+    ),
+    (
+        EscrowStatus::Frozen,
+        EscrowStatus::Locked,
+        StatusAction::Unfreeze,
+    ),
+    (
+        EscrowStatus::Frozen,
+        EscrowStatus::PartiallyRefunded,
+        StatusAction::Unfreeze,
+    ),
+];
+
+/// Whether `action` may be started on an escrow in status `from`.
+pub(crate) fn allows(from: &EscrowStatus, action: StatusAction) -> bool {
+    TRANSITIONS
+        .iter()
+        .any(|(f, _, a)| f == from && *a == action)
+}
+
+/// Validate a status write: `old -> new` must be a row of the table for
+/// `action`.
+pub(crate) fn transition(
+    old: &EscrowStatus,
+    new: &EscrowStatus,
+    action: StatusAction,
+) -> Result<(), Error> {
+    if TRANSITIONS
+        .iter()
+        .any(|(f, t, a)| f == old && t == new && *a == action)
+    {
+        Ok(())
+    } else {
+        Err(Error::InvalidStatusTransition)
+    }
+}
+
+pub(crate) fn status_transitions(env: &Env) -> Vec<StatusTransition> {
+    let mut table = Vec::new(env);
+    for (from, to, action) in TRANSITIONS.iter() {
+        table.push_back(StatusTransition {
+            from: from.clone(),
+            to: to.clone(),
+            action: *action,
+        });
+    }
+    table
+}
+
+// ---------------------------------------------------------------------------
+// Contract-wide solvency
+//
+// `DataKey::TotalLiabilities` is the sum of `remaining_amount` over every
+// escrow the contract still owes (locked, partially refunded or frozen). It
+// is adjusted wherever an escrow is created or pays out, so solvency can be
+// checked in O(1): the token balance must always cover it, together with
+// the split fee shares still waiting to be withdrawn
+// (`fees::unclaimed_total`).
+// ---------------------------------------------------------------------------
+
+/// Result of `verify_solvency`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SolvencyReport {
+    pub token_balance: i128,
+    pub total_liabilities: i128,
+    /// Split fee shares held for withdrawal.
+    pub unclaimed_fees: i128,
+    /// `token_balance - total_liabilities - unclaimed_fees`; negative when
+    /// insolvent.
+    pub surplus: i128,
+    pub is_solvent: bool,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum DiscrepancyKind {
+    /// `verify_escrow_invariants` failed for the escrow.
+    InvariantViolation,
+    /// Refund history records more than has left the escrow.
+    RefundHistoryExceedsPaid,
+    /// Escrow is marked `Refunded` but still holds a remaining amount.
+    RefundedWithRemaining,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Discrepancy {
+    pub bounty_id: u64,
+    pub kind: DiscrepancyKind,
+    /// Value the check expected (e.g. maximum refundable amount).
+    pub expected: i128,
+    /// Value actually found in storage.
+    pub actual: i128,
+}
+
+/// One page of `reconcile` results.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReconciliationReport {
+    pub discrepancies: Vec<Discrepancy>,
+    /// Number of escrows examined on this page.
+    pub scanned: u32,
+    /// Sum of outstanding `remaining_amount` over the escrows on this page.
+    /// Summed over all pages it must equal `total_liabilities`.
+    pub page_liabilities: i128,
+    pub total_liabilities: i128,
+    pub token_balance: i128,
+    /// Index position for the next page, or `None` after the last escrow.
+    pub next_cursor: Option<u32>,
+}
+
+pub(crate) fn total_liabilities(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TotalLiabilities)
+        .unwrap_or(0)
+}
+
+/// Amount the contract still owes for `escrow`: its remaining amount while
+/// it can pay out, zero once it is released or refunded.
+pub(crate) fn outstanding_amount(escrow: &Escrow) -> i128 {
+    match escrow.status {
+        EscrowStatus::Locked | EscrowStatus::PartiallyRefunded | EscrowStatus::Frozen => {
+            escrow.remaining_amount
+        }
+        EscrowStatus::Released | EscrowStatus::Refunded => 0,
+    }
+}
+
+/// Move the tracked total from an escrow's outstanding amount `before` a
+/// state change to its outstanding amount `after` it.
+pub(crate) fn adjust_liabilities(env: &Env, before: i128, after: i128) {
+    let total = total_liabilities(env)
+        .checked_sub(before)
+        .and_then(|t| t.checked_add(after))
+        .expect("liability overflow");
+    if total < 0 {
+        panic!("Invariant violated: negative total liabilities");
+    }
+    env.storage()
+        .instance()
+        .set(&DataKey::TotalLiabilities, &total);
+}
+
+pub(crate) fn token_balance(env: &Env) -> i128 {
+    match env
+        .storage()
+        .instance()
+        .get::<DataKey, Address>(&DataKey::Token)
+    {
+        Some(token_addr) => {
+            token::Client::new(env, &token_addr).balance(&env.current_contract_address())
+        }
+        None => 0,
+    }
+}
+
+pub(crate) fn solvency_report(env: &Env) -> SolvencyReport {
+    let token_balance = token_balance(env);
+    let total_liabilities = total_liabilities(env);
+    let unclaimed_fees = fees::unclaimed_total(env);
+    let surplus = token_balance - total_liabilities - unclaimed_fees;
+    SolvencyReport {
+        token_balance,
+        total_liabilities,
+        unclaimed_fees,
+        surplus,
+        is_solvent: surplus >= 0,
+    }
+}
+
+/// Fail the current invocation if the balance no longer covers liabilities.
+/// Called after every outbound transfer.
+pub(crate) fn ensure_solvent(env: &Env) -> Result<(), Error> {
+    if !solvency_report(env).is_solvent {
+        return Err(Error::SolvencyViolation);
+    }
+    Ok(())
+}
+
+/// Projected form of [`ensure_solvent`] for the simulation views: would the
+/// contract stay solvent after paying out `payout` while an escrow's
+/// outstanding amount moves from `owed_before` to `owed_after`?
+pub(crate) fn ensure_solvent_after(
+    env: &Env,
+    payout: i128,
+    owed_before: i128,
+    owed_after: i128,
+) -> Result<(), Error> {
+    let balance = token_balance(env) - payout;
+    let liabilities =
+        total_liabilities(env) - owed_before + owed_after + fees::unclaimed_total(env);
+    if balance < liabilities {
+        return Err(Error::SolvencyViolation);
+    }
+    Ok(())
+}
+
+fn check_escrow(bounty_id: u64, escrow: &Escrow, out: &mut Vec<Discrepancy>) {
+    if !verify_escrow_invariants(escrow) {
+        out.push_back(Discrepancy {
+            bounty_id,
+            kind: DiscrepancyKind::InvariantViolation,
+            expected: escrow.amount,
+            actual: escrow.remaining_amount,
+        });
+    }
+
+    let paid_out = escrow.amount - escrow.remaining_amount;
+    let mut refunded: i128 = 0;
+    for record in escrow.refund_history.iter() {
+        refunded += record.amount;
+    }
+    if refunded > paid_out {
+        out.push_back(Discrepancy {
+            bounty_id,
+            kind: DiscrepancyKind::RefundHistoryExceedsPaid,
+            expected: paid_out,
+            actual: refunded,
+        });
+    }
+
+    if escrow.status == EscrowStatus::Refunded && escrow.remaining_amount != 0 {
+        out.push_back(Discrepancy {
+            bounty_id,
+            kind: DiscrepancyKind::RefundedWithRemaining,
+            expected: 0,
+            actual: escrow.remaining_amount,
+        });
+    }
+}
+
+/// Upper bound on escrows examined by a single `reconcile` call.
+pub(crate) const MAX_RECONCILE_LIMIT: u32 = 50;
+
+/// Check up to `limit` escrows starting at position `cursor` of the escrow
+/// index and report every discrepancy found.
+pub(crate) fn reconcile(env: &Env, cursor: u32, limit: u32) -> ReconciliationReport {
+    let limit = limit.min(MAX_RECONCILE_LIMIT);
+    let index: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::EscrowIndex)
+        .unwrap_or(Vec::new(env));
+
+    let end = index.len().min(cursor.saturating_add(limit));
+    let mut discrepancies = Vec::new(env);
+    let mut page_liabilities: i128 = 0;
+    let mut scanned = 0u32;
+    let mut i = cursor;
+    while i < end {
+        let bounty_id = index.get(i).unwrap();
+        if let Some(escrow) = migration::load_escrow(env, bounty_id) {
+            check_escrow(bounty_id, &escrow, &mut discrepancies);
+            page_liabilities += outstanding_amount(&escrow);
+            scanned += 1;
+        }
+        i += 1;
+    }
+
+    ReconciliationReport {
+        discrepancies,
+        scanned,
+        page_liabilities,
+        total_liabilities: total_liabilities(env),
+        token_balance: token_balance(env),
+        next_cursor: if end < index.len() { Some(end) } else { None },
+    }
+}
+
+/// Sum the outstanding amounts of up to `limit` escrows starting at
+/// position `cursor` of the escrow index. Also returns the cursor of the
+/// following page, or `None` once the index is exhausted.
+pub(crate) fn liabilities_page(env: &Env, cursor: u32, limit: u32) -> (i128, Option<u32>) {
+    let index: Vec<u64> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::EscrowIndex)
+        .unwrap_or(Vec::new(env));
+
+    let end = index.len().min(cursor.saturating_add(limit));
+    let mut liabilities: i128 = 0;
+    let mut i = cursor;
+    while i < end {
+        if let Some(escrow) = migration::load_escrow(env, index.get(i).unwrap()) {
+            liabilities = liabilities.saturating_add(outstanding_amount(&escrow));
+        }
+        i += 1;
+    }
+    (
+        liabilities,
+        if end < index.len() { Some(end) } else { None },
+    )
+}
+
 #[cfg(test)]
 pub(crate) fn reset_test_state(env: &Env) {
     env.storage().instance().set(&INV_CALLS, &0_u32);
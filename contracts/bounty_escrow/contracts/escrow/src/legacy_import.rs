@@ -0,0 +1,145 @@
+//! # Legacy Escrow Import
+//!
+//! Moves escrows out of the older `soroban/contracts/escrow` deployment.
+//! Its `export_all(cursor, limit)` view pages through its escrow index and
+//! returns records with the same fields and variant names as
+//! [`LegacyEscrow`]; pages hold at most `MAX_BATCH_SIZE` records, so the
+//! `Locked` ones of a page can be fed to `import_escrows` unchanged.
+//! Escrows locked before that index existed are added to it by the legacy
+//! admin with `index_escrows` before exporting.
+//!
+//! Only escrows still `Locked` there, with a deadline still ahead, can be
+//! imported. Each record is read back from the legacy contract with
+//! `get_escrow` and must match it field for field, so the batch cannot
+//! describe escrows the legacy contract does not hold. Each keeps its
+//! legacy bounty id, depositor, amounts and deadline.
+//!
+//! In the same call the legacy contract's `mark_migrated` closes the
+//! imported escrows there, so they can no longer be refunded or released
+//! on both contracts, and pays their remaining amounts to a funder, who
+//! transfers the sum in; the contract stays solvent. Imported escrows get
+//! metadata with bounty type [`IMPORTED_BOUNTY_TYPE`] and an
+//! [`ImportRecord`].
+
+use crate::{DataKey, Error};
+use soroban_sdk::{contractclient, contracttype, Address, Env, String, Vec};
+
+/// `bounty_type` of the metadata written for an imported escrow.
+pub const IMPORTED_BOUNTY_TYPE: &str = "legacy_import";
+
+/// Status of an escrow in the legacy contract.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum LegacyEscrowStatus {
+    Locked,
+    Released,
+    Refunded,
+    /// Closed by `mark_migrated` after an import.
+    Migrated,
+}
+
+/// One escrow as exported by the legacy contract's `export_all`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyEscrow {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    pub status: LegacyEscrowStatus,
+    pub deadline: u64,
+}
+
+/// One escrow as the legacy contract's `get_escrow` returns it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LegacyEscrowState {
+    pub depositor: Address,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    pub status: LegacyEscrowStatus,
+    pub deadline: u64,
+}
+
+/// The part of the legacy escrow contract an import relies on.
+#[allow(dead_code)]
+#[contractclient(name = "LegacyEscrowClient")]
+pub trait LegacyEscrowInterface {
+    fn get_escrow(env: Env, bounty_id: u64) -> LegacyEscrowState;
+    /// Close `bounty_ids` and pay their remaining amounts to `to`.
+    fn mark_migrated(env: Env, bounty_ids: Vec<u64>, to: Address) -> i128;
+}
+
+/// Provenance of an imported escrow.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ImportRecord {
+    /// Legacy contract the escrow came from.
+    pub source: Address,
+    pub imported_by: Address,
+    pub imported_at: u64,
+}
+
+pub fn get_record(env: &Env, bounty_id: u64) -> Option<ImportRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ImportRecord(bounty_id))
+}
+
+pub fn set_record(env: &Env, bounty_id: u64, record: &ImportRecord) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::ImportRecord(bounty_id), record);
+}
+
+pub fn bounty_type(env: &Env) -> String {
+    String::from_str(env, IMPORTED_BOUNTY_TYPE)
+}
+
+/// Checks one record of an import batch against this contract and against
+/// the legacy contract at `source`. `duplicate` says whether its bounty id
+/// is repeated within the batch.
+pub fn validate(
+    env: &Env,
+    source: &Address,
+    escrow: &LegacyEscrow,
+    duplicate: bool,
+) -> Result<(), Error> {
+    // An imported escrow is an escrow here, so importing it again is
+    // refused like any other taken bounty id.
+    if get_record(env, escrow.bounty_id).is_some()
+        || env
+            .storage()
+            .persistent()
+            .has(&DataKey::Escrow(escrow.bounty_id))
+    {
+        return Err(Error::BountyExists);
+    }
+    let held = match LegacyEscrowClient::new(env, source).try_get_escrow(&escrow.bounty_id) {
+        Ok(Ok(held)) => held,
+        _ => return Err(Error::ImportMismatch),
+    };
+    if held.depositor != escrow.depositor
+        || held.amount != escrow.amount
+        || held.remaining_amount != escrow.remaining_amount
+        || held.status != escrow.status
+        || held.deadline != escrow.deadline
+    {
+        return Err(Error::ImportMismatch);
+    }
+    if escrow.status != LegacyEscrowStatus::Locked {
+        return Err(Error::FundsNotLocked);
+    }
+    if escrow.remaining_amount <= 0 || escrow.remaining_amount > escrow.amount {
+        return Err(Error::InvalidAmount);
+    }
+    // Past its deadline the depositor can already refund on the legacy
+    // contract; moving the funds would only race that refund.
+    if escrow.deadline <= env.ledger().timestamp() {
+        return Err(Error::InvalidDeadline);
+    }
+    if duplicate {
+        return Err(Error::DuplicateBountyId);
+    }
+    Ok(())
+}
@@ -1,7 +1,18 @@
 #![no_std]
+mod access_list;
+mod attestation;
+mod audit_log;
+mod claims;
+mod contributors;
+mod deadlines;
+mod deny_list;
 #[allow(dead_code)]
 mod events;
+mod fees;
+mod hooks;
 mod invariants;
+mod legacy_import;
+mod migration;
 #[cfg(test)]
 mod test_metadata;
 #[cfg(test)]
@@ -9,44 +20,49 @@ mod test_token_math;
 pub mod token_math;
 
 mod reentrancy_guard;
+mod simulation;
 mod test_cross_contract_interface;
 #[cfg(test)]
-mod test_rbac;
-#[cfg(test)]
 mod test_multi_token_fees;
-mod traits;
+#[cfg(test)]
+mod test_rbac;
+mod units;
+mod validation;
 
 use events::{
     emit_batch_funds_locked, emit_batch_funds_released, emit_bounty_initialized, emit_funds_locked,
     emit_funds_refunded, emit_funds_released, BatchFundsLocked, BatchFundsReleased,
-    BountyEscrowInitialized, ClaimCancelled, ClaimCreated, ClaimExecuted, FundsLocked,
-    FundsRefunded, FundsReleased, EVENT_VERSION_V2,
+    BountyEscrowInitialized, ClaimCancelled, ClaimCreated, ClaimExecuted, FeeOperationType,
+    FundsLocked, FundsRefunded, FundsReleased, EVENT_VERSION_V2, EVENT_VERSION_V3,
 };
 use soroban_sdk::{
-    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
-    Symbol, Vec,
+    contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, BytesN,
+    Env, Map, Symbol, Vec,
 };
 
 mod monitoring {
-    use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
+    //! Operational metrics for the escrow contract.
+    //!
+    //! Every successful state-changing entrypoint calls `track_operation`,
+    //! which maintains global and per-operation counters, the set of unique
+    //! callers and the timestamp of the last operation. It only writes
+    //! counters; each operation already emits its own domain event. Failures are counted
+    //! per error code through `track_error`.
+    //!
+    //! Soroban rolls back every storage write of a failed invocation, so an
+    //! entrypoint that returns `Err` cannot persist its own failure. Error
+    //! counters are therefore fed by paths that absorb an error without
+    //! aborting the call (e.g. per-item failures of best-effort batches).
+
+    use crate::{invariants, BountyEscrowContract, PauseFlags};
+    use grainlify_core::escrow_interface::EscrowInterface;
+    use soroban_sdk::{contracttype, symbol_short, Address, Env, Symbol};
 
     // Storage keys
-    #[allow(dead_code)]
     const OPERATION_COUNT: &str = "op_count";
-    #[allow(dead_code)]
     const USER_COUNT: &str = "usr_count";
-    #[allow(dead_code)]
     const ERROR_COUNT: &str = "err_count";
-
-    // Event: Operation metric
-    #[contracttype]
-    #[derive(Clone, Debug)]
-    pub struct OperationMetric {
-        pub operation: Symbol,
-        pub caller: Address,
-        pub timestamp: u64,
-        pub success: bool,
-    }
+    const LAST_OPERATION: &str = "last_op";
 
     // Event: Performance metric
     #[contracttype]
@@ -61,10 +77,17 @@ mod monitoring {
     #[contracttype]
     #[derive(Clone, Debug)]
     pub struct HealthStatus {
+        /// True when nothing is paused and the token balance covers all
+        /// outstanding liabilities. Per-escrow invariants are checked page
+        /// by page with `reconcile`.
         pub is_healthy: bool,
+        pub is_paused: bool,
+        pub is_solvent: bool,
+        pub token_balance: i128,
+        pub total_liabilities: i128,
         pub last_operation: u64,
         pub total_operations: u64,
-        pub contract_version: String,
+        pub contract_version: u32,
     }
 
     // Data: Analytics
@@ -73,7 +96,10 @@ mod monitoring {
     pub struct Analytics {
         pub operation_count: u64,
         pub unique_users: u64,
+        /// Failures absorbed without aborting the call. Invocations that
+        /// return `Err` are rolled back and never counted here.
         pub error_count: u64,
+        /// `error_count` per 10_000 operations.
         pub error_rate: u32,
     }
 
@@ -98,28 +124,58 @@ mod monitoring {
         pub last_called: u64,
     }
 
+    fn get_u64(env: &Env, name: &str) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, name))
+            .unwrap_or(0)
+    }
+
     // Track operation
-    #[allow(dead_code)]
     pub fn track_operation(env: &Env, operation: Symbol, caller: Address, success: bool) {
+        let now = env.ledger().timestamp();
         let key = Symbol::new(env, OPERATION_COUNT);
         let count: u64 = env.storage().persistent().get(&key).unwrap_or(0);
         env.storage().persistent().set(&key, &(count + 1));
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, LAST_OPERATION), &now);
+
+        let op_key = (Symbol::new(env, "perf_cnt"), operation.clone());
+        let op_count: u64 = env.storage().persistent().get(&op_key).unwrap_or(0);
+        env.storage().persistent().set(&op_key, &(op_count + 1));
+        env.storage()
+            .persistent()
+            .set(&(Symbol::new(env, "perf_last"), operation), &now);
+
+        let seen_key = (Symbol::new(env, "usr_seen"), caller);
+        if !env.storage().persistent().has(&seen_key) {
+            env.storage().persistent().set(&seen_key, &true);
+            let usr_key = Symbol::new(env, USER_COUNT);
+            let users: u64 = env.storage().persistent().get(&usr_key).unwrap_or(0);
+            env.storage().persistent().set(&usr_key, &(users + 1));
+        }
 
         if !success {
             let err_key = Symbol::new(env, ERROR_COUNT);
             let err_count: u64 = env.storage().persistent().get(&err_key).unwrap_or(0);
             env.storage().persistent().set(&err_key, &(err_count + 1));
         }
+    }
 
-        env.events().publish(
-            (symbol_short!("metric"), symbol_short!("op")),
-            OperationMetric {
-                operation,
-                caller,
-                timestamp: env.ledger().timestamp(),
-                success,
-            },
-        );
+    /// Record a failed operation under its error code.
+    pub fn track_error(env: &Env, operation: Symbol, caller: Address, error: crate::Error) {
+        let code_key = (Symbol::new(env, "err_code"), error as u32);
+        let count: u64 = env.storage().persistent().get(&code_key).unwrap_or(0);
+        env.storage().persistent().set(&code_key, &(count + 1));
+        track_operation(env, operation, caller, false);
+    }
+
+    pub fn get_error_count(env: &Env, error_code: u32) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&(Symbol::new(env, "err_code"), error_code))
+            .unwrap_or(0)
     }
 
     // Track performance
@@ -147,29 +203,31 @@ mod monitoring {
     }
 
     // Health check
-    #[allow(dead_code)]
     pub fn health_check(env: &Env) -> HealthStatus {
-        let key = Symbol::new(env, OPERATION_COUNT);
-        let ops: u64 = env.storage().persistent().get(&key).unwrap_or(0);
+        let flags: PauseFlags = BountyEscrowContract::get_pause_flags(env);
+        let is_paused = flags.lock_paused || flags.release_paused || flags.refund_paused;
+
+        // Tracked totals only: scanning every escrow would not fit a
+        // single invocation's read budget.
+        let solvency = invariants::solvency_report(env);
 
         HealthStatus {
-            is_healthy: true,
-            last_operation: env.ledger().timestamp(),
-            total_operations: ops,
-            contract_version: String::from_str(env, "1.0.0"),
+            is_healthy: !is_paused && solvency.is_solvent,
+            is_paused,
+            is_solvent: solvency.is_solvent,
+            token_balance: solvency.token_balance,
+            total_liabilities: solvency.total_liabilities,
+            last_operation: get_u64(env, LAST_OPERATION),
+            total_operations: get_u64(env, OPERATION_COUNT),
+            contract_version: BountyEscrowContract::get_version(env.clone()),
         }
     }
 
     // Get analytics
-    #[allow(dead_code)]
     pub fn get_analytics(env: &Env) -> Analytics {
-        let op_key = Symbol::new(env, OPERATION_COUNT);
-        let usr_key = Symbol::new(env, USER_COUNT);
-        let err_key = Symbol::new(env, ERROR_COUNT);
-
-        let ops: u64 = env.storage().persistent().get(&op_key).unwrap_or(0);
-        let users: u64 = env.storage().persistent().get(&usr_key).unwrap_or(0);
-        let errors: u64 = env.storage().persistent().get(&err_key).unwrap_or(0);
+        let ops = get_u64(env, OPERATION_COUNT);
+        let users = get_u64(env, USER_COUNT);
+        let errors = get_u64(env, ERROR_COUNT);
 
         let error_rate = if ops > 0 {
             ((errors as u128 * 10000) / ops as u128) as u32
@@ -186,22 +244,16 @@ mod monitoring {
     }
 
     // Get state snapshot
-    #[allow(dead_code)]
     pub fn get_state_snapshot(env: &Env) -> StateSnapshot {
-        let op_key = Symbol::new(env, OPERATION_COUNT);
-        let usr_key = Symbol::new(env, USER_COUNT);
-        let err_key = Symbol::new(env, ERROR_COUNT);
-
         StateSnapshot {
             timestamp: env.ledger().timestamp(),
-            total_operations: env.storage().persistent().get(&op_key).unwrap_or(0),
-            total_users: env.storage().persistent().get(&usr_key).unwrap_or(0),
-            total_errors: env.storage().persistent().get(&err_key).unwrap_or(0),
+            total_operations: get_u64(env, OPERATION_COUNT),
+            total_users: get_u64(env, USER_COUNT),
+            total_errors: get_u64(env, ERROR_COUNT),
         }
     }
 
     // Get performance stats
-    #[allow(dead_code)]
     pub fn get_performance_stats(env: &Env, function_name: Symbol) -> PerformanceStats {
         let count_key = (Symbol::new(env, "perf_cnt"), function_name.clone());
         let time_key = (Symbol::new(env, "perf_time"), function_name.clone());
@@ -224,7 +276,8 @@ mod monitoring {
 }
 
 mod anti_abuse {
-    use soroban_sdk::{contracttype, symbol_short, Address, Env};
+    use crate::{Error, RateLimitOperation};
+    use soroban_sdk::{contracttype, Address, Env};
 
     #[contracttype]
     #[derive(Clone, Debug, Eq, PartialEq)]
@@ -246,7 +299,8 @@ mod anti_abuse {
     #[derive(Clone, Debug, Eq, PartialEq)]
     pub enum AntiAbuseKey {
         Config,
-        State(Address),
+        OperationConfig(RateLimitOperation),
+        State(RateLimitOperation, Address),
         Whitelist(Address),
         Admin,
     }
@@ -262,11 +316,52 @@ mod anti_abuse {
             })
     }
 
-    #[allow(dead_code)]
     pub fn set_config(env: &Env, config: AntiAbuseConfig) {
         env.storage().instance().set(&AntiAbuseKey::Config, &config);
     }
 
+    /// Effective limit for `operation`.
+    ///
+    /// An explicit per-operation config always wins. Without one, `Lock`
+    /// falls back to the global config (the historical behaviour) while
+    /// `Claim` and `Capability` are not rate limited at all.
+    pub fn get_operation_config(
+        env: &Env,
+        operation: &RateLimitOperation,
+    ) -> Option<AntiAbuseConfig> {
+        let explicit: Option<AntiAbuseConfig> = env
+            .storage()
+            .instance()
+            .get(&AntiAbuseKey::OperationConfig(operation.clone()));
+        match (explicit, operation) {
+            (Some(config), _) => Some(config),
+            (None, RateLimitOperation::Lock) => Some(get_config(env)),
+            (None, _) => None,
+        }
+    }
+
+    pub fn set_operation_config(
+        env: &Env,
+        operation: RateLimitOperation,
+        config: Option<AntiAbuseConfig>,
+    ) {
+        let key = AntiAbuseKey::OperationConfig(operation);
+        match config {
+            Some(config) => env.storage().instance().set(&key, &config),
+            None => env.storage().instance().remove(&key),
+        }
+    }
+
+    pub fn get_state(
+        env: &Env,
+        address: &Address,
+        operation: &RateLimitOperation,
+    ) -> Option<AddressState> {
+        env.storage()
+            .persistent()
+            .get(&AntiAbuseKey::State(operation.clone(), address.clone()))
+    }
+
     pub fn is_whitelisted(env: &Env, address: Address) -> bool {
         env.storage()
             .instance()
@@ -293,24 +388,29 @@ mod anti_abuse {
         env.storage().instance().set(&AntiAbuseKey::Admin, &admin);
     }
 
-    pub fn check_rate_limit(env: &Env, address: Address) {
+    /// Run the cooldown and window checks without writing anything.
+    ///
+    /// Returns the state to persist if the operation goes ahead, or `None`
+    /// when the address/operation pair is not subject to any limit.
+    fn evaluate(
+        env: &Env,
+        address: &Address,
+        operation: &RateLimitOperation,
+    ) -> Result<Option<AddressState>, Error> {
         if is_whitelisted(env, address.clone()) {
-            return;
+            return Ok(None);
         }
+        let config = match get_operation_config(env, operation) {
+            Some(config) => config,
+            None => return Ok(None),
+        };
 
-        let config = get_config(env);
         let now = env.ledger().timestamp();
-        let key = AntiAbuseKey::State(address.clone());
-
-        let mut state: AddressState =
-            env.storage()
-                .persistent()
-                .get(&key)
-                .unwrap_or(AddressState {
-                    last_operation_timestamp: 0,
-                    window_start_timestamp: now,
-                    operation_count: 0,
-                });
+        let mut state = get_state(env, address, operation).unwrap_or(AddressState {
+            last_operation_timestamp: 0,
+            window_start_timestamp: now,
+            operation_count: 0,
+        });
 
         // 1. Cooldown check
         if state.last_operation_timestamp > 0
@@ -319,11 +419,7 @@ mod anti_abuse {
                     .last_operation_timestamp
                     .saturating_add(config.cooldown_period)
         {
-            env.events().publish(
-                (symbol_short!("abuse"), symbol_short!("cooldown")),
-                (address.clone(), now),
-            );
-            panic!("Operation in cooldown period");
+            return Err(Error::CooldownActive);
         }
 
         // 2. Window check
@@ -338,20 +434,40 @@ mod anti_abuse {
         } else {
             // Same window
             if state.operation_count >= config.max_operations {
-                env.events().publish(
-                    (symbol_short!("abuse"), symbol_short!("limit")),
-                    (address.clone(), now),
-                );
-                panic!("Rate limit exceeded");
+                return Err(Error::RateLimited);
             }
             state.operation_count += 1;
         }
 
         state.last_operation_timestamp = now;
-        env.storage().persistent().set(&key, &state);
+        Ok(Some(state))
+    }
+
+    /// Non-mutating variant of [`check_rate_limit`] used by the simulation views.
+    pub fn peek_rate_limit(
+        env: &Env,
+        address: &Address,
+        operation: RateLimitOperation,
+    ) -> Result<(), Error> {
+        evaluate(env, address, &operation).map(|_| ())
+    }
+
+    /// Record `operation` for `address`, or fail with `CooldownActive` /
+    /// `RateLimited`. A rejection is reported through the error alone: the
+    /// caller's invocation reverts, so no event could be published for it.
+    pub fn check_rate_limit(
+        env: &Env,
+        address: Address,
+        operation: RateLimitOperation,
+    ) -> Result<(), Error> {
+        if let Some(state) = evaluate(env, &address, &operation)? {
+            let key = AntiAbuseKey::State(operation, address);
+            env.storage().persistent().set(&key, &state);
 
-        // Extend TTL for state (approx 1 day)
-        env.storage().persistent().extend_ttl(&key, 17280, 17280);
+            // Extend TTL for state (approx 1 day)
+            env.storage().persistent().extend_ttl(&key, 17280, 17280);
+        }
+        Ok(())
     }
 }
 
@@ -359,7 +475,11 @@ const MAX_FEE_RATE: i128 = token_math::MAX_FEE_RATE;
 const MAX_BATCH_SIZE: u32 = 20;
 
 extern crate grainlify_core;
+use contributors::Payout;
 use grainlify_core::asset;
+use grainlify_core::escrow_interface::{self, EscrowInterface};
+use migration::{MigrationState, UpgradeRecord, CONTRACT_VERSION, MIGRATION_PAGE_SIZE};
+use validation::Mode;
 
 #[contracterror]
 #[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
@@ -401,6 +521,50 @@ pub enum Error {
     CapabilityUsesExhausted = 28,
     CapabilityExceedsAuthority = 29,
     InvalidAssetId = 30,
+    /// Returned when the caller exceeded `max_operations` in the current window
+    RateLimited = 31,
+    /// Returned when the caller acts again before its cooldown period elapsed
+    CooldownActive = 32,
+    /// Returned when the bounty is frozen for investigation
+    EscrowFrozen = 33,
+    /// Returned when unfreezing a bounty that is not frozen
+    EscrowNotFrozen = 34,
+    /// Returned when the payout recipient is on the deny-list
+    RecipientBlocked = 35,
+    /// Returned when a payout at or above the attestation threshold goes to
+    /// a recipient without a valid attestation
+    AttestationRequired = 36,
+    /// Returned when the attestation threshold or level is invalid
+    InvalidAttestationConfig = 37,
+    /// Returned when an outbound transfer would leave the token balance
+    /// below the sum of outstanding escrow liabilities
+    SolvencyViolation = 38,
+    /// Returned when a status write is not a row of the transition table
+    InvalidStatusTransition = 39,
+    /// Returned when registering a hook that is already registered
+    HookAlreadyRegistered = 40,
+    /// Returned when removing a hook that is not registered
+    HookNotRegistered = 41,
+    /// Returned when registering more than `MAX_HOOKS` hooks
+    TooManyHooks = 42,
+    /// Returned when an upgrade lacks the required multisig approvals
+    UpgradeNotApproved = 43,
+    /// Returned when fee tiers are unsorted or too many, or fee caps are invalid
+    InvalidFeeSchedule = 44,
+    /// Returned when fee split shares are negative or do not sum to 10_000 bp
+    InvalidFeeSplit = 45,
+    /// Returned when withdrawing fees with no claimable balance
+    NothingToWithdraw = 46,
+    /// Returned when a depositor names themselves as the referrer
+    InvalidReferrer = 47,
+    /// Returned when executing or redirecting a claim after its window
+    ClaimExpired = 48,
+    /// Returned when a claim has already been paid out
+    ClaimAlreadyExecuted = 49,
+    /// Returned when an access list bars a depositor or recipient
+    ParticipantNotAllowed = 50,
+    /// Returned when an imported record differs from the legacy contract's
+    ImportMismatch = 51,
 }
 
 #[contracttype]
@@ -418,6 +582,9 @@ pub enum EscrowStatus {
     Released,
     Refunded,
     PartiallyRefunded,
+    /// Held for investigation; the status before freezing is kept in the
+    /// bounty's `FreezeRecord` and restored by `unfreeze_escrow`.
+    Frozen,
 }
 
 #[contracttype]
@@ -446,13 +613,44 @@ pub enum DataKey {
     RefundApproval(u64),     // bounty_id -> RefundApproval
     ReentrancyGuard,
     MultisigConfig,
-    ReleaseApproval(u64), // bounty_id -> ReleaseApproval
-    PendingClaim(u64),    // bounty_id -> ClaimRecord
-    ClaimWindow,          // u64 seconds (global config)
-    PauseFlags,           // PauseFlags struct
+    ReleaseApproval(u64),        // bounty_id -> ReleaseApproval
+    PendingClaim(u64),           // bounty_id -> ClaimRecord
+    ClaimWindow,                 // u64 seconds (global config)
+    PauseFlags,                  // PauseFlags struct
     AmountPolicy, // Option<(i128, i128)> — (min_amount, max_amount) set by set_amount_policy
     CapabilityNonce, // monotonically increasing capability id
     Capability(u64), // capability_id -> Capability
+    EscrowFreeze(u64), // bounty_id -> FreezeRecord
+    DenyListed(Address), // recipient -> DenyListEntry
+    AttestationConfig, // AttestationConfig (optional payout gate)
+    AuditCursor(u64), // bounty_id -> AuditCursor
+    AuditPage(u64, u64), // (bounty_id, page) -> Vec<AuditEntry>
+    TotalLiabilities, // i128 sum of remaining amounts over active escrows
+    EventSchema,  // EventSchema selecting v2, v3 or dual emission
+    ContributorIndex(Address), // Vec<u64> of bounty_ids the contributor was paid from
+    ContributorStats(Address), // ContributorStats running totals
+    PayoutHooks,  // Vec<Address> of hook contracts notified on payouts
+    Version,      // u32 contract version written at init and by migrate
+    UpgradeApproval(BytesN<32>), // wasm_hash -> Vec<Address> of signer approvals
+    UpgradeHistory, // Vec<UpgradeRecord>
+    MigrationState, // MigrationState of the last migrate call
+    TokenDecimals(Address), // token -> u32 cached decimals()
+    TokenAmountPolicy(Address), // token -> units::AmountPolicy in base units
+    FeeSchedule,  // fees::FeeSchedule tiers and caps
+    FeeOverride(Address), // depositor -> fees::FeeOverride
+    FeeSplit,     // fees::FeeSplit shares of each collected fee
+    FeeBalance(Address), // owner -> i128 fee shares not yet withdrawn
+    UnclaimedFees, // i128 sum of every FeeBalance
+    RepoTreasury(u64), // repo_id -> maintainer treasury Address
+    Referrer(u64), // bounty_id -> referrer Address set at lock
+    ClaimRedirect(u64), // bounty_id -> payout Address chosen by the claim recipient
+    ClaimWindowOverride(u64), // bounty_id -> u64 seconds, replacing ClaimWindow
+    AccessMode(AccessRole), // role -> AccessMode (default Open)
+    AccessListed(AccessRole, AccessListKind, Address), // presence marks a list entry
+    DeadlineMode(u64), // bounty_id -> DeadlineMode, absent for absolute deadlines
+    Assignment(u64), // bounty_id -> Assignment
+    LastActivity(u64), // bounty_id -> u64 timestamp, inactivity-mode escrows only
+    ImportRecord(u64), // bounty_id -> ImportRecord for escrows moved from the legacy contract
 }
 
 #[contracttype]
@@ -462,6 +660,17 @@ pub struct EscrowWithId {
     pub escrow: Escrow,
 }
 
+/// Why and by whom a bounty was frozen.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FreezeRecord {
+    pub reason: soroban_sdk::String,
+    pub frozen_by: Address,
+    pub frozen_at: u64,
+    /// Status to restore when the bounty is unfrozen.
+    pub previous_status: EscrowStatus,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PauseFlags {
@@ -481,6 +690,8 @@ pub struct AggregateStats {
     pub count_locked: u32,
     pub count_released: u32,
     pub count_refunded: u32,
+    pub total_frozen: i128,
+    pub count_frozen: u32,
 }
 
 #[contracttype]
@@ -493,6 +704,41 @@ pub struct PauseStateChanged {
     pub timestamp: u64,
 }
 
+/// Operations that carry their own, independent rate limit.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum RateLimitOperation {
+    Lock,
+    Claim,
+    Capability,
+}
+
+/// Rate-limit bookkeeping of one address for a single operation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OperationRateLimitState {
+    /// `false` when no limit applies (no config for the operation, or the
+    /// address is whitelisted).
+    pub enforced: bool,
+    pub operation_count: u32,
+    pub window_start_timestamp: u64,
+    pub last_operation_timestamp: u64,
+    /// Operations still allowed in the current window (`u32::MAX` when not enforced).
+    pub remaining_operations: u32,
+    /// Earliest timestamp at which the next operation clears the cooldown.
+    pub cooldown_ends_at: u64,
+}
+
+/// Rate-limit state of an address across every limited operation.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimitState {
+    pub whitelisted: bool,
+    pub lock: OperationRateLimitState,
+    pub claim: OperationRateLimitState,
+    pub capability: OperationRateLimitState,
+}
+
 /// Public view of anti-abuse config (rate limit and cooldown).
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -579,8 +825,8 @@ pub struct RefundApproval {
 
 /// Result returned by dry-run simulation entrypoints.
 ///
-/// These view functions run the full validation pipeline for lock / release /
-/// refund without mutating any state.  They allow UIs and integrators to
+/// These view functions run the same validation as the real entrypoints
+/// without mutating any state.  They allow UIs and integrators to
 /// preview what *would* happen and surface errors before submitting a real
 /// transaction.
 #[contracttype]
@@ -598,6 +844,10 @@ pub struct SimulationResult {
     pub resulting_status: EscrowStatus,
     /// Remaining amount in the escrow *after* the simulated operation.
     pub remaining_amount: i128,
+    /// `amount` in whole-token units of the escrow token.
+    pub amount_display: units::DisplayAmount,
+    /// `remaining_amount` in whole-token units of the escrow token.
+    pub remaining_display: units::DisplayAmount,
 }
 
 #[contracttype]
@@ -625,9 +875,52 @@ pub struct ReleaseFundsItem {
     pub contributor: Address,
 }
 
+/// One payout of `batch_partial_release`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PartialReleaseItem {
+    pub bounty_id: u64,
+    pub contributor: Address,
+    pub amount: i128,
+}
+
+/// One claim of `batch_authorize_claims`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimAuthorizationItem {
+    pub bounty_id: u64,
+    pub recipient: Address,
+}
+
+/// Per-item outcome of a best-effort batch, in input order.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BatchItemResult {
+    pub bounty_id: u64,
+    pub ok: bool,
+    /// The `Error` code the item was skipped with, or zero when `ok`.
+    pub error_code: u32,
+}
+
 #[contract]
 pub struct BountyEscrowContract;
 
+pub use access_list::{AccessListKind, AccessMode, AccessRole};
+pub use attestation::AttestationConfig;
+pub use audit_log::{AuditAction, AuditCursor, AuditEntry, AuditLogPage};
+pub use claims::ClaimSweepReport;
+pub use contributors::ContributorStats;
+pub use deadlines::{Assignment, DeadlineMode};
+pub use deny_list::DenyListEntry;
+pub use events::{BountyEventKind, BountyEventV3, EventSchema};
+pub use fees::{FeeOverride, FeeQuote, FeeSchedule, FeeSplit, FeeTier};
+pub use invariants::{
+    Discrepancy, DiscrepancyKind, ReconciliationReport, SolvencyReport, StatusAction,
+    StatusTransition,
+};
+pub use legacy_import::{ImportRecord, LegacyEscrow, LegacyEscrowState, LegacyEscrowStatus};
+pub use monitoring::{Analytics, HealthStatus, PerformanceStats, StateSnapshot};
+
 #[contractimpl]
 impl BountyEscrowContract {
     /// Initialize the contract with the admin address and the token address (XLM).
@@ -641,17 +934,23 @@ impl BountyEscrowContract {
         env.storage()
             .instance()
             .set(&DataKey::Token, &normalized_token);
+        env.storage()
+            .instance()
+            .set(&DataKey::Version, &CONTRACT_VERSION);
+        units::try_cache_decimals(&env, &normalized_token);
 
         emit_bounty_initialized(
             &env,
             BountyEscrowInitialized {
                 version: EVENT_VERSION_V2,
-                admin,
+                admin: admin.clone(),
                 token: normalized_token,
                 timestamp: env.ledger().timestamp(),
             },
         );
 
+        monitoring::track_operation(&env, symbol_short!("init"), admin.clone(), true);
+
         Ok(())
     }
 
@@ -728,9 +1027,228 @@ impl BountyEscrowContract {
             },
         );
 
+        monitoring::track_operation(&env, symbol_short!("fee_cfg"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    /// Set or clear (`None`) the tiered fee schedule (admin only).
+    ///
+    /// Tiers must be sorted by strictly increasing `min_amount`, and each
+    /// rate must be within `0..=MAX_FEE_RATE`. Fees are only charged while
+    /// `FeeConfig::fee_enabled` is set.
+    pub fn set_fee_schedule(env: Env, schedule: Option<fees::FeeSchedule>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if let Some(schedule) = &schedule {
+            fees::validate_schedule(schedule)?;
+        }
+        fees::set_schedule(&env, &schedule);
+
+        events::emit_fee_schedule_updated(
+            &env,
+            events::FeeScheduleUpdated {
+                version: EVENT_VERSION_V2,
+                tier_count: schedule.as_ref().map(|s| s.tiers.len()).unwrap_or(0),
+                min_fee: schedule.as_ref().map(|s| s.min_fee).unwrap_or(0),
+                max_fee: schedule.as_ref().and_then(|s| s.max_fee),
+                cleared: schedule.is_none(),
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("fee_sched"), admin, true);
+        Ok(())
+    }
+
+    pub fn get_fee_schedule(env: Env) -> Option<fees::FeeSchedule> {
+        fees::get_schedule(&env)
+    }
+
+    /// Set or remove (`None`) the fee rates applied to one depositor's
+    /// escrows in place of the schedule (admin only).
+    pub fn set_fee_override(
+        env: Env,
+        depositor: Address,
+        fee_override: Option<fees::FeeOverride>,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if let Some(fee_override) = &fee_override {
+            fees::validate_override(fee_override)?;
+        }
+        fees::set_override(&env, &depositor, &fee_override);
+
+        events::emit_fee_override_updated(
+            &env,
+            events::FeeOverrideUpdated {
+                version: EVENT_VERSION_V2,
+                depositor,
+                lock_fee_rate: fee_override.as_ref().map(|o| o.lock_fee_rate),
+                release_fee_rate: fee_override.as_ref().map(|o| o.release_fee_rate),
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("fee_ovr"), admin, true);
+        Ok(())
+    }
+
+    pub fn get_fee_override(env: Env, depositor: Address) -> Option<fees::FeeOverride> {
+        fees::get_override(&env, &depositor)
+    }
+
+    /// Set or clear (`None`) how collected fees are shared between the
+    /// platform, the repository maintainers and the referrer (admin only).
+    ///
+    /// While a split is set, fees stay in the contract and accrue to
+    /// balances that each party withdraws with `withdraw_fees`.
+    pub fn set_fee_split(env: Env, split: Option<fees::FeeSplit>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if let Some(split) = &split {
+            fees::validate_split(split)?;
+        }
+        fees::set_split(&env, &split);
+
+        events::emit_fee_split_updated(
+            &env,
+            events::FeeSplitUpdated {
+                version: EVENT_VERSION_V2,
+                platform_bps: split.as_ref().map(|s| s.platform_bps).unwrap_or(0),
+                maintainer_bps: split.as_ref().map(|s| s.maintainer_bps).unwrap_or(0),
+                referrer_bps: split.as_ref().map(|s| s.referrer_bps).unwrap_or(0),
+                cleared: split.is_none(),
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("fee_split"), admin, true);
+        Ok(())
+    }
+
+    pub fn get_fee_split(env: Env) -> Option<fees::FeeSplit> {
+        fees::get_split(&env)
+    }
+
+    /// Set or remove (`None`) the maintainer treasury of `repo_id` (admin
+    /// only). Escrows whose metadata names the repository credit their
+    /// maintainer fee share to it.
+    pub fn set_repo_treasury(
+        env: Env,
+        repo_id: u64,
+        treasury: Option<Address>,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        fees::set_repo_treasury(&env, repo_id, &treasury);
+
+        events::emit_repo_treasury_updated(
+            &env,
+            events::RepoTreasuryUpdated {
+                version: EVENT_VERSION_V2,
+                repo_id,
+                treasury,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("repo_trsy"), admin, true);
         Ok(())
     }
 
+    pub fn get_repo_treasury(env: Env, repo_id: u64) -> Option<Address> {
+        fees::get_repo_treasury(&env, repo_id)
+    }
+
+    /// Fee shares accrued to `owner` and not yet withdrawn.
+    pub fn get_fee_balance(env: Env, owner: Address) -> i128 {
+        fees::balance(&env, &owner)
+    }
+
+    /// Transfer every fee share accrued to `owner` to them. Returns the
+    /// amount withdrawn.
+    pub fn withdraw_fees(env: Env, owner: Address) -> Result<i128, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+        owner.require_auth();
+
+        // EFFECTS: clear the balance before the transfer
+        let amount = fees::take_balance(&env, &owner)?;
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Token)
+            .ok_or(Error::NotInitialized)?;
+        token::Client::new(&env, &token_addr).transfer(
+            &env.current_contract_address(),
+            &owner,
+            &amount,
+        );
+        invariants::ensure_solvent(&env)?;
+
+        events::emit_fees_withdrawn(
+            &env,
+            events::FeesWithdrawn {
+                version: EVENT_VERSION_V2,
+                owner: owner.clone(),
+                amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("fee_wdraw"), owner, true);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(amount)
+    }
+
+    /// Fee that `op` would charge on `amount` for an escrow of `depositor`.
+    ///
+    /// Uses the same calculation as `lock_funds` (lock fee on the deposit)
+    /// and the release paths (release fee on each payout).
+    pub fn quote_fee(
+        env: Env,
+        depositor: Address,
+        amount: i128,
+        op: FeeOperationType,
+    ) -> Result<fees::FeeQuote, Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+        if amount < 0 {
+            return Err(Error::InvalidAmount);
+        }
+        Ok(fees::quote(&env, &depositor, amount, op))
+    }
+
     /// Update pause flags (admin only)
     pub fn set_paused(
         env: Env,
@@ -807,11 +1325,19 @@ impl BountyEscrowContract {
         }
 
         env.storage().instance().set(&DataKey::PauseFlags, &flags);
+
+        monitoring::track_operation(&env, symbol_short!("pause"), admin.clone(), true);
+
         Ok(())
     }
 
     /// Emergency withdraw all funds (admin only, must have lock_paused = true)
     ///
+    /// Drains the whole token balance without settling escrows, so it is the
+    /// one outbound path that does not run the solvency check; afterwards
+    /// `verify_solvency` reports the contract as insolvent until the
+    /// outstanding escrows are resolved.
+    ///
     /// # Reentrancy
     /// Protected by the shared reentrancy guard. The token transfer is the
     /// last operation (checks-effects-interactions).
@@ -830,6 +1356,7 @@ impl BountyEscrowContract {
         if !flags.lock_paused {
             return Err(Error::NotPaused);
         }
+        deny_list::ensure_recipient_allowed(&env, &target)?;
 
         let token_address: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let token_client = token::TokenClient::new(&env, &token_address);
@@ -843,7 +1370,7 @@ impl BountyEscrowContract {
             events::emit_emergency_withdraw(
                 &env,
                 events::EmergencyWithdrawEvent {
-                    admin,
+                    admin: admin.clone(),
                     recipient: target,
                     amount: balance,
                     timestamp: env.ledger().timestamp(),
@@ -851,14 +1378,867 @@ impl BountyEscrowContract {
             );
         }
 
+        monitoring::track_operation(&env, symbol_short!("emrg_wd"), admin.clone(), true);
+
         // GUARD: release reentrancy lock
         reentrancy_guard::release(&env);
         Ok(())
     }
 
-    /// Get current pause flags
-    pub fn get_pause_flags(env: &Env) -> PauseFlags {
-        env.storage()
+    /// Freeze a single bounty for investigation (admin only).
+    ///
+    /// Unlike `set_paused`, which halts an operation for every bounty, this
+    /// blocks every state-changing path (release, refund, claims and their
+    /// capability variants) for `bounty_id` only. The escrow reports
+    /// `EscrowStatus::Frozen` until `unfreeze_escrow` restores its prior status.
+    pub fn freeze_escrow(
+        env: Env,
+        bounty_id: u64,
+        reason: soroban_sdk::String,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let mut escrow: Escrow =
+            migration::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        Self::ensure_not_frozen(&env, bounty_id)?;
+        if !invariants::allows(&escrow.status, StatusAction::Freeze) {
+            return Err(Error::FundsNotLocked);
+        }
+
+        let now = env.ledger().timestamp();
+        let record = FreezeRecord {
+            reason: reason.clone(),
+            frozen_by: admin.clone(),
+            frozen_at: now,
+            previous_status: escrow.status.clone(),
+        };
+        invariants::transition(&escrow.status, &EscrowStatus::Frozen, StatusAction::Freeze)?;
+        escrow.status = EscrowStatus::Frozen;
+        invariants::assert_escrow(&env, &escrow);
+        migration::store_escrow(&env, bounty_id, &escrow);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowFreeze(bounty_id), &record);
+
+        audit_log::record(
+            &env,
+            bounty_id,
+            AuditAction::Frozen,
+            &admin,
+            escrow.remaining_amount,
+            None,
+        );
+
+        events::emit_escrow_frozen(
+            &env,
+            events::EscrowFrozen {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                reason,
+                frozen_by: admin.clone(),
+                remaining_amount: escrow.remaining_amount,
+                timestamp: now,
+            },
+        );
+        events::emit_bounty_event_v3(
+            &env,
+            BountyEventKind::Frozen,
+            bounty_id,
+            Some(&admin),
+            None,
+            escrow.remaining_amount,
+        );
+
+        monitoring::track_operation(&env, symbol_short!("freeze"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    /// Lift a freeze placed by `freeze_escrow` (admin only).
+    pub fn unfreeze_escrow(env: Env, bounty_id: u64) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let mut escrow: Escrow =
+            migration::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        let record: FreezeRecord = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowFreeze(bounty_id))
+            .ok_or(Error::EscrowNotFrozen)?;
+
+        invariants::transition(
+            &escrow.status,
+            &record.previous_status,
+            StatusAction::Unfreeze,
+        )?;
+        escrow.status = record.previous_status.clone();
+        invariants::assert_escrow(&env, &escrow);
+        migration::store_escrow(&env, bounty_id, &escrow);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::EscrowFreeze(bounty_id));
+
+        audit_log::record(
+            &env,
+            bounty_id,
+            AuditAction::Unfrozen,
+            &admin,
+            escrow.remaining_amount,
+            None,
+        );
+
+        events::emit_escrow_unfrozen(
+            &env,
+            events::EscrowUnfrozen {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                reason: record.reason,
+                unfrozen_by: admin.clone(),
+                restored_status: record.previous_status,
+                frozen_at: record.frozen_at,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        events::emit_bounty_event_v3(
+            &env,
+            BountyEventKind::Unfrozen,
+            bounty_id,
+            Some(&admin),
+            None,
+            escrow.remaining_amount,
+        );
+
+        monitoring::track_operation(&env, symbol_short!("unfreeze"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    /// View: freeze details of a bounty, if it is currently frozen.
+    pub fn get_freeze_record(env: Env, bounty_id: u64) -> Option<FreezeRecord> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::EscrowFreeze(bounty_id))
+    }
+
+    /// Add recipients to the deny-list (admin only).
+    ///
+    /// Listed addresses are rejected by every payout path (release, partial
+    /// release, claims, refunds, capabilities, batches and emergency
+    /// withdrawal) with `Error::RecipientBlocked`. `reason_code` is an opaque
+    /// compliance code recorded for off-chain review.
+    pub fn add_to_deny_list(
+        env: Env,
+        addresses: Vec<Address>,
+        reason_code: u32,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if addresses.is_empty() || addresses.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let now = env.ledger().timestamp();
+        for address in addresses.iter() {
+            deny_list::add(
+                &env,
+                &address,
+                &DenyListEntry {
+                    reason_code,
+                    listed_by: admin.clone(),
+                    listed_at: now,
+                },
+            );
+            events::emit_deny_list_updated(
+                &env,
+                events::DenyListUpdated {
+                    version: EVENT_VERSION_V2,
+                    address,
+                    listed: true,
+                    reason_code,
+                    updated_by: admin.clone(),
+                    timestamp: now,
+                },
+            );
+        }
+
+        monitoring::track_operation(&env, symbol_short!("deny_add"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    /// Remove recipients from the deny-list (admin only).
+    ///
+    /// Addresses that are not listed are skipped without emitting an event.
+    pub fn remove_from_deny_list(env: Env, addresses: Vec<Address>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if addresses.is_empty() || addresses.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let now = env.ledger().timestamp();
+        for address in addresses.iter() {
+            if deny_list::remove(&env, &address) {
+                events::emit_deny_list_updated(
+                    &env,
+                    events::DenyListUpdated {
+                        version: EVENT_VERSION_V2,
+                        address,
+                        listed: false,
+                        reason_code: 0,
+                        updated_by: admin.clone(),
+                        timestamp: now,
+                    },
+                );
+            }
+        }
+
+        monitoring::track_operation(&env, symbol_short!("deny_rm"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    /// View: deny-list entry for `address`, if listed.
+    pub fn get_deny_list_entry(env: Env, address: Address) -> Option<DenyListEntry> {
+        deny_list::get_entry(&env, &address)
+    }
+
+    /// View: whether `address` is currently barred from receiving payouts.
+    pub fn is_recipient_blocked(env: Env, address: Address) -> bool {
+        deny_list::is_listed(&env, &address)
+    }
+
+    /// Set how `role` is admitted (admin only).
+    ///
+    /// `AllowList` admits only allow-listed addresses, `DenyList` admits
+    /// everyone but deny-listed ones, and `Open` ignores both lists.
+    /// Depositors are checked when funds are locked, recipients on every
+    /// payout except refunds. Rejections fail with
+    /// `Error::ParticipantNotAllowed`.
+    pub fn set_access_mode(env: Env, role: AccessRole, mode: AccessMode) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        access_list::set_mode(&env, role, mode);
+        events::emit_access_mode_updated(
+            &env,
+            events::AccessModeUpdated {
+                version: EVENT_VERSION_V2,
+                role,
+                mode,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("acc_mode"), admin, true);
+
+        Ok(())
+    }
+
+    /// View: admission mode of `role` (`Open` until set).
+    pub fn get_access_mode(env: Env, role: AccessRole) -> AccessMode {
+        access_list::get_mode(&env, role)
+    }
+
+    /// Add addresses to, or remove them from, one of `role`'s access lists
+    /// (admin only).
+    ///
+    /// Entries take effect only while the role's mode uses that list.
+    /// Addresses whose entry does not change are skipped without an event.
+    pub fn set_access_list(
+        env: Env,
+        role: AccessRole,
+        list: AccessListKind,
+        addresses: Vec<Address>,
+        listed: bool,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if addresses.is_empty() || addresses.len() > MAX_BATCH_SIZE {
+            return Err(Error::InvalidBatchSize);
+        }
+
+        let now = env.ledger().timestamp();
+        for address in addresses.iter() {
+            if access_list::set_listed(&env, role, list, &address, listed) {
+                events::emit_access_list_updated(
+                    &env,
+                    events::AccessListUpdated {
+                        version: EVENT_VERSION_V2,
+                        role,
+                        list,
+                        address,
+                        listed,
+                        updated_by: admin.clone(),
+                        timestamp: now,
+                    },
+                );
+            }
+        }
+
+        monitoring::track_operation(&env, symbol_short!("acc_list"), admin, true);
+
+        Ok(())
+    }
+
+    /// View: whether `address` is on `role`'s `list`, whatever the mode.
+    pub fn is_on_access_list(
+        env: Env,
+        role: AccessRole,
+        list: AccessListKind,
+        address: Address,
+    ) -> bool {
+        access_list::is_listed(&env, role, list, &address)
+    }
+
+    /// View: whether `address` is currently admitted as `role`.
+    pub fn is_participant_allowed(env: Env, role: AccessRole, address: Address) -> bool {
+        access_list::is_allowed(&env, role, &address)
+    }
+
+    /// Require KYC attestations for large payouts (admin only).
+    ///
+    /// Every contributor payout from a bounty of `threshold` or more
+    /// (release, partial release, claims, their capability variants and the
+    /// batch payouts) calls `registry.is_verified(recipient, required_level)`
+    /// and fails with `Error::AttestationRequired` when it returns false. The
+    /// bounty's amount decides, so splitting it into smaller payouts does not
+    /// skip the check. Refunds to the original depositor are not gated.
+    pub fn set_attestation_config(
+        env: Env,
+        registry: Address,
+        threshold: i128,
+        required_level: u32,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        if threshold <= 0 || required_level == 0 {
+            return Err(Error::InvalidAttestationConfig);
+        }
+
+        attestation::set_config(
+            &env,
+            &AttestationConfig {
+                registry: registry.clone(),
+                threshold,
+                required_level,
+            },
+        );
+        events::emit_attestation_config_updated(
+            &env,
+            events::AttestationConfigUpdated {
+                version: EVENT_VERSION_V2,
+                registry: Some(registry),
+                threshold,
+                required_level,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("att_cfg"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    /// Disable the attestation gate (admin only).
+    pub fn clear_attestation_config(env: Env) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        attestation::clear_config(&env);
+        events::emit_attestation_config_updated(
+            &env,
+            events::AttestationConfigUpdated {
+                version: EVENT_VERSION_V2,
+                registry: None,
+                threshold: 0,
+                required_level: 0,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("att_clear"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    /// View: current attestation gate, if any.
+    pub fn get_attestation_config(env: Env) -> Option<AttestationConfig> {
+        attestation::get_config(&env)
+    }
+
+    /// Register a contract to be notified of contributor payouts (admin
+    /// only).
+    ///
+    /// The hook's `on_escrow_event(kind, bounty_id, recipient, amount)` is
+    /// called after every release, partial release and claim. A failing
+    /// hook never blocks the payout; see the `hooks` module.
+    pub fn register_hook(env: Env, hook: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let mut registered = hooks::get_hooks(&env);
+        if registered.contains(&hook) {
+            return Err(Error::HookAlreadyRegistered);
+        }
+        if registered.len() >= hooks::MAX_HOOKS {
+            return Err(Error::TooManyHooks);
+        }
+        registered.push_back(hook.clone());
+        hooks::set_hooks(&env, &registered);
+        events::emit_hook_updated(
+            &env,
+            events::HookUpdated {
+                version: EVENT_VERSION_V2,
+                hook,
+                registered: true,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("hook_add"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    /// Stop notifying `hook` of payouts (admin only).
+    pub fn unregister_hook(env: Env, hook: Address) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let mut registered = hooks::get_hooks(&env);
+        let index = registered
+            .first_index_of(&hook)
+            .ok_or(Error::HookNotRegistered)?;
+        registered.remove(index);
+        hooks::set_hooks(&env, &registered);
+        events::emit_hook_updated(
+            &env,
+            events::HookUpdated {
+                version: EVENT_VERSION_V2,
+                hook,
+                registered: false,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("hook_rm"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    /// View: registered payout hooks, in call order.
+    pub fn get_hooks(env: Env) -> Vec<Address> {
+        hooks::get_hooks(&env)
+    }
+
+    /// Approve upgrading to `wasm_hash` (multisig signers only).
+    ///
+    /// Approvals are counted per hash, so approving one build never
+    /// authorizes another. Approving twice is a no-op.
+    pub fn approve_upgrade(
+        env: Env,
+        wasm_hash: BytesN<32>,
+        approver: Address,
+    ) -> Result<(), Error> {
+        if !env.storage().instance().has(&DataKey::Admin) {
+            return Err(Error::NotInitialized);
+        }
+
+        let multisig_config = Self::get_multisig_config(env.clone());
+        if !multisig_config.signers.contains(&approver) {
+            return Err(Error::Unauthorized);
+        }
+        approver.require_auth();
+
+        let mut approvals = migration::get_upgrade_approvals(&env, &wasm_hash);
+        if approvals.contains(&approver) {
+            return Ok(());
+        }
+        approvals.push_back(approver.clone());
+        migration::set_upgrade_approvals(&env, &wasm_hash, &approvals);
+
+        events::emit_upgrade_approved(
+            &env,
+            events::UpgradeApproved {
+                version: EVENT_VERSION_V2,
+                wasm_hash,
+                approver: approver.clone(),
+                approvals: approvals.len(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("upg_appr"), approver, true);
+
+        Ok(())
+    }
+
+    /// View: signers that approved upgrading to `wasm_hash`.
+    pub fn get_upgrade_approvals(env: Env, wasm_hash: BytesN<32>) -> Vec<Address> {
+        migration::get_upgrade_approvals(&env, &wasm_hash)
+    }
+
+    /// Replace the contract code with the uploaded `wasm_hash` (admin only).
+    ///
+    /// When a multisig is configured, `required_signatures` signers must
+    /// first call `approve_upgrade` for the same hash. Escrowed funds and
+    /// storage stay in place; call `migrate` once the new code is live.
+    ///
+    /// # Errors
+    /// * `UpgradeNotApproved` - The multisig has not approved `wasm_hash`
+    pub fn upgrade(env: Env, wasm_hash: BytesN<32>) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let multisig_config = Self::get_multisig_config(env.clone());
+        if multisig_config.required_signatures > 0 {
+            let approvals = migration::get_upgrade_approvals(&env, &wasm_hash);
+            let valid = approvals
+                .iter()
+                .filter(|approver| multisig_config.signers.contains(approver))
+                .count() as u32;
+            if valid < multisig_config.required_signatures {
+                return Err(Error::UpgradeNotApproved);
+            }
+        }
+        migration::clear_upgrade_approvals(&env, &wasm_hash);
+
+        let from_version = migration::stored_version(&env);
+        let now = env.ledger().timestamp();
+        migration::record_upgrade(
+            &env,
+            UpgradeRecord {
+                wasm_hash: wasm_hash.clone(),
+                from_version,
+                upgraded_by: admin.clone(),
+                upgraded_at: now,
+            },
+        );
+        events::emit_contract_upgraded(
+            &env,
+            events::ContractUpgraded {
+                version: EVENT_VERSION_V2,
+                wasm_hash: wasm_hash.clone(),
+                from_version,
+                upgraded_by: admin.clone(),
+                timestamp: now,
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("upgrade"), admin, true);
+
+        env.deployer().update_current_contract_wasm(wasm_hash);
+        Ok(())
+    }
+
+    /// Bring contract-wide state up to this code's `CONTRACT_VERSION`
+    /// (admin only).
+    ///
+    /// Recomputes `TotalLiabilities` from the escrow index,
+    /// `MIGRATION_PAGE_SIZE` escrows per call. While the returned state has a
+    /// `next_cursor`, call again to continue; the call that reaches the end
+    /// of the index stores the total and the new version, caches the token's
+    /// decimals and emits `ContractMigrated`. Escrow records themselves are
+    /// converted lazily as they are next written. Safe to call more than
+    /// once.
+    ///
+    /// # Errors
+    /// * NotPaused - a recompute spanning several calls was started or
+    ///   continued while lock, release or refund was unpaused; the tally
+    ///   would miss changes to escrows it had already counted
+    pub fn migrate(env: Env) -> Result<MigrationState, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let (from_version, cursor, tallied) = match migration::get_migration_state(&env) {
+            Some(MigrationState {
+                from_version,
+                next_cursor: Some(cursor),
+                total_liabilities,
+                ..
+            }) => (from_version, cursor, total_liabilities),
+            _ => (migration::stored_version(&env), 0, 0),
+        };
+        let (page_liabilities, next_cursor) =
+            invariants::liabilities_page(&env, cursor, MIGRATION_PAGE_SIZE);
+        if cursor > 0 || next_cursor.is_some() {
+            let flags = Self::get_pause_flags(&env);
+            if !(flags.lock_paused && flags.release_paused && flags.refund_paused) {
+                return Err(Error::NotPaused);
+            }
+        }
+        let total_liabilities = tallied + page_liabilities;
+
+        let state = MigrationState {
+            from_version,
+            to_version: CONTRACT_VERSION,
+            total_liabilities,
+            next_cursor,
+            migrated_at: env.ledger().timestamp(),
+        };
+        migration::set_migration_state(&env, &state);
+        if next_cursor.is_none() {
+            env.storage()
+                .instance()
+                .set(&DataKey::TotalLiabilities, &total_liabilities);
+            if let Some(token) = units::escrow_token(&env) {
+                units::cache_decimals(&env, &token);
+            }
+            migration::set_stored_version(&env, CONTRACT_VERSION);
+            events::emit_contract_migrated(
+                &env,
+                events::ContractMigrated {
+                    version: EVENT_VERSION_V2,
+                    from_version,
+                    to_version: CONTRACT_VERSION,
+                    total_liabilities,
+                    migrated_by: admin.clone(),
+                    timestamp: state.migrated_at,
+                },
+            );
+        }
+
+        monitoring::track_operation(&env, symbol_short!("migrate"), admin, true);
+
+        Ok(state)
+    }
+
+    /// View: every upgrade performed, oldest first.
+    pub fn get_upgrade_history(env: Env) -> Vec<UpgradeRecord> {
+        migration::get_upgrade_history(&env)
+    }
+
+    /// View: result of the last `migrate` call, if any.
+    pub fn get_migration_state(env: Env) -> Option<MigrationState> {
+        migration::get_migration_state(&env)
+    }
+
+    /// Import escrows exported by the legacy escrow contract at `source`
+    /// (admin only).
+    ///
+    /// `escrows` are the `Locked` records of a page of the legacy
+    /// contract's `export_all` output. Every record must match what
+    /// `source` still holds (`ImportMismatch` otherwise), be `Locked` there,
+    /// have a deadline still in the future, and not be present here yet;
+    /// the batch is all-or-nothing. Each escrow keeps its bounty id,
+    /// depositor, amounts and deadline, and is tagged as imported in its
+    /// metadata. The legacy contract's `mark_migrated` then closes the
+    /// escrows there and pays their remaining amounts to `funder`, who pays
+    /// the sum in; no fee is taken from it. The legacy admin must authorize
+    /// that call too. Access lists and the lock pause do not apply: these
+    /// are existing deposits, not new ones.
+    ///
+    /// # Returns
+    /// The number of escrows imported.
+    pub fn import_escrows(
+        env: Env,
+        source: Address,
+        funder: Address,
+        escrows: Vec<LegacyEscrow>,
+    ) -> Result<u32, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        validation::import_batch(&env, escrows.len())?;
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if funder != admin {
+            funder.require_auth();
+        }
+
+        for legacy in escrows.iter() {
+            let duplicate =
+                validation::is_repeated(escrows.iter().map(|e| e.bounty_id), legacy.bounty_id);
+            legacy_import::validate(&env, &source, &legacy, duplicate)?;
+        }
+
+        // EFFECTS: write every escrow before the external calls (CEI)
+        let now = env.ledger().timestamp();
+        let mut total: i128 = 0;
+        let mut bounty_ids = Vec::new(&env);
+        for legacy in escrows.iter() {
+            let bounty_id = legacy.bounty_id;
+            bounty_ids.push_back(bounty_id);
+            let escrow = Escrow {
+                depositor: legacy.depositor.clone(),
+                amount: legacy.amount,
+                status: EscrowStatus::Locked,
+                deadline: legacy.deadline,
+                refund_history: vec![&env],
+                remaining_amount: legacy.remaining_amount,
+            };
+            invariants::assert_escrow(&env, &escrow);
+            invariants::adjust_liabilities(&env, 0, invariants::outstanding_amount(&escrow));
+            migration::store_escrow(&env, bounty_id, &escrow);
+            Self::index_escrow(&env, bounty_id, &legacy.depositor);
+
+            env.storage().persistent().set(
+                &DataKey::Metadata(bounty_id),
+                &EscrowMetadata {
+                    repo_id: 0,
+                    issue_id: 0,
+                    bounty_type: legacy_import::bounty_type(&env),
+                },
+            );
+            legacy_import::set_record(
+                &env,
+                bounty_id,
+                &ImportRecord {
+                    source: source.clone(),
+                    imported_by: admin.clone(),
+                    imported_at: now,
+                },
+            );
+            audit_log::record(
+                &env,
+                bounty_id,
+                AuditAction::Imported,
+                &admin,
+                legacy.remaining_amount,
+                Some(legacy.depositor.clone()),
+            );
+            events::emit_escrow_imported(
+                &env,
+                events::EscrowImported {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    depositor: legacy.depositor,
+                    amount: legacy.amount,
+                    remaining_amount: legacy.remaining_amount,
+                    deadline: legacy.deadline,
+                    source: source.clone(),
+                    imported_by: admin.clone(),
+                    timestamp: now,
+                },
+            );
+            total += legacy.remaining_amount;
+        }
+
+        // INTERACTION: close the escrows on the legacy contract, then take
+        // in the matching transfer
+        let released = legacy_import::LegacyEscrowClient::new(&env, &source)
+            .mark_migrated(&bounty_ids, &funder);
+        if released != total {
+            return Err(Error::ImportMismatch);
+        }
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&funder, &env.current_contract_address(), &total);
+        invariants::ensure_solvent(&env)?;
+
+        monitoring::track_operation(&env, symbol_short!("import"), admin, true);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(escrows.len())
+    }
+
+    /// View: where an imported escrow came from, or `None` if `bounty_id`
+    /// was not imported.
+    pub fn get_import_record(env: Env, bounty_id: u64) -> Option<ImportRecord> {
+        legacy_import::get_record(&env, bounty_id)
+    }
+
+    /// Select which event schema is published (admin only).
+    ///
+    /// Defaults to `EventSchema::V2`. Switch to `Dual` while consumers move
+    /// to the v3 topics, then to `V3` to stop emitting the v2 lifecycle
+    /// events.
+    pub fn set_event_schema(env: Env, schema: EventSchema) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        events::set_event_schema(&env, schema);
+        events::emit_event_schema_updated(
+            &env,
+            events::EventSchemaUpdated {
+                version: EVENT_VERSION_V3,
+                schema,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("ev_schema"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    pub fn get_event_schema(env: Env) -> EventSchema {
+        events::get_event_schema(&env)
+    }
+
+    /// Get current pause flags
+    pub fn get_pause_flags(env: &Env) -> PauseFlags {
+        env.storage()
             .instance()
             .get(&DataKey::PauseFlags)
             .unwrap_or(PauseFlags {
@@ -883,6 +2263,18 @@ impl BountyEscrowContract {
         false
     }
 
+    /// Reject any state change on a bounty that is frozen for investigation.
+    fn ensure_not_frozen(env: &Env, bounty_id: u64) -> Result<(), Error> {
+        if env
+            .storage()
+            .persistent()
+            .has(&DataKey::EscrowFreeze(bounty_id))
+        {
+            return Err(Error::EscrowFrozen);
+        }
+        Ok(())
+    }
+
     fn next_capability_id(env: &Env) -> u64 {
         let last_id: u64 = env
             .storage()
@@ -913,6 +2305,7 @@ impl BountyEscrowContract {
         if amount_limit <= 0 {
             return Err(Error::InvalidAmount);
         }
+        Self::ensure_not_frozen(env, bounty_id)?;
 
         match action {
             CapabilityAction::Claim => {
@@ -921,12 +2314,7 @@ impl BountyEscrowContract {
                     .persistent()
                     .get(&DataKey::PendingClaim(bounty_id))
                     .ok_or(Error::BountyNotFound)?;
-                if claim.claimed {
-                    return Err(Error::FundsNotLocked);
-                }
-                if env.ledger().timestamp() > claim.expires_at {
-                    return Err(Error::DeadlineNotPassed);
-                }
+                validation::ensure_claim_live(env, &claim)?;
                 if claim.recipient != owner.clone() {
                     return Err(Error::Unauthorized);
                 }
@@ -943,12 +2331,9 @@ impl BountyEscrowContract {
                 if admin != owner.clone() {
                     return Err(Error::Unauthorized);
                 }
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked {
+                let escrow: Escrow =
+                    migration::load_escrow(env, bounty_id).ok_or(Error::BountyNotFound)?;
+                if !invariants::allows(&escrow.status, StatusAction::PartialRelease) {
                     return Err(Error::FundsNotLocked);
                 }
                 if amount_limit > escrow.remaining_amount {
@@ -964,14 +2349,9 @@ impl BountyEscrowContract {
                 if admin != owner.clone() {
                     return Err(Error::Unauthorized);
                 }
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(bounty_id))
-                    .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked
-                    && escrow.status != EscrowStatus::PartiallyRefunded
-                {
+                let escrow: Escrow =
+                    migration::load_escrow(env, bounty_id).ok_or(Error::BountyNotFound)?;
+                if !invariants::allows(&escrow.status, StatusAction::Refund) {
                     return Err(Error::FundsNotLocked);
                 }
                 if amount_limit > escrow.remaining_amount {
@@ -991,6 +2371,7 @@ impl BountyEscrowContract {
         if requested_amount <= 0 {
             return Err(Error::InvalidAmount);
         }
+        Self::ensure_not_frozen(env, capability.bounty_id)?;
 
         match capability.action {
             CapabilityAction::Claim => {
@@ -999,12 +2380,7 @@ impl BountyEscrowContract {
                     .persistent()
                     .get(&DataKey::PendingClaim(capability.bounty_id))
                     .ok_or(Error::BountyNotFound)?;
-                if claim.claimed {
-                    return Err(Error::FundsNotLocked);
-                }
-                if env.ledger().timestamp() > claim.expires_at {
-                    return Err(Error::DeadlineNotPassed);
-                }
+                validation::ensure_claim_live(env, &claim)?;
                 if claim.recipient != capability.owner {
                     return Err(Error::Unauthorized);
                 }
@@ -1021,12 +2397,9 @@ impl BountyEscrowContract {
                 if admin != capability.owner {
                     return Err(Error::Unauthorized);
                 }
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(capability.bounty_id))
+                let escrow: Escrow = migration::load_escrow(env, capability.bounty_id)
                     .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked {
+                if !invariants::allows(&escrow.status, StatusAction::PartialRelease) {
                     return Err(Error::FundsNotLocked);
                 }
                 if requested_amount > escrow.remaining_amount {
@@ -1042,14 +2415,9 @@ impl BountyEscrowContract {
                 if admin != capability.owner {
                     return Err(Error::Unauthorized);
                 }
-                let escrow: Escrow = env
-                    .storage()
-                    .persistent()
-                    .get(&DataKey::Escrow(capability.bounty_id))
+                let escrow: Escrow = migration::load_escrow(env, capability.bounty_id)
                     .ok_or(Error::BountyNotFound)?;
-                if escrow.status != EscrowStatus::Locked
-                    && escrow.status != EscrowStatus::PartiallyRefunded
-                {
+                if !invariants::allows(&escrow.status, StatusAction::Refund) {
                     return Err(Error::FundsNotLocked);
                 }
                 if requested_amount > escrow.remaining_amount {
@@ -1060,41 +2428,15 @@ impl BountyEscrowContract {
         Ok(())
     }
 
+    /// Spend one use and `amount` of a capability already checked by
+    /// `validation::capability`.
     fn consume_capability(
         env: &Env,
         holder: &Address,
         capability_id: u64,
-        expected_action: CapabilityAction,
-        bounty_id: u64,
+        mut capability: Capability,
         amount: i128,
-    ) -> Result<Capability, Error> {
-        let mut capability = Self::load_capability(env, capability_id)?;
-
-        if capability.revoked {
-            return Err(Error::CapabilityRevoked);
-        }
-        if capability.action != expected_action {
-            return Err(Error::CapabilityActionMismatch);
-        }
-        if capability.bounty_id != bounty_id {
-            return Err(Error::CapabilityActionMismatch);
-        }
-        if capability.holder != holder.clone() {
-            return Err(Error::Unauthorized);
-        }
-        if env.ledger().timestamp() > capability.expiry {
-            return Err(Error::CapabilityExpired);
-        }
-        if capability.remaining_uses == 0 {
-            return Err(Error::CapabilityUsesExhausted);
-        }
-        if amount > capability.remaining_amount {
-            return Err(Error::CapabilityAmountExceeded);
-        }
-
-        holder.require_auth();
-        Self::ensure_owner_still_authorized(env, &capability, amount)?;
-
+    ) {
         capability.remaining_amount -= amount;
         capability.remaining_uses -= 1;
         env.storage()
@@ -1107,15 +2449,13 @@ impl BountyEscrowContract {
                 capability_id,
                 holder: holder.clone(),
                 action: capability.action.clone(),
-                bounty_id,
+                bounty_id: capability.bounty_id,
                 amount_used: amount,
                 remaining_amount: capability.remaining_amount,
                 remaining_uses: capability.remaining_uses,
                 used_at: env.ledger().timestamp(),
             },
         );
-
-        Ok(capability)
     }
 
     #[allow(clippy::too_many_arguments)]
@@ -1161,6 +2501,15 @@ impl BountyEscrowContract {
             .persistent()
             .set(&DataKey::Capability(capability_id), &capability);
 
+        audit_log::record(
+            &env,
+            bounty_id,
+            AuditAction::CapabilityIssued,
+            &owner,
+            amount_limit,
+            Some(holder.clone()),
+        );
+
         events::emit_capability_issued(
             &env,
             events::CapabilityIssued {
@@ -1176,6 +2525,13 @@ impl BountyEscrowContract {
             },
         );
 
+        monitoring::track_operation(
+            &env,
+            symbol_short!("cap_issue"),
+            capability.owner.clone(),
+            true,
+        );
+
         Ok(capability_id)
     }
 
@@ -1195,6 +2551,15 @@ impl BountyEscrowContract {
             .persistent()
             .set(&DataKey::Capability(capability_id), &capability);
 
+        audit_log::record(
+            &env,
+            capability.bounty_id,
+            AuditAction::CapabilityRevoked,
+            &owner,
+            capability.remaining_amount,
+            Some(capability.holder.clone()),
+        );
+
         events::emit_capability_revoked(
             &env,
             events::CapabilityRevoked {
@@ -1204,6 +2569,13 @@ impl BountyEscrowContract {
             },
         );
 
+        monitoring::track_operation(
+            &env,
+            symbol_short!("cap_revok"),
+            capability.owner.clone(),
+            true,
+        );
+
         Ok(())
     }
 
@@ -1244,6 +2616,8 @@ impl BountyEscrowContract {
             .instance()
             .set(&DataKey::MultisigConfig, &config);
 
+        monitoring::track_operation(&env, symbol_short!("multisig"), admin.clone(), true);
+
         Ok(())
     }
 
@@ -1280,97 +2654,246 @@ impl BountyEscrowContract {
             }
         }
 
-        if !is_signer {
-            return Err(Error::Unauthorized);
-        }
+        if !is_signer {
+            return Err(Error::Unauthorized);
+        }
+
+        approver.require_auth();
+
+        let approval_key = DataKey::ReleaseApproval(bounty_id);
+        let mut approval: ReleaseApproval = env
+            .storage()
+            .persistent()
+            .get(&approval_key)
+            .unwrap_or(ReleaseApproval {
+                bounty_id,
+                contributor: contributor.clone(),
+                approvals: vec![&env],
+            });
+
+        for existing in approval.approvals.iter() {
+            if existing == approver {
+                return Ok(());
+            }
+        }
+
+        approval.approvals.push_back(approver.clone());
+        env.storage().persistent().set(&approval_key, &approval);
+
+        audit_log::record(
+            &env,
+            bounty_id,
+            AuditAction::ReleaseApproved,
+            &approver,
+            0,
+            Some(contributor.clone()),
+        );
+
+        events::emit_approval_added(
+            &env,
+            events::ApprovalAdded {
+                bounty_id,
+                contributor: contributor.clone(),
+                approver: approver.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("approve"), approver.clone(), true);
+
+        Ok(())
+    }
+
+    /// Lock funds for a specific bounty.
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. State (escrow record,
+    /// indexes) is written before the inbound token transfer so that
+    /// a malicious token callback cannot re-enter with stale state.
+    pub fn lock_funds(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> Result<(), Error> {
+        let deadline_mode = DeadlineMode::Absolute(deadline);
+        Self::lock_escrow(env, depositor, bounty_id, amount, deadline_mode, None)
+    }
+
+    /// Lock funds like `lock_funds`, crediting `referrer` with the referrer
+    /// share of this bounty's fees while a fee split is configured.
+    pub fn lock_funds_with_referrer(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+        referrer: Address,
+    ) -> Result<(), Error> {
+        let deadline_mode = DeadlineMode::Absolute(deadline);
+        Self::lock_escrow(
+            env,
+            depositor,
+            bounty_id,
+            amount,
+            deadline_mode,
+            Some(referrer),
+        )
+    }
+
+    /// Lock funds like `lock_funds`, with any deadline mode: a fixed
+    /// timestamp, open-ended, a period after assignment, or a period of
+    /// inactivity. Relative modes need a non-zero period.
+    pub fn lock_funds_with_deadline_mode(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline_mode: DeadlineMode,
+    ) -> Result<(), Error> {
+        Self::lock_escrow(env, depositor, bounty_id, amount, deadline_mode, None)
+    }
+
+    pub fn get_referrer(env: Env, bounty_id: u64) -> Option<Address> {
+        fees::get_referrer(&env, bounty_id)
+    }
+
+    /// Replace the deadline mode of an existing escrow, e.g. to turn a
+    /// far-future sentinel deadline into an open-ended bounty. A new
+    /// relative mode starts its clock now. The deadline decides when the
+    /// depositor gets their funds back, so both the admin and the depositor
+    /// must sign. Only escrows that can still be refunded have a deadline
+    /// worth changing; frozen escrows are refused.
+    pub fn set_deadline_mode(
+        env: Env,
+        bounty_id: u64,
+        deadline_mode: DeadlineMode,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        Self::ensure_not_frozen(&env, bounty_id)?;
+        let mut escrow = validation::load_escrow(&env, bounty_id)?;
+        escrow.depositor.require_auth();
+        if !invariants::allows(&escrow.status, StatusAction::Refund) {
+            return Err(Error::FundsNotLocked);
+        }
+        deadlines::validate(&deadline_mode)?;
+        escrow.deadline = deadlines::stored_deadline(&deadline_mode);
+        migration::store_escrow(&env, bounty_id, &escrow);
+        deadlines::set_mode(&env, bounty_id, &deadline_mode);
+        audit_log::record(
+            &env,
+            bounty_id,
+            AuditAction::DeadlineModeUpdated,
+            &admin,
+            0,
+            None,
+        );
+
+        events::emit_deadline_mode_updated(
+            &env,
+            events::DeadlineModeUpdated {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                mode: deadline_mode,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("dl_mode"), admin, true);
 
-        approver.require_auth();
+        Ok(())
+    }
 
-        let approval_key = DataKey::ReleaseApproval(bounty_id);
-        let mut approval: ReleaseApproval = env
+    /// View: deadline mode of `bounty_id`.
+    pub fn get_deadline_mode(env: Env, bounty_id: u64) -> Result<DeadlineMode, Error> {
+        let escrow = validation::load_escrow(&env, bounty_id)?;
+        Ok(deadlines::get_mode(&env, bounty_id, &escrow))
+    }
+
+    /// View: when the depositor may refund without approval, or `None` when
+    /// that is not yet known (unassigned) or never (open-ended).
+    pub fn get_refund_deadline(env: Env, bounty_id: u64) -> Result<Option<u64>, Error> {
+        let escrow = validation::load_escrow(&env, bounty_id)?;
+        Ok(deadlines::refundable_at(&env, bounty_id, &escrow))
+    }
+
+    /// Assign `contributor` to a locked, unfrozen bounty (admin only).
+    ///
+    /// Starts the clock of an `AfterAssignment` deadline and counts as
+    /// activity for an `Inactivity` one. Reassigning restarts the clock.
+    pub fn assign_contributor(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
+        let admin: Address = env
             .storage()
-            .persistent()
-            .get(&approval_key)
-            .unwrap_or(ReleaseApproval {
-                bounty_id,
-                contributor: contributor.clone(),
-                approvals: vec![&env],
-            });
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
 
-        for existing in approval.approvals.iter() {
-            if existing == approver {
-                return Ok(());
-            }
+        Self::ensure_not_frozen(&env, bounty_id)?;
+        let escrow = validation::load_escrow(&env, bounty_id)?;
+        if !invariants::allows(&escrow.status, StatusAction::Release) {
+            return Err(Error::FundsNotLocked);
         }
+        let assignment = deadlines::assign(&env, bounty_id, &contributor);
+        audit_log::record(
+            &env,
+            bounty_id,
+            AuditAction::ContributorAssigned,
+            &admin,
+            0,
+            Some(contributor.clone()),
+        );
 
-        approval.approvals.push_back(approver.clone());
-        env.storage().persistent().set(&approval_key, &approval);
-
-        events::emit_approval_added(
+        events::emit_contributor_assigned(
             &env,
-            events::ApprovalAdded {
+            events::ContributorAssigned {
+                version: EVENT_VERSION_V2,
                 bounty_id,
-                contributor: contributor.clone(),
-                approver,
-                timestamp: env.ledger().timestamp(),
+                contributor,
+                assigned_by: admin.clone(),
+                assigned_at: assignment.assigned_at,
             },
         );
 
+        monitoring::track_operation(&env, symbol_short!("assign"), admin, true);
+
         Ok(())
     }
 
-    /// Lock funds for a specific bounty.
-    ///
-    /// # Reentrancy
-    /// Protected by the shared reentrancy guard. State (escrow record,
-    /// indexes) is written before the inbound token transfer so that
-    /// a malicious token callback cannot re-enter with stale state.
-    pub fn lock_funds(
+    /// View: contributor assigned to `bounty_id`, if any.
+    pub fn get_assignment(env: Env, bounty_id: u64) -> Option<Assignment> {
+        deadlines::get_assignment(&env, bounty_id)
+    }
+
+    fn lock_escrow(
         env: Env,
         depositor: Address,
         bounty_id: u64,
         amount: i128,
-        deadline: u64,
+        deadline_mode: DeadlineMode,
+        referrer: Option<Address>,
     ) -> Result<(), Error> {
         // GUARD: acquire reentrancy lock
         reentrancy_guard::acquire(&env);
 
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, depositor.clone());
-
-        if Self::check_paused(&env, symbol_short!("lock")) {
-            return Err(Error::FundsPaused);
-        }
-
-        let _start = env.ledger().timestamp();
-        let _caller = depositor.clone();
-
-        // Verify depositor authorization
-        depositor.require_auth();
-
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyExists);
+        validation::lock(&env, &depositor, bounty_id, amount, Mode::Execute)?;
+        deadlines::validate(&deadline_mode)?;
+        if referrer.as_ref() == Some(&depositor) {
+            return Err(Error::InvalidReferrer);
         }
+        let deadline = deadlines::stored_deadline(&deadline_mode);
 
-        // Enforce min/max amount policy if one has been configured (Issue #62).
-        // When no policy is set this block is skipped entirely, preserving
-        // backward-compatible behaviour for callers that never call set_amount_policy.
-        if let Some((min_amount, max_amount)) = env
-            .storage()
-            .instance()
-            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
-        {
-            if amount < min_amount {
-                return Err(Error::AmountBelowMinimum);
-            }
-            if amount > max_amount {
-                return Err(Error::AmountAboveMaximum);
-            }
-        }
+        // The lock fee comes out of the deposit; only the rest is escrowed.
+        let fee_quote = fees::quote(&env, &depositor, amount, FeeOperationType::Lock);
+        let amount = fee_quote.net;
 
         // EFFECTS: write escrow state and indexes before the external call
         let escrow = Escrow {
@@ -1382,37 +2905,50 @@ impl BountyEscrowContract {
             remaining_amount: amount,
         };
         invariants::assert_escrow(&env, &escrow);
+        invariants::adjust_liabilities(&env, 0, amount);
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        // Update indexes
-        let mut index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::EscrowIndex)
-            .unwrap_or(Vec::new(&env));
-        index.push_back(bounty_id);
-        env.storage()
-            .persistent()
-            .set(&DataKey::EscrowIndex, &index);
+        migration::store_escrow(&env, bounty_id, &escrow);
+        if !matches!(deadline_mode, DeadlineMode::Absolute(_)) {
+            deadlines::set_mode(&env, bounty_id, &deadline_mode);
+            events::emit_deadline_mode_updated(
+                &env,
+                events::DeadlineModeUpdated {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    mode: deadline_mode,
+                    updated_by: depositor.clone(),
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
 
-        let mut depositor_index: Vec<u64> = env
-            .storage()
-            .persistent()
-            .get(&DataKey::DepositorIndex(depositor.clone()))
-            .unwrap_or(Vec::new(&env));
-        depositor_index.push_back(bounty_id);
-        env.storage().persistent().set(
-            &DataKey::DepositorIndex(depositor.clone()),
-            &depositor_index,
-        );
+        Self::index_escrow(&env, bounty_id, &depositor);
+        audit_log::record(&env, bounty_id, AuditAction::Lock, &depositor, amount, None);
+        if let Some(referrer) = referrer {
+            fees::set_referrer(&env, bounty_id, &referrer);
+            events::emit_referrer_attached(
+                &env,
+                events::ReferrerAttached {
+                    version: EVENT_VERSION_V2,
+                    bounty_id,
+                    referrer,
+                    timestamp: env.ledger().timestamp(),
+                },
+            );
+        }
 
         // INTERACTION: external token transfer is last
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
         client.transfer(&depositor, &env.current_contract_address(), &amount);
+        fees::collect(
+            &env,
+            &client,
+            &depositor,
+            bounty_id,
+            &fee_quote,
+            FeeOperationType::Lock,
+        );
 
         // Emit value allows for off-chain indexing
         emit_funds_locked(
@@ -1425,6 +2961,16 @@ impl BountyEscrowContract {
                 deadline,
             },
         );
+        events::emit_bounty_event_v3(
+            &env,
+            BountyEventKind::Locked,
+            bounty_id,
+            Some(&depositor),
+            None,
+            amount,
+        );
+
+        monitoring::track_operation(&env, symbol_short!("lock"), depositor.clone(), true);
 
         // GUARD: release reentrancy lock
         reentrancy_guard::release(&env);
@@ -1438,69 +2984,54 @@ impl BountyEscrowContract {
     /// Protected by the shared reentrancy guard. Escrow state is updated
     /// to `Released` *before* the outbound token transfer (CEI pattern).
     pub fn release_funds(env: Env, bounty_id: u64, contributor: Address) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-
-        // Block direct release while an active dispute (pending claim) exists.
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
-                .storage()
-                .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
-            }
-        }
-
-        let _start = env.ledger().timestamp();
-
-        // GUARD: acquire reentrancy lock (replaces inline guard)
+        // GUARD: acquire reentrancy lock
         reentrancy_guard::acquire(&env);
 
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
-        }
+        let (admin, mut escrow) =
+            validation::release(&env, bounty_id, &contributor, Mode::Execute)?;
 
         // EFFECTS: update state before external call (CEI)
+        let owed_before = invariants::outstanding_amount(&escrow);
         let release_amount = escrow.amount;
+        invariants::transition(
+            &escrow.status,
+            &EscrowStatus::Released,
+            StatusAction::Release,
+        )?;
         escrow.status = EscrowStatus::Released;
         escrow.remaining_amount = 0;
         invariants::assert_escrow(&env, &escrow);
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        invariants::adjust_liabilities(&env, owed_before, 0);
+        migration::store_escrow(&env, bounty_id, &escrow);
+        audit_log::record(
+            &env,
+            bounty_id,
+            AuditAction::Release,
+            &admin,
+            release_amount,
+            Some(contributor.clone()),
+        );
+        contributors::record_payout(
+            &env,
+            &contributor,
+            &escrow.depositor,
+            bounty_id,
+            release_amount,
+            Payout::Full,
+        );
 
         // INTERACTION: external token transfer is last
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
+        fees::pay_out(
+            &env,
+            &client,
+            bounty_id,
+            &escrow.depositor,
             &contributor,
-            &release_amount,
+            release_amount,
         );
+        invariants::ensure_solvent(&env)?;
 
         emit_funds_released(
             &env,
@@ -1512,6 +3043,24 @@ impl BountyEscrowContract {
                 timestamp: env.ledger().timestamp(),
             },
         );
+        events::emit_bounty_event_v3(
+            &env,
+            BountyEventKind::Released,
+            bounty_id,
+            Some(&admin),
+            Some(contributor.clone()),
+            release_amount,
+        );
+
+        monitoring::track_operation(&env, symbol_short!("release"), admin.clone(), true);
+
+        hooks::notify(
+            &env,
+            hooks::release_kind(),
+            bounty_id,
+            &contributor,
+            release_amount,
+        );
 
         // GUARD: release reentrancy lock
         reentrancy_guard::release(&env);
@@ -1520,6 +3069,10 @@ impl BountyEscrowContract {
 
     /// Delegated release flow using a capability instead of admin auth.
     /// The capability amount limit is consumed by `payout_amount`.
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. Escrow state is updated
+    /// *before* the outbound token transfer (CEI pattern).
     pub fn release_with_capability(
         env: Env,
         bounty_id: u64,
@@ -1528,52 +3081,66 @@ impl BountyEscrowContract {
         holder: Address,
         capability_id: u64,
     ) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-        if payout_amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
-        }
-        if payout_amount > escrow.remaining_amount {
-            return Err(Error::InsufficientFunds);
-        }
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
 
-        Self::consume_capability(
+        let (mut escrow, capability) = validation::release_with_capability(
             &env,
+            bounty_id,
+            &contributor,
+            payout_amount,
             &holder,
             capability_id,
-            CapabilityAction::Release,
+            Mode::Execute,
+        )?;
+        Self::consume_capability(&env, &holder, capability_id, capability, payout_amount);
+
+        // EFFECTS: update escrow state before external call (CEI)
+        let owed_before = invariants::outstanding_amount(&escrow);
+        let previous_status = escrow.status.clone();
+        escrow.remaining_amount -= payout_amount;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
+        }
+        invariants::transition(
+            &previous_status,
+            &escrow.status,
+            StatusAction::PartialRelease,
+        )?;
+        invariants::adjust_liabilities(&env, owed_before, invariants::outstanding_amount(&escrow));
+        migration::store_escrow(&env, bounty_id, &escrow);
+        deadlines::touch(&env, bounty_id);
+        audit_log::record(
+            &env,
             bounty_id,
+            AuditAction::Release,
+            &holder,
             payout_amount,
-        )?;
+            Some(contributor.clone()),
+        );
+        contributors::record_payout(
+            &env,
+            &contributor,
+            &escrow.depositor,
+            bounty_id,
+            payout_amount,
+            Payout::Partial {
+                completes: escrow.remaining_amount == 0,
+            },
+        );
 
+        // INTERACTION: external token transfer is last
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
+        fees::pay_out(
+            &env,
+            &client,
+            bounty_id,
+            &escrow.depositor,
             &contributor,
-            &payout_amount,
+            payout_amount,
         );
-
-        escrow.remaining_amount -= payout_amount;
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Released;
-        }
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        invariants::ensure_solvent(&env)?;
 
         emit_funds_released(
             &env,
@@ -1581,10 +3148,31 @@ impl BountyEscrowContract {
                 version: EVENT_VERSION_V2,
                 bounty_id,
                 amount: payout_amount,
-                recipient: contributor,
+                recipient: contributor.clone(),
                 timestamp: env.ledger().timestamp(),
             },
         );
+        events::emit_bounty_event_v3(
+            &env,
+            BountyEventKind::PartiallyReleased,
+            bounty_id,
+            Some(&holder),
+            Some(contributor.clone()),
+            payout_amount,
+        );
+
+        monitoring::track_operation(&env, symbol_short!("rel_cap"), holder.clone(), true);
+
+        hooks::notify(
+            &env,
+            hooks::partial_kind(),
+            bounty_id,
+            &contributor,
+            payout_amount,
+        );
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
 
         Ok(())
     }
@@ -1600,46 +3188,117 @@ impl BountyEscrowContract {
         env.storage()
             .instance()
             .set(&DataKey::ClaimWindow, &claim_window);
+        events::emit_claim_window_updated(
+            &env,
+            events::ClaimWindowUpdated {
+                version: EVENT_VERSION_V3,
+                claim_window,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("claim_win"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    /// Set or clear (`None`) the claim window of one bounty, replacing the
+    /// global window for claims authorized on it afterwards (admin only).
+    pub fn set_bounty_claim_window(
+        env: Env,
+        bounty_id: u64,
+        claim_window: Option<u64>,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+            return Err(Error::BountyNotFound);
+        }
+
+        claims::set_window_override(&env, bounty_id, claim_window);
+        events::emit_bounty_claim_window_updated(
+            &env,
+            events::BountyClaimWindowUpdated {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                claim_window,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("claim_win"), admin, true);
+
         Ok(())
     }
 
+    /// View: claim window, in seconds, a claim authorized now on
+    /// `bounty_id` would get.
+    pub fn get_bounty_claim_window(env: Env, bounty_id: u64) -> u64 {
+        claims::window(&env, bounty_id)
+    }
+
+    /// Delete expired, unexecuted claims of up to `limit` escrows from
+    /// position `cursor` of the escrow index, publishing a `claim/expired`
+    /// event for each. Frozen escrows are skipped. Anyone may call this;
+    /// resume with `next_cursor` until it is `None`.
+    pub fn sweep_expired_claims(env: Env, cursor: u32, limit: u32) -> ClaimSweepReport {
+        claims::sweep(&env, cursor, limit)
+    }
+
     /// Authorize a release as a pending claim instead of immediate transfer.
     /// Admin calls this instead of release_funds when claim period is active.
-    /// Beneficiary must call claim() within the window to receive funds.
+    /// Beneficiary must call claim() within the window to receive the
+    /// escrow's remaining amount. Fails with ClaimPending while an earlier
+    /// claim is still live; cancel it or let it expire first.
     pub fn authorize_claim(env: Env, bounty_id: u64, recipient: Address) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+        let (admin, amount) =
+            validation::authorize_claim(&env, bounty_id, &recipient, None, Mode::Execute)?;
+        Self::record_claim_authorization(&env, &admin, bounty_id, amount, &recipient);
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
+        monitoring::track_operation(&env, symbol_short!("auth_clm"), admin.clone(), true);
 
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        Ok(())
+    }
 
-        if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
-        }
+    /// Like `authorize_claim`, for `amount` of the remaining amount. Once
+    /// it is claimed, the escrow stays locked with the rest and a further
+    /// claim can be authorized.
+    pub fn authorize_partial_claim(
+        env: Env,
+        bounty_id: u64,
+        recipient: Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        let (admin, amount) =
+            validation::authorize_claim(&env, bounty_id, &recipient, Some(amount), Mode::Execute)?;
+        Self::record_claim_authorization(&env, &admin, bounty_id, amount, &recipient);
+
+        monitoring::track_operation(&env, symbol_short!("auth_clm"), admin.clone(), true);
 
+        Ok(())
+    }
+
+    /// Store a pending claim of `amount` and publish its events. Replaces
+    /// any earlier claim on the bounty, with its redirect.
+    fn record_claim_authorization(
+        env: &Env,
+        admin: &Address,
+        bounty_id: u64,
+        amount: i128,
+        recipient: &Address,
+    ) {
         let now = env.ledger().timestamp();
-        let claim_window: u64 = env
-            .storage()
-            .instance()
-            .get(&DataKey::ClaimWindow)
-            .unwrap_or(0);
+        let claim_window = claims::window(env, bounty_id);
         let claim = ClaimRecord {
             bounty_id,
             recipient: recipient.clone(),
-            amount: escrow.amount,
+            amount,
             expires_at: now.saturating_add(claim_window),
             claimed: false,
         };
@@ -1647,93 +3306,89 @@ impl BountyEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::PendingClaim(bounty_id), &claim);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ClaimRedirect(bounty_id));
+        deadlines::touch(env, bounty_id);
+        audit_log::record(
+            env,
+            bounty_id,
+            AuditAction::ClaimAuthorized,
+            admin,
+            amount,
+            Some(recipient.clone()),
+        );
 
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("created")),
+        events::emit_claim_created(
+            env,
             ClaimCreated {
                 bounty_id,
-                recipient,
-                amount: escrow.amount,
+                recipient: recipient.clone(),
+                amount,
                 expires_at: claim.expires_at,
             },
         );
+        events::emit_bounty_event_v3(
+            env,
+            BountyEventKind::ClaimAuthorized,
+            bounty_id,
+            Some(admin),
+            Some(recipient.clone()),
+            amount,
+        );
+    }
+
+    /// The claim's recipient sends its payout to `payout_to` instead. The
+    /// claim itself, and the contributor credited with it, are unchanged.
+    pub fn redirect_claim(env: Env, bounty_id: u64, payout_to: Address) -> Result<(), Error> {
+        let claim = validation::redirect_claim(&env, bounty_id, &payout_to, Mode::Execute)?;
+
+        if payout_to == claim.recipient {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::ClaimRedirect(bounty_id));
+        } else {
+            env.storage()
+                .persistent()
+                .set(&DataKey::ClaimRedirect(bounty_id), &payout_to);
+        }
+
+        events::emit_claim_redirected(
+            &env,
+            events::ClaimRedirected {
+                bounty_id,
+                recipient: claim.recipient.clone(),
+                amount: claim.amount,
+                payout_to,
+                redirected_at: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("clm_redir"), claim.recipient, true);
+
         Ok(())
     }
 
+    /// View: address the pending claim of `bounty_id` pays out to.
+    pub fn get_claim_payout_address(env: Env, bounty_id: u64) -> Result<Address, Error> {
+        let claim = Self::get_pending_claim(env.clone(), bounty_id)?;
+        Ok(validation::claim_payout_address(&env, &claim))
+    }
+
     /// Beneficiary calls this to claim their authorized funds within the window.
     ///
     /// # Reentrancy
     /// Protected by the shared reentrancy guard. Escrow and claim state
     /// are updated *before* the outbound token transfer (CEI pattern).
     pub fn claim(env: Env, bounty_id: u64) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-
         // GUARD: acquire reentrancy lock
         reentrancy_guard::acquire(&env);
 
-        if !env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            return Err(Error::BountyNotFound);
-        }
-        let mut claim: ClaimRecord = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
-            .unwrap();
-
-        claim.recipient.require_auth();
-
-        let now = env.ledger().timestamp();
-        if now > claim.expires_at {
-            return Err(Error::DeadlineNotPassed); // reuse or add ClaimExpired error
-        }
-        if claim.claimed {
-            return Err(Error::FundsNotLocked);
-        }
-
-        // EFFECTS: update escrow and claim state before external call (CEI)
-        let claim_amount = claim.amount;
-        let claim_recipient = claim.recipient.clone();
-
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        escrow.status = EscrowStatus::Released;
-        escrow.remaining_amount = 0;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
-
-        claim.claimed = true;
-        env.storage()
-            .persistent()
-            .set(&DataKey::PendingClaim(bounty_id), &claim);
-
-        // INTERACTION: external token transfer is last
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &claim_recipient,
-            &claim_amount,
-        );
+        let (claim, escrow) = validation::claim(&env, bounty_id, Mode::Execute)?;
+        let recipient = claim.recipient.clone();
+        Self::execute_claim(&env, claim, escrow, &recipient)?;
 
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("done")),
-            ClaimExecuted {
-                bounty_id,
-                recipient: claim_recipient,
-                amount: claim_amount,
-                claimed_at: now,
-            },
-        );
+        monitoring::track_operation(&env, symbol_short!("claim"), recipient, true);
 
         // GUARD: release reentrancy lock
         reentrancy_guard::release(&env);
@@ -1741,89 +3396,156 @@ impl BountyEscrowContract {
     }
 
     /// Delegated claim execution using a capability.
-    /// Funds are still transferred to the pending claim recipient.
+    /// Funds are still transferred to the claim's payout address.
+    ///
+    /// # Reentrancy
+    /// Same guard and CEI ordering as `claim`.
     pub fn claim_with_capability(
         env: Env,
         bounty_id: u64,
         holder: Address,
         capability_id: u64,
     ) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-        if !env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            return Err(Error::BountyNotFound);
-        }
-
-        let mut claim: ClaimRecord = env
-            .storage()
-            .persistent()
-            .get(&DataKey::PendingClaim(bounty_id))
-            .unwrap();
-
-        let now = env.ledger().timestamp();
-        if now > claim.expires_at {
-            return Err(Error::DeadlineNotPassed);
-        }
-        if claim.claimed {
-            return Err(Error::FundsNotLocked);
-        }
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
 
-        Self::consume_capability(
+        let (claim, escrow, capability) = validation::claim_with_capability(
             &env,
+            bounty_id,
             &holder,
             capability_id,
-            CapabilityAction::Claim,
-            bounty_id,
-            claim.amount,
+            Mode::Execute,
         )?;
+        Self::consume_capability(&env, &holder, capability_id, capability, claim.amount);
+        Self::execute_claim(&env, claim, escrow, &holder)?;
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
-            &claim.recipient,
-            &claim.amount,
-        );
+        monitoring::track_operation(&env, symbol_short!("claim_cap"), holder.clone(), true);
 
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-        escrow.status = EscrowStatus::Released;
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
+
+    /// Effects, payout and events of a validated claim made by `actor`.
+    fn execute_claim(
+        env: &Env,
+        mut claim: ClaimRecord,
+        mut escrow: Escrow,
+        actor: &Address,
+    ) -> Result<(), Error> {
+        let bounty_id = claim.bounty_id;
+        let payout_to = validation::claim_payout_address(env, &claim);
+        let now = env.ledger().timestamp();
+
+        // EFFECTS: update escrow and claim state before external call (CEI)
+        let owed_before = invariants::outstanding_amount(&escrow);
+        let remaining = escrow.remaining_amount - claim.amount;
+        let status = if remaining == 0 {
+            EscrowStatus::Released
+        } else {
+            escrow.status.clone()
+        };
+        invariants::transition(&escrow.status, &status, StatusAction::Claim)?;
+        let payout = if claim.amount == escrow.amount {
+            Payout::Full
+        } else {
+            Payout::Partial {
+                completes: remaining == 0,
+            }
+        };
+        escrow.status = status;
+        escrow.remaining_amount = remaining;
+        invariants::adjust_liabilities(env, owed_before, invariants::outstanding_amount(&escrow));
+        migration::store_escrow(env, bounty_id, &escrow);
+        deadlines::touch(env, bounty_id);
 
         claim.claimed = true;
         env.storage()
             .persistent()
             .set(&DataKey::PendingClaim(bounty_id), &claim);
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ClaimRedirect(bounty_id));
+        audit_log::record(
+            env,
+            bounty_id,
+            AuditAction::Claimed,
+            actor,
+            claim.amount,
+            Some(payout_to.clone()).filter(|to| to != actor),
+        );
+        contributors::record_payout(
+            env,
+            &claim.recipient,
+            &escrow.depositor,
+            bounty_id,
+            claim.amount,
+            payout,
+        );
 
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("done")),
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(env, &token_addr);
+        fees::pay_out(
+            env,
+            &client,
+            bounty_id,
+            &escrow.depositor,
+            &payout_to,
+            claim.amount,
+        );
+        invariants::ensure_solvent(env)?;
+
+        events::emit_claim_executed(
+            env,
             ClaimExecuted {
                 bounty_id,
-                recipient: claim.recipient,
+                recipient: payout_to.clone(),
                 amount: claim.amount,
                 claimed_at: now,
             },
         );
+        events::emit_bounty_event_v3(
+            env,
+            BountyEventKind::Claimed,
+            bounty_id,
+            Some(actor),
+            Some(payout_to.clone()),
+            claim.amount,
+        );
+
+        hooks::notify(
+            env,
+            hooks::claim_kind(),
+            bounty_id,
+            &payout_to,
+            claim.amount,
+        );
         Ok(())
     }
 
     /// Admin can cancel an expired or unwanted pending claim, returning escrow to Locked.
+    ///
+    /// This is a correction and does not affect the recipient's
+    /// reputation; use `reject_pending_claim` to rule against them.
     pub fn cancel_pending_claim(env: Env, bounty_id: u64) -> Result<(), Error> {
+        Self::cancel_claim(env, bounty_id, false)
+    }
+
+    /// Admin cancels a pending claim as the outcome of a dispute its
+    /// recipient lost. Same effects as `cancel_pending_claim`, and the
+    /// recipient's `disputes_lost` is incremented.
+    pub fn reject_pending_claim(env: Env, bounty_id: u64) -> Result<(), Error> {
+        Self::cancel_claim(env, bounty_id, true)
+    }
+
+    fn cancel_claim(env: Env, bounty_id: u64, dispute_lost: bool) -> Result<(), Error> {
         if !env.storage().instance().has(&DataKey::Admin) {
             return Err(Error::NotInitialized);
         }
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
+        Self::ensure_not_frozen(&env, bounty_id)?;
 
         if !env
             .storage()
@@ -1839,23 +3561,48 @@ impl BountyEscrowContract {
             .unwrap();
 
         if claim.claimed {
-            return Err(Error::FundsNotLocked);
+            return Err(Error::ClaimAlreadyExecuted);
         }
 
         env.storage()
             .persistent()
             .remove(&DataKey::PendingClaim(bounty_id));
+        env.storage()
+            .persistent()
+            .remove(&DataKey::ClaimRedirect(bounty_id));
+        audit_log::record(
+            &env,
+            bounty_id,
+            AuditAction::ClaimCancelled,
+            &admin,
+            claim.amount,
+            Some(claim.recipient.clone()),
+        );
+        if dispute_lost {
+            contributors::record_dispute_lost(&env, &claim.recipient);
+        }
 
-        env.events().publish(
-            (symbol_short!("claim"), symbol_short!("cancel")),
+        events::emit_claim_cancelled(
+            &env,
             ClaimCancelled {
                 bounty_id,
-                recipient: claim.recipient,
+                recipient: claim.recipient.clone(),
                 amount: claim.amount,
                 cancelled_at: env.ledger().timestamp(),
-                cancelled_by: admin,
+                cancelled_by: admin.clone(),
             },
         );
+        events::emit_bounty_event_v3(
+            &env,
+            BountyEventKind::ClaimCancelled,
+            bounty_id,
+            Some(&admin),
+            Some(claim.recipient),
+            claim.amount,
+        );
+
+        monitoring::track_operation(&env, symbol_short!("cancel"), admin.clone(), true);
+
         Ok(())
     }
 
@@ -1882,19 +3629,16 @@ impl BountyEscrowContract {
 
         let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
         admin.require_auth();
+        deny_list::ensure_recipient_allowed(&env, &recipient)?;
 
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
+        Self::ensure_not_frozen(&env, bounty_id)?;
 
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        let escrow: Escrow = migration::load_escrow(&env, bounty_id).unwrap();
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
+        if !invariants::allows(&escrow.status, StatusAction::Refund) {
             return Err(Error::FundsNotLocked);
         }
 
@@ -1914,6 +3658,24 @@ impl BountyEscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::RefundApproval(bounty_id), &approval);
+        audit_log::record(
+            &env,
+            bounty_id,
+            AuditAction::RefundApproved,
+            &admin,
+            amount,
+            Some(recipient.clone()),
+        );
+        events::emit_bounty_event_v3(
+            &env,
+            BountyEventKind::RefundApproved,
+            bounty_id,
+            Some(&admin),
+            Some(recipient.clone()),
+            amount,
+        );
+
+        monitoring::track_operation(&env, symbol_short!("appr_ref"), admin.clone(), true);
 
         Ok(())
     }
@@ -1938,64 +3700,41 @@ impl BountyEscrowContract {
         // GUARD: acquire reentrancy lock
         reentrancy_guard::acquire(&env);
 
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked {
-            return Err(Error::FundsNotLocked);
-        }
-
-        // Guard: zero or negative payout makes no sense and would corrupt state
-        if payout_amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
-
-        // Guard: prevent overpayment — payout cannot exceed what is still owed
-        if payout_amount > escrow.remaining_amount {
-            return Err(Error::InsufficientFunds);
-        }
+        let (admin, escrow) = validation::partial_release(
+            &env,
+            bounty_id,
+            &contributor,
+            payout_amount,
+            Mode::Execute,
+        )?;
 
         // EFFECTS: update escrow state before external call (CEI)
-        escrow.remaining_amount -= payout_amount;
-        if escrow.remaining_amount == 0 {
-            escrow.status = EscrowStatus::Released;
-        }
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        let depositor = escrow.depositor.clone();
+        Self::record_partial_release(&env, &admin, bounty_id, escrow, &contributor, payout_amount)?;
 
         // INTERACTION: external token transfer is last
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
         let client = token::Client::new(&env, &token_addr);
-        client.transfer(
-            &env.current_contract_address(),
+        fees::pay_out(
+            &env,
+            &client,
+            bounty_id,
+            &depositor,
             &contributor,
-            &payout_amount,
+            payout_amount,
         );
+        invariants::ensure_solvent(&env)?;
 
-        events::emit_funds_released(
+        Self::publish_partial_release(&env, &admin, bounty_id, &contributor, payout_amount);
+
+        monitoring::track_operation(&env, symbol_short!("part_rel"), admin.clone(), true);
+
+        hooks::notify(
             &env,
-            FundsReleased {
-                version: EVENT_VERSION_V2,
-                bounty_id,
-                amount: payout_amount,
-                recipient: contributor.clone(),
-                timestamp: env.ledger().timestamp(),
-            },
+            hooks::partial_kind(),
+            bounty_id,
+            &contributor,
+            payout_amount,
         );
 
         // GUARD: release reentrancy lock
@@ -2011,81 +3750,140 @@ impl BountyEscrowContract {
     /// history, and approval cleanup are performed *before* the outbound
     /// token transfer (CEI pattern).
     pub fn refund(env: Env, bounty_id: u64) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("refund")) {
-            return Err(Error::FundsPaused);
-        }
-
         // GUARD: acquire reentrancy lock
         reentrancy_guard::acquire(&env);
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
+        let plan = validation::refund(&env, bounty_id)?;
+        let refund_to = plan.refund_to.clone();
+        let refund_amount = plan.amount;
 
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        // EFFECTS: update state before external call (CEI)
+        let actor = Self::record_refund(&env, bounty_id, plan)?;
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
-        }
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
+        invariants::ensure_solvent(&env)?;
 
-        // Block refund if there is a pending claim (Issue #391 fix)
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
-                .storage()
-                .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
-            }
-        }
+        Self::publish_refund(&env, actor.as_ref(), bounty_id, &refund_to, refund_amount);
 
-        let now = env.ledger().timestamp();
-        let approval_key = DataKey::RefundApproval(bounty_id);
-        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
+        // A permissionless refund has no caller to credit; count it against
+        // the recipient.
+        let caller = actor.unwrap_or_else(|| refund_to.clone());
+        monitoring::track_operation(&env, symbol_short!("refund"), caller, true);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(())
+    }
 
-        // Refund is allowed if:
-        // 1. Deadline has passed (returns full amount to depositor)
-        // 2. An administrative approval exists (can be early, partial, and to custom recipient)
-        if now < escrow.deadline && approval.is_none() {
-            return Err(Error::DeadlineNotPassed);
+    /// Effects of a validated partial release: escrow, liabilities and
+    /// audit log. The caller transfers the payout afterwards.
+    fn record_partial_release(
+        env: &Env,
+        admin: &Address,
+        bounty_id: u64,
+        mut escrow: Escrow,
+        contributor: &Address,
+        payout_amount: i128,
+    ) -> Result<(), Error> {
+        let owed_before = invariants::outstanding_amount(&escrow);
+        let previous_status = escrow.status.clone();
+        escrow.remaining_amount -= payout_amount;
+        if escrow.remaining_amount == 0 {
+            escrow.status = EscrowStatus::Released;
         }
+        invariants::transition(
+            &previous_status,
+            &escrow.status,
+            StatusAction::PartialRelease,
+        )?;
+        invariants::adjust_liabilities(env, owed_before, invariants::outstanding_amount(&escrow));
+        migration::store_escrow(env, bounty_id, &escrow);
+        deadlines::touch(env, bounty_id);
+        audit_log::record(
+            env,
+            bounty_id,
+            AuditAction::PartialRelease,
+            admin,
+            payout_amount,
+            Some(contributor.clone()),
+        );
+        contributors::record_payout(
+            env,
+            contributor,
+            &escrow.depositor,
+            bounty_id,
+            payout_amount,
+            Payout::Partial {
+                completes: escrow.remaining_amount == 0,
+            },
+        );
+        Ok(())
+    }
 
-        let (refund_amount, refund_to, is_full) = if let Some(app) = approval.clone() {
-            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
-            (app.amount, app.recipient, full)
-        } else {
-            // Standard refund after deadline
-            (escrow.remaining_amount, escrow.depositor.clone(), true)
-        };
+    fn publish_partial_release(
+        env: &Env,
+        admin: &Address,
+        bounty_id: u64,
+        contributor: &Address,
+        payout_amount: i128,
+    ) {
+        events::emit_funds_released(
+            env,
+            FundsReleased {
+                version: EVENT_VERSION_V2,
+                bounty_id,
+                amount: payout_amount,
+                recipient: contributor.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        events::emit_bounty_event_v3(
+            env,
+            BountyEventKind::PartiallyReleased,
+            bounty_id,
+            Some(admin),
+            Some(contributor.clone()),
+            payout_amount,
+        );
+    }
 
-        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
-        }
+    /// Effects of a validated refund: escrow, refund history, liabilities,
+    /// approval cleanup and audit log. Returns the approver the refund is
+    /// attributed to, or `None` for a permissionless refund after the
+    /// deadline. The caller transfers the refund afterwards.
+    fn record_refund(
+        env: &Env,
+        bounty_id: u64,
+        plan: validation::RefundPlan,
+    ) -> Result<Option<Address>, Error> {
+        let validation::RefundPlan {
+            mut escrow,
+            amount: refund_amount,
+            refund_to,
+            is_full,
+            approval,
+        } = plan;
 
-        // EFFECTS: update state before external call (CEI)
-        invariants::assert_escrow(&env, &escrow);
+        invariants::assert_escrow(env, &escrow);
+        let owed_before = invariants::outstanding_amount(&escrow);
+        let previous_status = escrow.status.clone();
         escrow.remaining_amount -= refund_amount;
         if is_full || escrow.remaining_amount == 0 {
             escrow.status = EscrowStatus::Refunded;
         } else {
             escrow.status = EscrowStatus::PartiallyRefunded;
         }
+        invariants::transition(&previous_status, &escrow.status, StatusAction::Refund)?;
+        invariants::adjust_liabilities(env, owed_before, invariants::outstanding_amount(&escrow));
 
         // Add to refund history
         escrow.refund_history.push_back(RefundRecord {
             amount: refund_amount,
             recipient: refund_to.clone(),
-            timestamp: now,
+            timestamp: env.ledger().timestamp(),
             mode: if is_full {
                 RefundMode::Full
             } else {
@@ -2094,38 +3892,70 @@ impl BountyEscrowContract {
         });
 
         // Save updated escrow
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        migration::store_escrow(env, bounty_id, &escrow);
 
-        // Remove approval after successful execution
-        if approval.is_some() {
-            env.storage().persistent().remove(&approval_key);
+        // Remove approval after successful execution; the audit log keeps
+        // the approver as the actor of the refund. Without an approval the
+        // refund is permissionless and nobody is named.
+        let actor = approval.map(|app| {
+            env.storage()
+                .persistent()
+                .remove(&DataKey::RefundApproval(bounty_id));
+            app.approved_by
+        });
+        match &actor {
+            Some(approver) => audit_log::record(
+                env,
+                bounty_id,
+                AuditAction::Refund,
+                approver,
+                refund_amount,
+                Some(refund_to),
+            ),
+            None => audit_log::record_unattributed(
+                env,
+                bounty_id,
+                AuditAction::Refund,
+                refund_amount,
+                Some(refund_to),
+            ),
         }
+        Ok(actor)
+    }
 
-        // INTERACTION: external token transfer is last
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        client.transfer(&env.current_contract_address(), &refund_to, &refund_amount);
-
+    fn publish_refund(
+        env: &Env,
+        actor: Option<&Address>,
+        bounty_id: u64,
+        refund_to: &Address,
+        refund_amount: i128,
+    ) {
         emit_funds_refunded(
-            &env,
+            env,
             FundsRefunded {
                 version: EVENT_VERSION_V2,
                 bounty_id,
                 amount: refund_amount,
                 refund_to: refund_to.clone(),
-                timestamp: now,
+                timestamp: env.ledger().timestamp(),
             },
         );
-
-        // GUARD: release reentrancy lock
-        reentrancy_guard::release(&env);
-        Ok(())
+        events::emit_bounty_event_v3(
+            env,
+            BountyEventKind::Refunded,
+            bounty_id,
+            actor,
+            Some(refund_to.clone()),
+            refund_amount,
+        );
     }
 
     /// Delegated refund path using a capability.
     /// This can be used for short-lived, bounded delegated refunds without granting admin rights.
+    ///
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. Escrow state and refund
+    /// history are updated *before* the outbound token transfer (CEI pattern).
     pub fn refund_with_capability(
         env: Env,
         bounty_id: u64,
@@ -2133,67 +3963,32 @@ impl BountyEscrowContract {
         holder: Address,
         capability_id: u64,
     ) -> Result<(), Error> {
-        if Self::check_paused(&env, symbol_short!("refund")) {
-            return Err(Error::FundsPaused);
-        }
-        if amount <= 0 {
-            return Err(Error::InvalidAmount);
-        }
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return Err(Error::BountyNotFound);
-        }
-
-        let mut escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
-
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return Err(Error::FundsNotLocked);
-        }
-        if amount > escrow.remaining_amount {
-            return Err(Error::InvalidAmount);
-        }
-
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
-                .storage()
-                .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return Err(Error::ClaimPending);
-            }
-        }
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
 
-        Self::consume_capability(
+        let (mut escrow, capability) = validation::refund_with_capability(
             &env,
-            &holder,
-            capability_id,
-            CapabilityAction::Refund,
             bounty_id,
             amount,
+            &holder,
+            capability_id,
+            Mode::Execute,
         )?;
+        Self::consume_capability(&env, &holder, capability_id, capability, amount);
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
+        // EFFECTS: update state before external call (CEI)
         let now = env.ledger().timestamp();
         let refund_to = escrow.depositor.clone();
-
-        client.transfer(&env.current_contract_address(), &refund_to, &amount);
-
+        let owed_before = invariants::outstanding_amount(&escrow);
+        let previous_status = escrow.status.clone();
         escrow.remaining_amount -= amount;
         if escrow.remaining_amount == 0 {
             escrow.status = EscrowStatus::Refunded;
         } else {
             escrow.status = EscrowStatus::PartiallyRefunded;
         }
+        invariants::transition(&previous_status, &escrow.status, StatusAction::Refund)?;
+        invariants::adjust_liabilities(&env, owed_before, invariants::outstanding_amount(&escrow));
 
         escrow.refund_history.push_back(RefundRecord {
             amount,
@@ -2206,9 +4001,21 @@ impl BountyEscrowContract {
             },
         });
 
-        env.storage()
-            .persistent()
-            .set(&DataKey::Escrow(bounty_id), &escrow);
+        migration::store_escrow(&env, bounty_id, &escrow);
+        audit_log::record(
+            &env,
+            bounty_id,
+            AuditAction::Refund,
+            &holder,
+            amount,
+            Some(refund_to.clone()),
+        );
+
+        // INTERACTION: external token transfer is last
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(&env, &token_addr);
+        client.transfer(&env.current_contract_address(), &refund_to, &amount);
+        invariants::ensure_solvent(&env)?;
 
         emit_funds_refunded(
             &env,
@@ -2216,11 +4023,23 @@ impl BountyEscrowContract {
                 version: EVENT_VERSION_V2,
                 bounty_id,
                 amount,
-                refund_to,
+                refund_to: refund_to.clone(),
                 timestamp: now,
             },
         );
+        events::emit_bounty_event_v3(
+            &env,
+            BountyEventKind::Refunded,
+            bounty_id,
+            Some(&holder),
+            Some(refund_to.clone()),
+            amount,
+        );
 
+        monitoring::track_operation(&env, symbol_short!("ref_cap"), holder.clone(), true);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
         Ok(())
     }
 
@@ -2229,11 +4048,7 @@ impl BountyEscrowContract {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
-        Ok(env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap())
+        Ok(migration::load_escrow(&env, bounty_id).unwrap())
     }
 
     /// view function to get contract balance of the token
@@ -2246,304 +4061,157 @@ impl BountyEscrowContract {
         Ok(client.balance(&env.current_contract_address()))
     }
 
-    // =========================================================================
-    // Dry-Run Simulation Entry Points  (Issue #567)
-    //
-    // These are **view-only** functions that replay the validation logic of
-    // lock / release / refund WITHOUT writing any state or performing token
-    // transfers.  They return a `SimulationResult` so callers can preview
-    // the outcome and surface user-facing errors before submitting a real tx.
-    // =========================================================================
-
-    /// Simulate a `lock_funds` call.
+    /// View: contract health derived from pause flags and whether the token
+    /// balance covers the tracked `TotalLiabilities` and unclaimed fees. It
+    /// reads no escrow records; use the paged `reconcile` to check each
+    /// escrow's invariants.
     ///
-    /// Checks initialisation, duplicate bounty, pause state, and amount policy
-    /// exactly as the real function does, then returns what the resulting
-    /// escrow state would look like.  No auth is required.
-    pub fn simulate_lock(
-        env: Env,
-        depositor: Address,
-        bounty_id: u64,
-        amount: i128,
-        deadline: u64,
-    ) -> SimulationResult {
-        // --- Checks (mirrors lock_funds validation) ---
-
-        if Self::check_paused(&env, symbol_short!("lock")) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::FundsPaused as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
-
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::NotInitialized as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
-
-        if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::BountyExists as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
-
-        if amount <= 0 {
-            return SimulationResult {
-                success: false,
-                error_code: Error::InvalidAmount as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
-
-        if deadline <= env.ledger().timestamp() {
-            return SimulationResult {
-                success: false,
-                error_code: Error::InvalidDeadline as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
-
-        // Enforce amount policy if set
-        if let Some((min_amount, max_amount)) = env
-            .storage()
-            .instance()
-            .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
-        {
-            if amount < min_amount {
-                return SimulationResult {
-                    success: false,
-                    error_code: Error::AmountBelowMinimum as u32,
-                    amount: 0,
-                    resulting_status: EscrowStatus::Locked,
-                    remaining_amount: 0,
-                };
-            }
-            if amount > max_amount {
-                return SimulationResult {
-                    success: false,
-                    error_code: Error::AmountAboveMaximum as u32,
-                    amount: 0,
-                    resulting_status: EscrowStatus::Locked,
-                    remaining_amount: 0,
-                };
-            }
-        }
-
-        // Check depositor has sufficient balance
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let token_client = token::Client::new(&env, &token_addr);
-        let depositor_balance = token_client.balance(&depositor);
-        if depositor_balance < amount {
-            return SimulationResult {
-                success: false,
-                error_code: Error::InsufficientFunds as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
-
-        // --- Would succeed ---
-        SimulationResult {
-            success: true,
-            error_code: 0,
-            amount,
-            resulting_status: EscrowStatus::Locked,
-            remaining_amount: amount,
-        }
+    /// Failed invocations are rolled back and do not affect the result; see
+    /// `get_analytics` for which failures are counted.
+    pub fn health_check(env: Env) -> HealthStatus {
+        monitoring::health_check(&env)
     }
 
-    /// Simulate a `release_funds` call.
+    /// View: operation, unique-user and error counters.
     ///
-    /// Checks initialisation, existence, pause state, and escrow status
-    /// exactly as the real function does.  Returns the projected released
-    /// state.  No auth is required.
-    pub fn simulate_release(env: Env, bounty_id: u64, _contributor: Address) -> SimulationResult {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::FundsPaused as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
-
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::NotInitialized as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
-
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::BountyNotFound as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
-
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+    /// Only failures that do not abort their invocation are counted, such as
+    /// skipped items of best-effort batches. A call that returns `Err` is
+    /// rolled back, its metrics included, so it is observable only through
+    /// the transaction result, not through these counters.
+    pub fn get_analytics(env: Env) -> Analytics {
+        monitoring::get_analytics(&env)
+    }
 
-        if escrow.status != EscrowStatus::Locked {
-            return SimulationResult {
-                success: false,
-                error_code: Error::FundsNotLocked as u32,
-                amount: 0,
-                resulting_status: escrow.status,
-                remaining_amount: escrow.remaining_amount,
-            };
-        }
+    /// View: point-in-time copy of the monitoring counters.
+    pub fn get_state_snapshot(env: Env) -> StateSnapshot {
+        monitoring::get_state_snapshot(&env)
+    }
 
-        // --- Would succeed ---
-        SimulationResult {
-            success: true,
-            error_code: 0,
-            amount: escrow.amount,
-            resulting_status: EscrowStatus::Released,
-            remaining_amount: 0,
-        }
+    /// View: call count and last call time of one instrumented operation.
+    pub fn get_performance_stats(env: Env, function_name: Symbol) -> PerformanceStats {
+        monitoring::get_performance_stats(&env, function_name)
     }
 
-    /// Simulate a `refund` call.
+    /// View: number of recorded failures with the given `Error` code.
     ///
-    /// Checks pause state, existence, escrow status, pending claims,
-    /// deadline, and refund approval exactly as the real function does.
-    /// Returns the projected refund amount and resulting status.
-    /// No auth is required.
-    pub fn simulate_refund(env: Env, bounty_id: u64) -> SimulationResult {
-        if Self::check_paused(&env, symbol_short!("refund")) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::FundsPaused as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
+    /// Same scope as `get_analytics().error_count`: entrypoints that return
+    /// this error are rolled back and leave the count unchanged.
+    pub fn get_error_count(env: Env, error_code: u32) -> u64 {
+        monitoring::get_error_count(&env, error_code)
+    }
 
-        if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
-            return SimulationResult {
-                success: false,
-                error_code: Error::BountyNotFound as u32,
-                amount: 0,
-                resulting_status: EscrowStatus::Locked,
-                remaining_amount: 0,
-            };
-        }
+    // =========================================================================
+    // Dry-Run Simulation Entry Points  (Issue #567)
+    //
+    // These are **view-only** functions that run the same checks as the real
+    // entrypoints (see `validation`) WITHOUT writing any state or performing
+    // token transfers.  They return a `SimulationResult` so callers can
+    // preview the outcome and surface user-facing errors before submitting a
+    // real tx.  No auth is required.
+    // =========================================================================
 
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+    /// Simulate a `lock_funds` call, including the depositor's token balance
+    /// and the deadline.
+    pub fn simulate_lock(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline: u64,
+    ) -> SimulationResult {
+        let deadline_mode = DeadlineMode::Absolute(deadline);
+        simulation::lock(&env, &depositor, bounty_id, amount, &deadline_mode)
+    }
 
-        if escrow.status != EscrowStatus::Locked && escrow.status != EscrowStatus::PartiallyRefunded
-        {
-            return SimulationResult {
-                success: false,
-                error_code: Error::FundsNotLocked as u32,
-                amount: 0,
-                resulting_status: escrow.status,
-                remaining_amount: escrow.remaining_amount,
-            };
-        }
+    /// Simulate a `lock_funds_with_deadline_mode` call.
+    pub fn simulate_lock_with_deadline_mode(
+        env: Env,
+        depositor: Address,
+        bounty_id: u64,
+        amount: i128,
+        deadline_mode: DeadlineMode,
+    ) -> SimulationResult {
+        simulation::lock(&env, &depositor, bounty_id, amount, &deadline_mode)
+    }
 
-        // Block if there is an active pending claim
-        if env
-            .storage()
-            .persistent()
-            .has(&DataKey::PendingClaim(bounty_id))
-        {
-            let claim: ClaimRecord = env
-                .storage()
-                .persistent()
-                .get(&DataKey::PendingClaim(bounty_id))
-                .unwrap();
-            if !claim.claimed {
-                return SimulationResult {
-                    success: false,
-                    error_code: Error::ClaimPending as u32,
-                    amount: 0,
-                    resulting_status: escrow.status,
-                    remaining_amount: escrow.remaining_amount,
-                };
-            }
-        }
+    /// Simulate a `release_funds` call, including pending claims and the
+    /// post-transfer solvency check.
+    pub fn simulate_release(env: Env, bounty_id: u64, contributor: Address) -> SimulationResult {
+        simulation::release(&env, bounty_id, &contributor)
+    }
 
-        let now = env.ledger().timestamp();
-        let approval_key = DataKey::RefundApproval(bounty_id);
-        let approval: Option<RefundApproval> = env.storage().persistent().get(&approval_key);
-
-        if now < escrow.deadline && approval.is_none() {
-            return SimulationResult {
-                success: false,
-                error_code: Error::DeadlineNotPassed as u32,
-                amount: 0,
-                resulting_status: escrow.status,
-                remaining_amount: escrow.remaining_amount,
-            };
-        }
+    /// Simulate a `refund` call in whichever mode applies (deadline-based or
+    /// admin-approved).
+    pub fn simulate_refund(env: Env, bounty_id: u64) -> SimulationResult {
+        simulation::refund(&env, bounty_id)
+    }
 
-        // Calculate refund parameters (same logic as real refund)
-        let (refund_amount, is_full) = if let Some(app) = approval {
-            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
-            (app.amount, full)
-        } else {
-            (escrow.remaining_amount, true)
-        };
+    /// Simulate a `partial_release` call.
+    pub fn simulate_partial_release(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        payout_amount: i128,
+    ) -> SimulationResult {
+        simulation::partial_release(&env, bounty_id, &contributor, payout_amount)
+    }
 
-        if refund_amount <= 0 || refund_amount > escrow.remaining_amount {
-            return SimulationResult {
-                success: false,
-                error_code: Error::InvalidAmount as u32,
-                amount: 0,
-                resulting_status: escrow.status,
-                remaining_amount: escrow.remaining_amount,
-            };
-        }
+    /// Simulate executing the pending claim on `bounty_id`, including the
+    /// recipient's claim rate limit and the claim window.
+    pub fn simulate_claim(env: Env, bounty_id: u64) -> SimulationResult {
+        simulation::claim(&env, bounty_id)
+    }
 
-        // --- Would succeed ---
-        let new_remaining = escrow.remaining_amount - refund_amount;
-        let new_status = if is_full || new_remaining == 0 {
-            EscrowStatus::Refunded
-        } else {
-            EscrowStatus::PartiallyRefunded
-        };
+    /// Simulate a `release_with_capability` call.
+    pub fn simulate_release_with_capability(
+        env: Env,
+        bounty_id: u64,
+        contributor: Address,
+        payout_amount: i128,
+        holder: Address,
+        capability_id: u64,
+    ) -> SimulationResult {
+        simulation::release_with_capability(
+            &env,
+            bounty_id,
+            &contributor,
+            payout_amount,
+            &holder,
+            capability_id,
+        )
+    }
 
-        SimulationResult {
-            success: true,
-            error_code: 0,
-            amount: refund_amount,
-            resulting_status: new_status,
-            remaining_amount: new_remaining,
-        }
+    /// Simulate a `claim_with_capability` call.
+    pub fn simulate_claim_with_capability(
+        env: Env,
+        bounty_id: u64,
+        holder: Address,
+        capability_id: u64,
+    ) -> SimulationResult {
+        simulation::claim_with_capability(&env, bounty_id, &holder, capability_id)
+    }
+
+    /// Simulate a `refund_with_capability` call.
+    pub fn simulate_refund_with_capability(
+        env: Env,
+        bounty_id: u64,
+        amount: i128,
+        holder: Address,
+        capability_id: u64,
+    ) -> SimulationResult {
+        simulation::refund_with_capability(&env, bounty_id, amount, &holder, capability_id)
+    }
+
+    /// Simulate a `batch_lock_funds` call, returning one result per item in
+    /// order. The real call succeeds only if every item does.
+    pub fn simulate_batch_lock(env: Env, items: Vec<LockFundsItem>) -> Vec<SimulationResult> {
+        simulation::batch_lock(&env, &items)
+    }
+
+    /// Simulate a `batch_release_funds` call, returning one result per item
+    /// in order. The real call succeeds only if every item does.
+    pub fn simulate_batch_release(env: Env, items: Vec<ReleaseFundsItem>) -> Vec<SimulationResult> {
+        simulation::batch_release(&env, &items)
     }
 
     /// Query escrows with filtering and pagination
@@ -2569,11 +4237,7 @@ impl BountyEscrowContract {
             }
 
             let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
+            if let Some(escrow) = migration::load_escrow(&env, bounty_id) {
                 if escrow.status == status {
                     if skipped < offset {
                         skipped += 1;
@@ -2610,11 +4274,7 @@ impl BountyEscrowContract {
             }
 
             let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
+            if let Some(escrow) = migration::load_escrow(&env, bounty_id) {
                 if escrow.amount >= min_amount && escrow.amount <= max_amount {
                     if skipped < offset {
                         skipped += 1;
@@ -2651,11 +4311,7 @@ impl BountyEscrowContract {
             }
 
             let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
+            if let Some(escrow) = migration::load_escrow(&env, bounty_id) {
                 if escrow.deadline >= min_deadline && escrow.deadline <= max_deadline {
                     if skipped < offset {
                         skipped += 1;
@@ -2687,17 +4343,41 @@ impl BountyEscrowContract {
 
         for i in start..end {
             let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
+            if let Some(escrow) = migration::load_escrow(&env, bounty_id) {
+                results.push_back(EscrowWithId { bounty_id, escrow });
+            }
+        }
+        results
+    }
+
+    /// Query escrows a contributor has been paid from, in order of first
+    /// payout.
+    pub fn query_escrows_by_contributor(
+        env: Env,
+        contributor: Address,
+        offset: u32,
+        limit: u32,
+    ) -> Vec<EscrowWithId> {
+        let index = contributors::get_index(&env, &contributor);
+        let mut results = Vec::new(&env);
+        let start = offset.min(index.len());
+        let end = offset.saturating_add(limit).min(index.len());
+
+        for i in start..end {
+            let bounty_id = index.get(i).unwrap();
+            if let Some(escrow) = migration::load_escrow(&env, bounty_id) {
                 results.push_back(EscrowWithId { bounty_id, escrow });
             }
         }
         results
     }
 
+    /// View: payout statistics for a contributor. All zero for an address
+    /// that has never been paid.
+    pub fn get_contributor_stats(env: Env, contributor: Address) -> ContributorStats {
+        contributors::get_stats(&env, &contributor)
+    }
+
     /// Get aggregate statistics
     pub fn get_aggregate_stats(env: Env) -> AggregateStats {
         let index: Vec<u64> = env
@@ -2712,15 +4392,13 @@ impl BountyEscrowContract {
             count_locked: 0,
             count_released: 0,
             count_refunded: 0,
+            total_frozen: 0,
+            count_frozen: 0,
         };
 
         for i in 0..index.len() {
             let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
+            if let Some(escrow) = migration::load_escrow(&env, bounty_id) {
                 match escrow.status {
                     EscrowStatus::Locked => {
                         stats.total_locked += escrow.amount;
@@ -2734,6 +4412,10 @@ impl BountyEscrowContract {
                         stats.total_refunded += escrow.amount;
                         stats.count_refunded += 1;
                     }
+                    EscrowStatus::Frozen => {
+                        stats.total_frozen += escrow.remaining_amount;
+                        stats.count_frozen += 1;
+                    }
                 }
             }
         }
@@ -2783,10 +4465,108 @@ impl BountyEscrowContract {
         env.storage()
             .instance()
             .set(&DataKey::AmountPolicy, &(min_amount, max_amount));
+        events::emit_amount_policy_updated(
+            &env,
+            events::AmountPolicyUpdated {
+                version: EVENT_VERSION_V3,
+                min_amount,
+                max_amount,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("amt_pol"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    /// Set lock limits for `token` in whole-token units (admin only).
+    ///
+    /// The limits are converted with the token's `decimals()` and stored in
+    /// base units; they replace the global `set_amount_policy` limits for
+    /// that token.
+    ///
+    /// # Errors
+    /// * `InvalidAmount` - Negative limits, `min_units > max_units`, or a
+    ///   limit that overflows once scaled
+    pub fn set_token_amount_policy(
+        env: Env,
+        caller: Address,
+        token: Address,
+        min_units: i128,
+        max_units: i128,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        if caller != admin {
+            return Err(Error::Unauthorized);
+        }
+        admin.require_auth();
+
+        if min_units < 0 || min_units > max_units {
+            return Err(Error::InvalidAmount);
+        }
+        let decimals = units::cache_decimals(&env, &token);
+        let policy = units::AmountPolicy {
+            min_amount: units::from_whole(min_units, decimals)?,
+            max_amount: units::from_whole(max_units, decimals)?,
+        };
+        units::set_token_policy(&env, &token, &policy);
+        events::emit_token_amount_policy_updated(
+            &env,
+            events::TokenAmountPolicyUpdated {
+                version: EVENT_VERSION_V2,
+                token,
+                decimals,
+                min_amount: policy.min_amount,
+                max_amount: policy.max_amount,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("amt_pol"), admin, true);
 
         Ok(())
     }
 
+    /// View: lock limits enforced for `token`, in base and whole-token units.
+    pub fn get_amount_policy(env: Env, token: Address) -> Option<units::AmountPolicyView> {
+        let (policy, per_token) = units::effective_policy(&env, &token)?;
+        let decimals = units::decimals(&env, &token);
+        Some(units::AmountPolicyView {
+            token,
+            min: units::display(policy.min_amount, decimals),
+            max: units::display(policy.max_amount, decimals),
+            per_token,
+        })
+    }
+
+    /// View: decimals of the escrow token.
+    pub fn get_token_decimals(env: Env) -> Result<u32, Error> {
+        let token = units::escrow_token(&env).ok_or(Error::NotInitialized)?;
+        Ok(units::decimals(&env, &token))
+    }
+
+    /// View: `amount` base units of the escrow token in both scales.
+    pub fn to_display_amount(env: Env, amount: i128) -> units::DisplayAmount {
+        units::display_escrow(&env, amount)
+    }
+
+    /// View: locked and remaining amounts of an escrow in both scales.
+    pub fn get_escrow_amounts(env: Env, bounty_id: u64) -> Result<units::EscrowAmounts, Error> {
+        let escrow = migration::load_escrow(&env, bounty_id).ok_or(Error::BountyNotFound)?;
+        let decimals = units::escrow_decimals(&env);
+        Ok(units::EscrowAmounts {
+            amount: units::display(escrow.amount, decimals),
+            remaining_amount: units::display(escrow.remaining_amount, decimals),
+        })
+    }
+
     /// Get escrow IDs by status
     pub fn get_escrow_ids_by_status(
         env: Env,
@@ -2808,11 +4588,7 @@ impl BountyEscrowContract {
                 break;
             }
             let bounty_id = index.get(i).unwrap();
-            if let Some(escrow) = env
-                .storage()
-                .persistent()
-                .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-            {
+            if let Some(escrow) = migration::load_escrow(&env, bounty_id) {
                 if escrow.status == status {
                     if skipped < offset {
                         skipped += 1;
@@ -2834,6 +4610,9 @@ impl BountyEscrowContract {
             .ok_or(Error::NotInitialized)?;
         current.require_auth();
         anti_abuse::set_admin(&env, admin);
+
+        monitoring::track_operation(&env, symbol_short!("aa_admin"), current.clone(), true);
+
         Ok(())
     }
 
@@ -2853,7 +4632,20 @@ impl BountyEscrowContract {
             .get(&DataKey::Admin)
             .ok_or(Error::NotInitialized)?;
         admin.require_auth();
-        anti_abuse::set_whitelist(&env, whitelisted_address, whitelisted);
+        anti_abuse::set_whitelist(&env, whitelisted_address.clone(), whitelisted);
+        events::emit_whitelist_updated(
+            &env,
+            events::WhitelistUpdated {
+                version: EVENT_VERSION_V3,
+                address: whitelisted_address,
+                whitelisted,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("whitelist"), admin.clone(), true);
+
         Ok(())
     }
 
@@ -2876,16 +4668,196 @@ impl BountyEscrowContract {
             cooldown_period,
         };
         anti_abuse::set_config(&env, config);
+        events::emit_anti_abuse_config_updated(
+            &env,
+            events::AntiAbuseConfigUpdated {
+                version: EVENT_VERSION_V3,
+                window_size,
+                max_operations,
+                cooldown_period,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("aa_config"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    /// Get current anti-abuse config (rate limit and cooldown).
+    pub fn get_anti_abuse_config(env: Env) -> AntiAbuseConfigView {
+        let c = anti_abuse::get_config(&env);
+        AntiAbuseConfigView {
+            window_size: c.window_size,
+            max_operations: c.max_operations,
+            cooldown_period: c.cooldown_period,
+        }
+    }
+
+    /// Override the rate limit of a single operation (admin only).
+    ///
+    /// Without an override `Lock` uses the global anti-abuse config while
+    /// `Claim` and `Capability` are unlimited.
+    pub fn update_operation_rate_limit(
+        env: Env,
+        operation: RateLimitOperation,
+        window_size: u64,
+        max_operations: u32,
+        cooldown_period: u64,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        let config = anti_abuse::AntiAbuseConfig {
+            window_size,
+            max_operations,
+            cooldown_period,
+        };
+        anti_abuse::set_operation_config(&env, operation.clone(), Some(config));
+        events::emit_operation_rate_limit_updated(
+            &env,
+            events::OperationRateLimitUpdated {
+                version: EVENT_VERSION_V3,
+                operation,
+                window_size,
+                max_operations,
+                cooldown_period,
+                cleared: false,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("rate_lim"), admin.clone(), true);
+
+        Ok(())
+    }
+
+    /// Remove a per-operation override, restoring the default limit (admin only).
+    pub fn clear_operation_rate_limit(
+        env: Env,
+        operation: RateLimitOperation,
+    ) -> Result<(), Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+        anti_abuse::set_operation_config(&env, operation.clone(), None);
+        events::emit_operation_rate_limit_updated(
+            &env,
+            events::OperationRateLimitUpdated {
+                version: EVENT_VERSION_V3,
+                operation,
+                window_size: 0,
+                max_operations: 0,
+                cooldown_period: 0,
+                cleared: true,
+                updated_by: admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("rate_clr"), admin.clone(), true);
+
         Ok(())
     }
 
-    /// Get current anti-abuse config (rate limit and cooldown).
-    pub fn get_anti_abuse_config(env: Env) -> AntiAbuseConfigView {
-        let c = anti_abuse::get_config(&env);
-        AntiAbuseConfigView {
-            window_size: c.window_size,
-            max_operations: c.max_operations,
-            cooldown_period: c.cooldown_period,
+    /// Effective rate limit for `operation`, or `None` when it is unlimited.
+    pub fn get_operation_rate_limit(
+        env: Env,
+        operation: RateLimitOperation,
+    ) -> Option<AntiAbuseConfigView> {
+        anti_abuse::get_operation_config(&env, &operation).map(|c| AntiAbuseConfigView {
+            window_size: c.window_size,
+            max_operations: c.max_operations,
+            cooldown_period: c.cooldown_period,
+        })
+    }
+
+    /// View: rate-limit counters and cooldowns of `address` for every limited operation.
+    pub fn get_rate_limit_state(env: Env, address: Address) -> RateLimitState {
+        let whitelisted = anti_abuse::is_whitelisted(&env, address.clone());
+        RateLimitState {
+            whitelisted,
+            lock: Self::operation_rate_limit_state(
+                &env,
+                &address,
+                RateLimitOperation::Lock,
+                whitelisted,
+            ),
+            claim: Self::operation_rate_limit_state(
+                &env,
+                &address,
+                RateLimitOperation::Claim,
+                whitelisted,
+            ),
+            capability: Self::operation_rate_limit_state(
+                &env,
+                &address,
+                RateLimitOperation::Capability,
+                whitelisted,
+            ),
+        }
+    }
+
+    fn operation_rate_limit_state(
+        env: &Env,
+        address: &Address,
+        operation: RateLimitOperation,
+        whitelisted: bool,
+    ) -> OperationRateLimitState {
+        let config = anti_abuse::get_operation_config(env, &operation);
+        let state = anti_abuse::get_state(env, address, &operation);
+        let (operation_count, window_start_timestamp, last_operation_timestamp) = match state {
+            Some(s) => (
+                s.operation_count,
+                s.window_start_timestamp,
+                s.last_operation_timestamp,
+            ),
+            None => (0, 0, 0),
+        };
+
+        let config = match config {
+            Some(config) if !whitelisted => config,
+            _ => {
+                return OperationRateLimitState {
+                    enforced: false,
+                    operation_count,
+                    window_start_timestamp,
+                    last_operation_timestamp,
+                    remaining_operations: u32::MAX,
+                    cooldown_ends_at: 0,
+                }
+            }
+        };
+
+        let now = env.ledger().timestamp();
+        let window_expired = operation_count == 0
+            || now >= window_start_timestamp.saturating_add(config.window_size);
+        let remaining_operations = if window_expired {
+            config.max_operations
+        } else {
+            config.max_operations.saturating_sub(operation_count)
+        };
+        let cooldown_ends_at = if last_operation_timestamp > 0 {
+            last_operation_timestamp.saturating_add(config.cooldown_period)
+        } else {
+            0
+        };
+
+        OperationRateLimitState {
+            enforced: true,
+            operation_count,
+            window_start_timestamp,
+            last_operation_timestamp,
+            remaining_operations,
+            cooldown_ends_at,
         }
     }
 
@@ -2902,26 +4874,38 @@ impl BountyEscrowContract {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        let escrow: Escrow = migration::load_escrow(&env, bounty_id).unwrap();
         Ok(escrow.refund_history)
     }
 
     /// NEW: Verify escrow invariants for a specific bounty
     pub fn verify_state(env: Env, bounty_id: u64) -> bool {
-        if let Some(escrow) = env
-            .storage()
-            .persistent()
-            .get::<DataKey, Escrow>(&DataKey::Escrow(bounty_id))
-        {
+        if let Some(escrow) = migration::load_escrow(&env, bounty_id) {
             invariants::verify_escrow_invariants(&escrow)
         } else {
             false
         }
     }
+
+    /// The escrow status state machine: every `(from, to, action)` move the
+    /// contract will perform. Moves not listed are rejected.
+    pub fn get_status_transitions(env: Env) -> Vec<StatusTransition> {
+        invariants::status_transitions(&env)
+    }
+
+    /// Compare the contract's token balance against the tracked sum of
+    /// outstanding escrow liabilities.
+    pub fn verify_solvency(env: Env) -> SolvencyReport {
+        invariants::solvency_report(&env)
+    }
+
+    /// Walk up to `limit` escrows from position `cursor` of the escrow index
+    /// and report per-escrow discrepancies. Resume with `next_cursor` until it
+    /// is `None`; `page_liabilities` summed across pages should equal
+    /// `total_liabilities`.
+    pub fn reconcile(env: Env, cursor: u32, limit: u32) -> ReconciliationReport {
+        invariants::reconcile(&env, cursor, limit)
+    }
     /// Gets refund eligibility information for a bounty.
     ///
     /// # Arguments
@@ -2931,7 +4915,8 @@ impl BountyEscrowContract {
     /// # Returns
     /// * `Ok((bool, bool, i128, Option<RefundApproval>))` - Tuple containing:
     ///   - can_refund: Whether refund is possible
-    ///   - deadline_passed: Whether the deadline has passed
+    ///   - deadline_passed: Whether the deadline has passed under the
+    ///     escrow's `DeadlineMode` (never for open-ended escrows)
     ///   - remaining: Remaining amount in escrow
     ///   - approval: Optional refund approval if exists
     /// * `Err(Error::BountyNotFound)` - Bounty doesn't exist
@@ -2942,14 +4927,9 @@ impl BountyEscrowContract {
         if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
             return Err(Error::BountyNotFound);
         }
-        let escrow: Escrow = env
-            .storage()
-            .persistent()
-            .get(&DataKey::Escrow(bounty_id))
-            .unwrap();
+        let escrow: Escrow = migration::load_escrow(&env, bounty_id).unwrap();
 
-        let now = env.ledger().timestamp();
-        let deadline_passed = now >= escrow.deadline;
+        let deadline_passed = deadlines::has_passed(&env, bounty_id, &escrow);
 
         let approval = if env
             .storage()
@@ -2967,11 +4947,12 @@ impl BountyEscrowContract {
         };
 
         // can_refund is true if:
-        // 1. Status is Locked or PartiallyRefunded AND
-        // 2. (deadline has passed OR there's an approval)
-        let can_refund = (escrow.status == EscrowStatus::Locked
-            || escrow.status == EscrowStatus::PartiallyRefunded)
-            && (deadline_passed || approval.is_some());
+        // 1. the transition table allows a refund from the status AND
+        // 2. (deadline has passed OR there's an approval) AND
+        // 3. no live claim blocks it (expired claims are void)
+        let can_refund = invariants::allows(&escrow.status, StatusAction::Refund)
+            && (deadline_passed || approval.is_some())
+            && validation::ensure_no_claim_blocks_refund(&env, bounty_id).is_ok();
 
         Ok((
             can_refund,
@@ -2997,66 +4978,520 @@ impl BountyEscrowContract {
     ///
     /// # Note
     /// This operation is atomic - if any item fails, the entire transaction reverts.
-    /// # Reentrancy
-    /// Protected by the shared reentrancy guard. All escrow records are
-    /// written first; token transfers happen in a second pass (CEI).
-    pub fn batch_lock_funds(env: Env, items: Vec<LockFundsItem>) -> Result<u32, Error> {
-        if Self::check_paused(&env, symbol_short!("lock")) {
-            return Err(Error::FundsPaused);
-        }
+    /// See `batch_lock_funds_best_effort` to skip invalid items instead.
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. All escrow records are
+    /// written first; token transfers happen in a second pass (CEI).
+    pub fn batch_lock_funds(env: Env, items: Vec<LockFundsItem>) -> Result<u32, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        validation::batch(&env, symbol_short!("lock"), items.len())?;
+
+        // Validate all items before processing (all-or-nothing approach)
+        for item in items.iter() {
+            let duplicate =
+                validation::is_repeated(items.iter().map(|i| i.bounty_id), item.bounty_id);
+            validation::batch_lock_item(&env, &item, duplicate)?;
+        }
+
+        let locked_count = Self::apply_batch_lock(&env, &items);
+
+        // Emit batch event
+        emit_batch_funds_locked(
+            &env,
+            BatchFundsLocked {
+                count: locked_count,
+                total_amount: items.iter().map(|i| i.amount).sum(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(
+            &env,
+            symbol_short!("batch_lck"),
+            items.get(0).unwrap().depositor,
+            true,
+        );
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(locked_count)
+    }
+
+    /// Best-effort variant of `batch_lock_funds`: locks every valid item and
+    /// skips the rest.
+    ///
+    /// An item is skipped if its bounty already exists, its amount is not
+    /// positive, its bounty id already appeared earlier in the batch, or its
+    /// depositor cannot fund it on top of their earlier items. Each item
+    /// publishes a `b_item` event with its outcome and the batch ends with a
+    /// `b_summary` event.
+    ///
+    /// # Returns
+    /// One `BatchItemResult` per item, in input order.
+    ///
+    /// # Errors
+    /// Only batch-level failures abort the call: FundsPaused,
+    /// InvalidBatchSize and NotInitialized.
+    ///
+    /// # Reentrancy
+    /// Same guard and CEI ordering as `batch_lock_funds`, applied to the
+    /// accepted items.
+    pub fn batch_lock_funds_best_effort(
+        env: Env,
+        items: Vec<LockFundsItem>,
+    ) -> Result<Vec<BatchItemResult>, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let operation = symbol_short!("lock");
+        validation::batch(&env, operation.clone(), items.len())?;
+
+        let mut accepted: Vec<LockFundsItem> = Vec::new(&env);
+        let mut outcomes: Vec<(u64, Option<Error>)> = Vec::new(&env);
+        for (index, item) in items.iter().enumerate() {
+            let duplicate = items
+                .iter()
+                .take(index)
+                .any(|earlier| earlier.bounty_id == item.bounty_id);
+            match validation::best_effort_lock_item(&env, &accepted, &item, duplicate) {
+                Ok(()) => {
+                    accepted.push_back(item.clone());
+                    outcomes.push_back((item.bounty_id, None));
+                }
+                Err(err) => {
+                    monitoring::track_error(
+                        &env,
+                        symbol_short!("batch_lbe"),
+                        item.depositor.clone(),
+                        err,
+                    );
+                    outcomes.push_back((item.bounty_id, Some(err)));
+                }
+            }
+        }
+
+        if !accepted.is_empty() {
+            Self::apply_batch_lock(&env, &accepted);
+            monitoring::track_operation(
+                &env,
+                symbol_short!("batch_lbe"),
+                accepted.get(0).unwrap().depositor,
+                true,
+            );
+        }
+        let total_amount = accepted.iter().map(|i| i.amount).sum();
+        let results = Self::report_batch_outcomes(&env, operation, &outcomes, total_amount);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(results)
+    }
+
+    /// Batch release funds to multiple contributors in a single transaction.
+    /// This improves gas efficiency by reducing transaction overhead.
+    ///
+    /// # Arguments
+    /// * `items` - Vector of ReleaseFundsItem containing bounty_id and contributor address
+    ///
+    /// # Returns
+    /// Number of successfully released bounties
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
+    /// * BountyNotFound - if any bounty_id doesn't exist
+    /// * FundsNotLocked - if any bounty is not in Locked status
+    /// * Unauthorized - if caller is not admin
+    ///
+    /// # Note
+    /// This operation is atomic - if any item fails, the entire transaction reverts.
+    /// See `batch_release_funds_best_effort` to skip invalid items instead.
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. All escrow records are
+    /// updated to `Released` first; token transfers happen in a second
+    /// pass (CEI).
+    pub fn batch_release_funds(env: Env, items: Vec<ReleaseFundsItem>) -> Result<u32, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        validation::batch(&env, symbol_short!("release"), items.len())?;
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        // Validate all items before processing (all-or-nothing approach)
+        let mut total_amount: i128 = 0;
+        for item in items.iter() {
+            let duplicate =
+                validation::is_repeated(items.iter().map(|i| i.bounty_id), item.bounty_id);
+            let escrow = validation::batch_release_item(&env, &item, duplicate)?;
+            total_amount = total_amount
+                .checked_add(escrow.amount)
+                .ok_or(Error::InvalidAmount)?;
+        }
+
+        let released_count = Self::apply_batch_release(&env, &admin, &items)?;
+        invariants::ensure_solvent(&env)?;
+
+        // Emit batch event
+        emit_batch_funds_released(
+            &env,
+            BatchFundsReleased {
+                count: released_count,
+                total_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("batch_rel"), admin.clone(), true);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(released_count)
+    }
+
+    /// Best-effort variant of `batch_release_funds`: releases every valid
+    /// item and skips the rest.
+    ///
+    /// An item is skipped for any reason `batch_release_funds` would reject
+    /// it, if its bounty id already appeared earlier in the batch, or if
+    /// paying it on top of the earlier items would leave the contract
+    /// insolvent. Each item publishes a `b_item` event with its outcome and
+    /// the batch ends with a `b_summary` event.
+    ///
+    /// # Returns
+    /// One `BatchItemResult` per item, in input order.
+    ///
+    /// # Errors
+    /// Only batch-level failures abort the call: FundsPaused,
+    /// InvalidBatchSize, NotInitialized and admin auth.
+    ///
+    /// # Reentrancy
+    /// Same guard and CEI ordering as `batch_release_funds`, applied to the
+    /// accepted items.
+    pub fn batch_release_funds_best_effort(
+        env: Env,
+        items: Vec<ReleaseFundsItem>,
+    ) -> Result<Vec<BatchItemResult>, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let operation = symbol_short!("release");
+        validation::batch(&env, operation.clone(), items.len())?;
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        let mut accepted: Vec<ReleaseFundsItem> = Vec::new(&env);
+        let mut outcomes: Vec<(u64, Option<Error>)> = Vec::new(&env);
+        let mut total_amount: i128 = 0;
+        let mut owed_released: i128 = 0;
+        for (index, item) in items.iter().enumerate() {
+            let duplicate = items
+                .iter()
+                .take(index)
+                .any(|earlier| earlier.bounty_id == item.bounty_id);
+            let outcome =
+                validation::batch_release_item(&env, &item, duplicate).and_then(|escrow| {
+                    let paid = total_amount + escrow.amount;
+                    let owed = owed_released + invariants::outstanding_amount(&escrow);
+                    invariants::ensure_solvent_after(&env, paid, owed, 0)?;
+                    Ok((paid, owed))
+                });
+            match outcome {
+                Ok((paid, owed)) => {
+                    total_amount = paid;
+                    owed_released = owed;
+                    accepted.push_back(item.clone());
+                    outcomes.push_back((item.bounty_id, None));
+                }
+                Err(err) => {
+                    monitoring::track_error(&env, symbol_short!("batch_rbe"), admin.clone(), err);
+                    outcomes.push_back((item.bounty_id, Some(err)));
+                }
+            }
+        }
+
+        if !accepted.is_empty() {
+            Self::apply_batch_release(&env, &admin, &accepted)?;
+            invariants::ensure_solvent(&env)?;
+            monitoring::track_operation(&env, symbol_short!("batch_rbe"), admin.clone(), true);
+        }
+        let results = Self::report_batch_outcomes(&env, operation, &outcomes, total_amount);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(results)
+    }
+
+    /// Batch refund several bounties in a single transaction.
+    ///
+    /// Each bounty is refunded exactly as `refund` would: in full to the
+    /// depositor after its deadline, or as its pending refund approval
+    /// says. Refunds to the same recipient are paid in one transfer.
+    ///
+    /// # Returns
+    /// Number of refunded bounties
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
+    /// * DuplicateBountyId - if a bounty id appears more than once
+    /// * Any error `refund` would return for one of the bounties
+    ///
+    /// # Note
+    /// This operation is atomic - if any item fails, the entire transaction reverts.
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. Every escrow is updated
+    /// before the aggregated token transfers (CEI).
+    pub fn batch_refund(env: Env, bounty_ids: Vec<u64>) -> Result<u32, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        validation::batch(&env, symbol_short!("refund"), bounty_ids.len())?;
+
+        // Validate all items before processing (all-or-nothing approach)
+        let mut total_amount: i128 = 0;
+        for bounty_id in bounty_ids.iter() {
+            let duplicate = validation::is_repeated(bounty_ids.iter(), bounty_id);
+            let plan = validation::batch_refund_item(&env, bounty_id, duplicate)?;
+            total_amount = total_amount
+                .checked_add(plan.amount)
+                .ok_or(Error::InvalidAmount)?;
+        }
+
+        // EFFECTS: update every escrow before any external call (CEI). The
+        // plans are derived again; no item touches another item's escrow.
+        let mut payouts: Vec<(Address, i128)> = Vec::new(&env);
+        let mut actors: Vec<Option<Address>> = Vec::new(&env);
+        for bounty_id in bounty_ids.iter() {
+            let plan = validation::refund(&env, bounty_id)?;
+            payouts.push_back((plan.refund_to.clone(), plan.amount));
+            actors.push_back(Self::record_refund(&env, bounty_id, plan)?);
+        }
+
+        // INTERACTION: one transfer per recipient
+        Self::transfer_aggregated(&env, &payouts);
+        invariants::ensure_solvent(&env)?;
+
+        for (idx, bounty_id) in bounty_ids.iter().enumerate() {
+            let (refund_to, amount) = payouts.get(idx as u32).unwrap();
+            let actor = actors.get(idx as u32).unwrap();
+            Self::publish_refund(&env, actor.as_ref(), bounty_id, &refund_to, amount);
+        }
+        events::emit_batch_funds_refunded(
+            &env,
+            events::BatchFundsRefunded {
+                count: bounty_ids.len(),
+                total_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        let caller = actors
+            .get(0)
+            .unwrap()
+            .unwrap_or_else(|| payouts.get(0).unwrap().0);
+        monitoring::track_operation(&env, symbol_short!("batch_ref"), caller, true);
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(bounty_ids.len())
+    }
+
+    /// Batch partial release: pay part of several escrows in a single
+    /// transaction. Only the admin can call this.
+    ///
+    /// Each item is checked as `partial_release` would check it. Payouts to
+    /// the same contributor are paid in one transfer.
+    ///
+    /// # Returns
+    /// Number of payouts made
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
+    /// * DuplicateBountyId - if a bounty id appears more than once
+    /// * Any error `partial_release` would return for one of the items
+    ///
+    /// # Note
+    /// This operation is atomic - if any item fails, the entire transaction reverts.
+    /// # Reentrancy
+    /// Protected by the shared reentrancy guard. Every escrow is updated
+    /// before the aggregated token transfers (CEI).
+    pub fn batch_partial_release(env: Env, items: Vec<PartialReleaseItem>) -> Result<u32, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        validation::batch(&env, symbol_short!("release"), items.len())?;
+
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
+
+        // Validate all items before processing (all-or-nothing approach)
+        let mut total_amount: i128 = 0;
+        for item in items.iter() {
+            let duplicate =
+                validation::is_repeated(items.iter().map(|i| i.bounty_id), item.bounty_id);
+            validation::batch_partial_release_item(&env, &item, duplicate)?;
+            total_amount = total_amount
+                .checked_add(item.amount)
+                .ok_or(Error::InvalidAmount)?;
+        }
+
+        // EFFECTS: update every escrow before any external call (CEI)
+        let mut payouts: Vec<(Address, i128)> = Vec::new(&env);
+        for item in items.iter() {
+            let escrow = validation::load_escrow(&env, item.bounty_id)?;
+            let depositor = escrow.depositor.clone();
+            Self::record_partial_release(
+                &env,
+                &admin,
+                item.bounty_id,
+                escrow,
+                &item.contributor,
+                item.amount,
+            )?;
+            fees::push_payout(
+                &env,
+                &mut payouts,
+                item.bounty_id,
+                &depositor,
+                &item.contributor,
+                item.amount,
+            );
+        }
+
+        // INTERACTION: one transfer per contributor
+        Self::transfer_aggregated(&env, &payouts);
+        invariants::ensure_solvent(&env)?;
+
+        for item in items.iter() {
+            Self::publish_partial_release(
+                &env,
+                &admin,
+                item.bounty_id,
+                &item.contributor,
+                item.amount,
+            );
+        }
+        events::emit_batch_partial_released(
+            &env,
+            events::BatchPartialReleased {
+                count: items.len(),
+                total_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+
+        monitoring::track_operation(&env, symbol_short!("batch_prl"), admin.clone(), true);
+
+        for item in items.iter() {
+            hooks::notify(
+                &env,
+                hooks::partial_kind(),
+                item.bounty_id,
+                &item.contributor,
+                item.amount,
+            );
+        }
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(items.len())
+    }
+
+    /// Batch `authorize_claim`: open a pending claim on several bounties in
+    /// a single transaction. Only the admin can call this.
+    ///
+    /// # Returns
+    /// Number of claims authorised
+    ///
+    /// # Errors
+    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
+    /// * DuplicateBountyId - if a bounty id appears more than once
+    /// * ClaimPending - if a bounty already has a live claim
+    /// * Any error `authorize_claim` would return for one of the items
+    ///
+    /// # Note
+    /// This operation is atomic - if any item fails, the entire transaction reverts.
+    pub fn batch_authorize_claims(
+        env: Env,
+        items: Vec<ClaimAuthorizationItem>,
+    ) -> Result<u32, Error> {
+        validation::batch(&env, symbol_short!("release"), items.len())?;
 
-        // GUARD: acquire reentrancy lock
-        reentrancy_guard::acquire(&env);
+        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+        admin.require_auth();
 
-        // Validate batch size
-        let batch_size = items.len();
-        if batch_size == 0 {
-            return Err(Error::InvalidBatchSize);
-        }
-        if batch_size > MAX_BATCH_SIZE {
-            return Err(Error::InvalidBatchSize);
+        // Validate all items before processing (all-or-nothing approach)
+        let mut total_amount: i128 = 0;
+        for item in items.iter() {
+            let duplicate =
+                validation::is_repeated(items.iter().map(|i| i.bounty_id), item.bounty_id);
+            let escrow = validation::batch_claim_item(&env, &item, duplicate)?;
+            total_amount = total_amount
+                .checked_add(escrow.remaining_amount)
+                .ok_or(Error::InvalidAmount)?;
         }
 
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
+        for item in items.iter() {
+            let escrow = validation::load_escrow(&env, item.bounty_id)?;
+            Self::record_claim_authorization(
+                &env,
+                &admin,
+                item.bounty_id,
+                escrow.remaining_amount,
+                &item.recipient,
+            );
         }
+        events::emit_batch_claims_authorized(
+            &env,
+            events::BatchClaimsAuthorized {
+                count: items.len(),
+                total_amount,
+                timestamp: env.ledger().timestamp(),
+            },
+        );
 
-        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        let contract_address = env.current_contract_address();
-        let timestamp = env.ledger().timestamp();
+        monitoring::track_operation(&env, symbol_short!("batch_clm"), admin.clone(), true);
 
-        // Validate all items before processing (all-or-nothing approach)
-        for item in items.iter() {
-            // Check if bounty already exists
-            if env
-                .storage()
-                .persistent()
-                .has(&DataKey::Escrow(item.bounty_id))
-            {
-                return Err(Error::BountyExists);
-            }
+        Ok(items.len())
+    }
 
-            // Validate amount
-            if item.amount <= 0 {
-                return Err(Error::InvalidAmount);
-            }
+    /// Add a new escrow to the global and per-depositor indexes.
+    fn index_escrow(env: &Env, bounty_id: u64, depositor: &Address) {
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(env));
+        index.push_back(bounty_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowIndex, &index);
 
-            // Check for duplicate bounty_ids in the batch
-            let mut count = 0u32;
-            for other_item in items.iter() {
-                if other_item.bounty_id == item.bounty_id {
-                    count += 1;
-                }
-            }
-            if count > 1 {
-                return Err(Error::DuplicateBountyId);
-            }
-        }
+        let depositor_key = DataKey::DepositorIndex(depositor.clone());
+        let mut depositor_index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&depositor_key)
+            .unwrap_or(Vec::new(env));
+        depositor_index.push_back(bounty_id);
+        env.storage()
+            .persistent()
+            .set(&depositor_key, &depositor_index);
+    }
+
+    /// Lock already-validated batch items: auth each depositor once, write
+    /// every escrow, then transfer (CEI). Returns the number locked.
+    fn apply_batch_lock(env: &Env, items: &Vec<LockFundsItem>) -> u32 {
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(env, &token_addr);
+        let contract_address = env.current_contract_address();
 
         // Collect unique depositors and require auth once for each
         // This prevents "frame is already authorized" errors when same depositor appears multiple times
-        let mut seen_depositors: Vec<Address> = Vec::new(&env);
+        let mut seen_depositors: Vec<Address> = Vec::new(env);
         for item in items.iter() {
             let mut found = false;
             for seen in seen_depositors.iter() {
@@ -3071,171 +5506,143 @@ impl BountyEscrowContract {
             }
         }
 
-        // EFFECTS: write all escrow records before any external calls (CEI)
+        // EFFECTS: write all escrow records before any external calls (CEI).
+        // Each deposit is escrowed net of its lock fee.
+        let mut quotes: Vec<fees::FeeQuote> = Vec::new(env);
         let mut locked_count = 0u32;
         for item in items.iter() {
+            let quote = fees::quote(env, &item.depositor, item.amount, FeeOperationType::Lock);
             let escrow = Escrow {
                 depositor: item.depositor.clone(),
-                amount: item.amount,
+                amount: quote.net,
                 status: EscrowStatus::Locked,
                 deadline: item.deadline,
-                refund_history: vec![&env],
-                remaining_amount: item.amount,
+                refund_history: vec![env],
+                remaining_amount: quote.net,
             };
 
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(item.bounty_id), &escrow);
+            migration::store_escrow(env, item.bounty_id, &escrow);
+            invariants::adjust_liabilities(env, 0, quote.net);
+
+            // Keep the same indexes as `lock_funds` so batch-locked escrows
+            // are visible to queries and reconciliation.
+            Self::index_escrow(env, item.bounty_id, &item.depositor);
+            audit_log::record(
+                env,
+                item.bounty_id,
+                AuditAction::Lock,
+                &item.depositor,
+                quote.net,
+                None,
+            );
 
+            quotes.push_back(quote);
             locked_count += 1;
         }
 
         // INTERACTION: all external token transfers happen after state is finalized
-        for item in items.iter() {
-            client.transfer(&item.depositor, &contract_address, &item.amount);
+        for (idx, item) in items.iter().enumerate() {
+            let quote = quotes.get(idx as u32).unwrap();
+            client.transfer(&item.depositor, &contract_address, &quote.net);
+            fees::collect(
+                env,
+                &client,
+                &item.depositor,
+                item.bounty_id,
+                &quote,
+                FeeOperationType::Lock,
+            );
 
             emit_funds_locked(
-                &env,
+                env,
                 FundsLocked {
                     version: EVENT_VERSION_V2,
                     bounty_id: item.bounty_id,
-                    amount: item.amount,
+                    amount: quote.net,
                     depositor: item.depositor.clone(),
                     deadline: item.deadline,
                 },
             );
+            events::emit_bounty_event_v3(
+                env,
+                BountyEventKind::Locked,
+                item.bounty_id,
+                Some(&item.depositor),
+                None,
+                quote.net,
+            );
         }
 
-        // Emit batch event
-        emit_batch_funds_locked(
-            &env,
-            BatchFundsLocked {
-                count: locked_count,
-                total_amount: items.iter().map(|i| i.amount).sum(),
-                timestamp,
-            },
-        );
-
-        // GUARD: release reentrancy lock
-        reentrancy_guard::release(&env);
-        Ok(locked_count)
+        locked_count
     }
 
-    /// Batch release funds to multiple contributors in a single transaction.
-    /// This improves gas efficiency by reducing transaction overhead.
-    ///
-    /// # Arguments
-    /// * `items` - Vector of ReleaseFundsItem containing bounty_id and contributor address
-    ///
-    /// # Returns
-    /// Number of successfully released bounties
-    ///
-    /// # Errors
-    /// * InvalidBatchSize - if batch size exceeds MAX_BATCH_SIZE or is zero
-    /// * BountyNotFound - if any bounty_id doesn't exist
-    /// * FundsNotLocked - if any bounty is not in Locked status
-    /// * Unauthorized - if caller is not admin
-    ///
-    /// # Note
-    /// This operation is atomic - if any item fails, the entire transaction reverts.
-    /// # Reentrancy
-    /// Protected by the shared reentrancy guard. All escrow records are
-    /// updated to `Released` first; token transfers happen in a second
-    /// pass (CEI).
-    pub fn batch_release_funds(env: Env, items: Vec<ReleaseFundsItem>) -> Result<u32, Error> {
-        if Self::check_paused(&env, symbol_short!("release")) {
-            return Err(Error::FundsPaused);
-        }
-
-        // GUARD: acquire reentrancy lock
-        reentrancy_guard::acquire(&env);
-
-        // Validate batch size
-        let batch_size = items.len();
-        if batch_size == 0 {
-            return Err(Error::InvalidBatchSize);
-        }
-        if batch_size > MAX_BATCH_SIZE {
-            return Err(Error::InvalidBatchSize);
-        }
-
-        if !env.storage().instance().has(&DataKey::Admin) {
-            return Err(Error::NotInitialized);
-        }
-
-        let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
-
+    /// Release already-validated batch items: mark every escrow `Released`,
+    /// then transfer (CEI). Returns the number released.
+    fn apply_batch_release(
+        env: &Env,
+        admin: &Address,
+        items: &Vec<ReleaseFundsItem>,
+    ) -> Result<u32, Error> {
         let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
-        let client = token::Client::new(&env, &token_addr);
-        let contract_address = env.current_contract_address();
+        let client = token::Client::new(env, &token_addr);
         let timestamp = env.ledger().timestamp();
 
-        // Validate all items before processing (all-or-nothing approach)
-        let mut total_amount: i128 = 0;
-        for item in items.iter() {
-            if !env
-                .storage()
-                .persistent()
-                .has(&DataKey::Escrow(item.bounty_id))
-            {
-                return Err(Error::BountyNotFound);
-            }
-
-            let escrow: Escrow = env
-                .storage()
-                .persistent()
-                .get(&DataKey::Escrow(item.bounty_id))
-                .unwrap();
-
-            if escrow.status != EscrowStatus::Locked {
-                return Err(Error::FundsNotLocked);
-            }
-
-            let mut count = 0u32;
-            for other_item in items.iter() {
-                if other_item.bounty_id == item.bounty_id {
-                    count += 1;
-                }
-            }
-            if count > 1 {
-                return Err(Error::DuplicateBountyId);
-            }
-
-            total_amount = total_amount
-                .checked_add(escrow.amount)
-                .ok_or(Error::InvalidAmount)?;
-        }
-
         // EFFECTS: update all escrow records before any external calls (CEI)
         // We collect (contributor, amount) pairs for the transfer pass.
-        let mut release_pairs: Vec<(Address, i128)> = Vec::new(&env);
+        let mut release_pairs: Vec<(Address, i128)> = Vec::new(env);
+        let mut depositors: Vec<Address> = Vec::new(env);
         let mut released_count = 0u32;
         for item in items.iter() {
-            let mut escrow: Escrow = env
-                .storage()
-                .persistent()
-                .get(&DataKey::Escrow(item.bounty_id))
-                .unwrap();
+            let mut escrow: Escrow = migration::load_escrow(env, item.bounty_id).unwrap();
 
+            let owed_before = invariants::outstanding_amount(&escrow);
             let amount = escrow.amount;
+            invariants::transition(
+                &escrow.status,
+                &EscrowStatus::Released,
+                StatusAction::Release,
+            )?;
             escrow.status = EscrowStatus::Released;
             escrow.remaining_amount = 0;
-            env.storage()
-                .persistent()
-                .set(&DataKey::Escrow(item.bounty_id), &escrow);
+            invariants::adjust_liabilities(env, owed_before, 0);
+            migration::store_escrow(env, item.bounty_id, &escrow);
+            audit_log::record(
+                env,
+                item.bounty_id,
+                AuditAction::Release,
+                admin,
+                amount,
+                Some(item.contributor.clone()),
+            );
+            contributors::record_payout(
+                env,
+                &item.contributor,
+                &escrow.depositor,
+                item.bounty_id,
+                amount,
+                Payout::Full,
+            );
 
             release_pairs.push_back((item.contributor.clone(), amount));
+            depositors.push_back(escrow.depositor.clone());
             released_count += 1;
         }
 
         // INTERACTION: all external token transfers happen after state is finalized
         for (idx, item) in items.iter().enumerate() {
             let (ref contributor, amount) = release_pairs.get(idx as u32).unwrap();
-            client.transfer(&contract_address, contributor, &amount);
+            let depositor = depositors.get(idx as u32).unwrap();
+            fees::pay_out(
+                env,
+                &client,
+                item.bounty_id,
+                &depositor,
+                contributor,
+                amount,
+            );
 
             emit_funds_released(
-                &env,
+                env,
                 FundsReleased {
                     version: EVENT_VERSION_V2,
                     bounty_id: item.bounty_id,
@@ -3244,21 +5651,92 @@ impl BountyEscrowContract {
                     timestamp,
                 },
             );
+            events::emit_bounty_event_v3(
+                env,
+                BountyEventKind::Released,
+                item.bounty_id,
+                Some(admin),
+                Some(contributor.clone()),
+                amount,
+            );
         }
 
-        // Emit batch event
-        emit_batch_funds_released(
-            &env,
-            BatchFundsReleased {
-                count: released_count,
+        // Hooks see every transfer of the batch completed
+        for (idx, item) in items.iter().enumerate() {
+            let (ref contributor, amount) = release_pairs.get(idx as u32).unwrap();
+            hooks::notify(
+                env,
+                hooks::release_kind(),
+                item.bounty_id,
+                contributor,
+                amount,
+            );
+        }
+
+        Ok(released_count)
+    }
+
+    /// Pay out `payouts` from the contract with one transfer per distinct
+    /// recipient.
+    fn transfer_aggregated(env: &Env, payouts: &Vec<(Address, i128)>) {
+        let mut totals: Map<Address, i128> = Map::new(env);
+        for (recipient, amount) in payouts.iter() {
+            let total = totals.get(recipient.clone()).unwrap_or(0);
+            totals.set(recipient, total + amount);
+        }
+
+        let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+        let client = token::Client::new(env, &token_addr);
+        let contract_address = env.current_contract_address();
+        for (recipient, amount) in totals.iter() {
+            client.transfer(&contract_address, &recipient, &amount);
+        }
+    }
+
+    /// Publish the per-item and summary events of a best-effort batch and
+    /// build its results.
+    fn report_batch_outcomes(
+        env: &Env,
+        operation: Symbol,
+        outcomes: &Vec<(u64, Option<Error>)>,
+        total_amount: i128,
+    ) -> Vec<BatchItemResult> {
+        let timestamp = env.ledger().timestamp();
+        let mut results = Vec::new(env);
+        let mut succeeded = 0u32;
+        for (bounty_id, error) in outcomes.iter() {
+            let error_code = error.map_or(0, |err| err as u32);
+            if error.is_none() {
+                succeeded += 1;
+            }
+            events::emit_batch_item_processed(
+                env,
+                events::BatchItemProcessed {
+                    operation: operation.clone(),
+                    bounty_id,
+                    ok: error.is_none(),
+                    error_code,
+                    timestamp,
+                },
+            );
+            results.push_back(BatchItemResult {
+                bounty_id,
+                ok: error.is_none(),
+                error_code,
+            });
+        }
+        events::emit_batch_summary(
+            env,
+            events::BatchSummary {
+                operation,
+                attempted: outcomes.len(),
+                succeeded,
+                failed: outcomes.len() - succeeded,
                 total_amount,
                 timestamp,
             },
         );
-
-        // GUARD: release reentrancy lock
-        reentrancy_guard::release(&env);
-        Ok(released_count)
+        results
     }
     pub fn update_metadata(
         env: Env,
@@ -3278,14 +5756,51 @@ impl BountyEscrowContract {
         let metadata = EscrowMetadata {
             repo_id,
             issue_id,
-            bounty_type,
+            bounty_type: bounty_type.clone(),
         };
         env.storage()
             .persistent()
             .set(&DataKey::Metadata(bounty_id), &metadata);
+        events::emit_metadata_updated(
+            &env,
+            events::MetadataUpdated {
+                version: EVENT_VERSION_V3,
+                bounty_id,
+                repo_id,
+                issue_id,
+                bounty_type,
+                updated_by: stored_admin.clone(),
+                timestamp: env.ledger().timestamp(),
+            },
+        );
+        audit_log::record(
+            &env,
+            bounty_id,
+            AuditAction::MetadataUpdated,
+            &stored_admin,
+            0,
+            None,
+        );
+
+        monitoring::track_operation(&env, symbol_short!("metadata"), stored_admin.clone(), true);
+
         Ok(())
     }
 
+    /// View: page through the audit log of `bounty_id`, oldest first.
+    ///
+    /// Pass `cursor = 0` to start from the oldest retained entry and feed
+    /// back `next_cursor` until it is `None`. `limit` is capped at
+    /// `MAX_AUDIT_QUERY_LIMIT`.
+    pub fn get_audit_log(env: Env, bounty_id: u64, cursor: u64, limit: u32) -> AuditLogPage {
+        audit_log::query(&env, bounty_id, cursor, limit)
+    }
+
+    /// View: retained sequence range of the audit log of `bounty_id`.
+    pub fn get_audit_log_bounds(env: Env, bounty_id: u64) -> AuditCursor {
+        audit_log::bounds(&env, bounty_id)
+    }
+
     pub fn get_metadata(env: Env, bounty_id: u64) -> Result<EscrowMetadata, Error> {
         env.storage()
             .persistent()
@@ -3294,50 +5809,33 @@ impl BountyEscrowContract {
     }
 }
 
-impl traits::EscrowInterface for BountyEscrowContract {
-    /// Lock funds for a bounty through the trait interface
-    fn lock_funds(
-        env: &Env,
-        depositor: Address,
-        bounty_id: u64,
-        amount: i128,
-        deadline: u64,
-    ) -> Result<(), crate::Error> {
-        BountyEscrowContract::lock_funds(env.clone(), depositor, bounty_id, amount, deadline)
-    }
-
-    /// Release funds to contributor through the trait interface
-    fn release_funds(env: &Env, bounty_id: u64, contributor: Address) -> Result<(), crate::Error> {
-        BountyEscrowContract::release_funds(env.clone(), bounty_id, contributor)
+#[contractimpl]
+impl EscrowInterface for BountyEscrowContract {
+    /// True for the shared escrow interface and the bounty escrow interface.
+    fn supports_interface(_env: Env, interface_id: Symbol) -> bool {
+        escrow_interface::supports(&interface_id, &escrow_interface::BOUNTY_ESCROW_INTERFACE_ID)
     }
 
-    /// Refund funds to depositor through the trait interface
-    fn refund(env: &Env, bounty_id: u64) -> Result<(), crate::Error> {
-        BountyEscrowContract::refund(env.clone(), bounty_id)
+    fn interface_version(_env: Env) -> u32 {
+        escrow_interface::ESCROW_INTERFACE_VERSION
     }
 
-    /// Get escrow information through the trait interface
-    fn get_escrow_info(env: &Env, bounty_id: u64) -> Result<crate::Escrow, crate::Error> {
-        BountyEscrowContract::get_escrow_info(env.clone(), bounty_id)
+    /// Contracts initialized before versions were stored report version 1.
+    fn get_version(env: Env) -> u32 {
+        migration::stored_version(&env)
     }
 
-    /// Get contract balance through the trait interface
-    fn get_balance(env: &Env) -> Result<i128, crate::Error> {
-        BountyEscrowContract::get_balance(env.clone())
+    fn escrow_token(env: Env) -> Option<Address> {
+        env.storage().instance().get(&DataKey::Token)
     }
-}
 
-impl traits::UpgradeInterface for BountyEscrowContract {
-    /// Get contract version
-    fn get_version(_env: &Env) -> u32 {
-        1 // Current version
+    fn escrow_balance(env: Env) -> i128 {
+        invariants::token_balance(&env)
     }
 
-    /// Set contract version (admin only)
-    fn set_version(_env: &Env, _new_version: u32) -> Result<(), soroban_sdk::String> {
-        // Version management - reserved for future use
-        // Currently, version is hardcoded to 1
-        Ok(())
+    /// Sum of `remaining_amount` over escrows that still hold funds.
+    fn escrow_liabilities(env: Env) -> i128 {
+        invariants::total_liabilities(&env)
     }
 }
 
@@ -3349,38 +5847,71 @@ mod test;
 #[cfg(test)]
 mod test_analytics_monitoring;
 #[cfg(test)]
+mod test_attestation_gate;
+#[cfg(test)]
+mod test_audit_log;
+#[cfg(test)]
 mod test_auto_refund_permissions;
 #[cfg(test)]
-// Temporarily disabled: this suite targets a different blacklist API surface
-// (`initialize`, `set_blacklist`, `set_whitelist_mode`) than this contract exposes.
-// Re-enable after API/test alignment.
-// mod test_blacklist_and_whitelist;
+mod test_batch_best_effort;
+#[cfg(test)]
+mod test_batch_payouts;
+#[cfg(test)]
+mod test_blacklist_and_whitelist;
 #[cfg(test)]
 mod test_bounty_escrow;
 #[cfg(test)]
 mod test_capability_tokens;
 #[cfg(test)]
+mod test_claim_expiry;
+#[cfg(test)]
+mod test_contributor_stats;
+#[cfg(test)]
 mod test_dispute_resolution;
 #[cfg(test)]
 mod test_dry_run_simulation;
 #[cfg(test)]
+mod test_escrow_freeze;
+#[cfg(test)]
+mod test_event_schema_v3;
+#[cfg(test)]
 mod test_expiration_and_dispute;
 #[cfg(test)]
+mod test_fee_schedule;
+#[cfg(test)]
+mod test_fee_split;
+#[cfg(test)]
 mod test_front_running_ordering;
 #[cfg(test)]
 mod test_granular_pause;
 #[cfg(test)]
 mod test_invariants;
+#[cfg(test)]
+mod test_legacy_import;
 mod test_lifecycle;
 #[cfg(test)]
 mod test_metadata_tagging;
 #[cfg(test)]
+mod test_partial_claims;
+#[cfg(test)]
 mod test_partial_payout_rounding;
 #[cfg(test)]
 mod test_pause;
 #[cfg(test)]
+mod test_payout_hooks;
+#[cfg(test)]
+mod test_recipient_deny_list;
+#[cfg(test)]
 mod test_reentrancy_guard;
 #[cfg(test)]
+mod test_setup;
+#[cfg(test)]
+mod test_solvency;
+#[cfg(test)]
+mod test_token_units;
+#[cfg(test)]
+mod test_upgrade_scenarios;
+#[cfg(test)]
 mod escrow_status_transition_tests {
     use super::*;
     use soroban_sdk::{
@@ -3475,10 +6006,12 @@ mod escrow_status_transition_tests {
 
             // Write escrow directly to contract storage
             self.env.as_contract(&self.contract_id, || {
-                self.env
-                    .storage()
-                    .persistent()
-                    .set(&DataKey::Escrow(bounty_id), &escrow);
+                migration::store_escrow(&self.env, bounty_id, &escrow);
+                invariants::adjust_liabilities(
+                    &self.env,
+                    0,
+                    invariants::outstanding_amount(&escrow),
+                );
             });
         }
     }
@@ -0,0 +1,149 @@
+//! # Upgrades and State Migration
+//!
+//! `upgrade(wasm_hash)` swaps the contract code in place, so escrowed funds
+//! never move. Two kinds of state follow the code:
+//!
+//! - **Contract-wide state** is brought up to date by `migrate()`, which the
+//!   admin calls after an upgrade. It recomputes `TotalLiabilities` from the
+//!   escrow index (deployments older than liability tracking never stored
+//!   it) a page at a time, and records the new `CONTRACT_VERSION` once the
+//!   whole index is counted. A recompute longer than one page must run with
+//!   the contract paused.
+//! - **Escrow records** are migrated lazily. Every read goes through
+//!   [`load_escrow`], which accepts any layout this contract has ever
+//!   written and returns the current `Escrow`; every write goes through
+//!   [`store_escrow`], which writes the latest layout. A record is therefore
+//!   converted the next time it changes, and untouched records stay readable.
+//!
+//! ## Escrow layouts
+//!
+//! | Layout | Stored as |
+//! |--------|-----------|
+//! | 1      | bare `Escrow` map (contract version 1) |
+//! | 2      | `VersionedEscrow::V2(Escrow)` |
+//!
+//! To ship a layout that adds fields (token, milestones, fee snapshot...),
+//! freeze the current struct as `EscrowV2`, add `V3(Escrow)` to
+//! `VersionedEscrow`, and fill the new fields for older variants in
+//! [`decode_escrow`].
+
+use crate::{DataKey, Escrow};
+use soroban_sdk::{contracttype, Address, BytesN, Env, TryFromVal, Val, Vec};
+
+/// Version of this contract code, reported by `get_version`.
+pub const CONTRACT_VERSION: u32 = 2;
+/// Escrows counted by a single `migrate` call.
+pub const MIGRATION_PAGE_SIZE: u32 = 50;
+
+/// Escrow record envelope; the variant names the layout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum VersionedEscrow {
+    V2(Escrow),
+}
+
+/// One entry of the upgrade history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpgradeRecord {
+    pub wasm_hash: BytesN<32>,
+    /// `get_version` when the upgrade was performed.
+    pub from_version: u32,
+    pub upgraded_by: Address,
+    pub upgraded_at: u64,
+}
+
+/// Outcome of the last `migrate` call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MigrationState {
+    pub from_version: u32,
+    pub to_version: u32,
+    /// Outstanding amount counted so far; the recomputed `TotalLiabilities`
+    /// once `next_cursor` is `None`.
+    pub total_liabilities: i128,
+    /// Escrow index position the next `migrate` call continues from, or
+    /// `None` when the migration is complete.
+    pub next_cursor: Option<u32>,
+    pub migrated_at: u64,
+}
+
+/// Decode an escrow record written under any known layout.
+pub fn decode_escrow(env: &Env, stored: &Val) -> Escrow {
+    if let Ok(versioned) = VersionedEscrow::try_from_val(env, stored) {
+        return match versioned {
+            VersionedEscrow::V2(escrow) => escrow,
+        };
+    }
+    // Layout 1: the bare struct.
+    Escrow::try_from_val(env, stored).expect("unknown escrow layout")
+}
+
+pub fn load_escrow(env: &Env, bounty_id: u64) -> Option<Escrow> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, Val>(&DataKey::Escrow(bounty_id))
+        .map(|stored| decode_escrow(env, &stored))
+}
+
+/// Write `escrow` in the latest layout.
+pub fn store_escrow(env: &Env, bounty_id: u64, escrow: &Escrow) {
+    env.storage().persistent().set(
+        &DataKey::Escrow(bounty_id),
+        &VersionedEscrow::V2(escrow.clone()),
+    );
+}
+
+/// Version of the stored state; deployments older than version tracking
+/// report 1.
+pub fn stored_version(env: &Env) -> u32 {
+    env.storage().instance().get(&DataKey::Version).unwrap_or(1)
+}
+
+pub fn set_stored_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::Version, &version);
+}
+
+pub fn get_upgrade_approvals(env: &Env, wasm_hash: &BytesN<32>) -> Vec<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UpgradeApproval(wasm_hash.clone()))
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn set_upgrade_approvals(env: &Env, wasm_hash: &BytesN<32>, approvals: &Vec<Address>) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::UpgradeApproval(wasm_hash.clone()), approvals);
+}
+
+pub fn clear_upgrade_approvals(env: &Env, wasm_hash: &BytesN<32>) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::UpgradeApproval(wasm_hash.clone()));
+}
+
+pub fn get_upgrade_history(env: &Env) -> Vec<UpgradeRecord> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::UpgradeHistory)
+        .unwrap_or(Vec::new(env))
+}
+
+pub fn record_upgrade(env: &Env, record: UpgradeRecord) {
+    let mut history = get_upgrade_history(env);
+    history.push_back(record);
+    env.storage()
+        .persistent()
+        .set(&DataKey::UpgradeHistory, &history);
+}
+
+pub fn get_migration_state(env: &Env) -> Option<MigrationState> {
+    env.storage().instance().get(&DataKey::MigrationState)
+}
+
+pub fn set_migration_state(env: &Env, state: &MigrationState) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MigrationState, state);
+}
@@ -0,0 +1,347 @@
+//! # Dry-Run Simulation
+//!
+//! Backs the `simulate_*` views. Each one runs the entrypoint's own checks
+//! from [`crate::validation`] in [`Mode::Simulate`], then projects the escrow
+//! state the call would leave behind, including the post-transfer solvency
+//! check. Nothing is written and no auth is required.
+//!
+//! Batch simulations return one result per item. A batch is all-or-nothing,
+//! so the real call succeeds only if every item does; errors that apply to
+//! the whole batch (pause, size, initialisation) are reported on every item.
+
+use crate::deadlines::{self, DeadlineMode};
+use crate::events::FeeOperationType;
+use crate::fees;
+use crate::invariants;
+use crate::units;
+use crate::validation::{self, Mode, RefundPlan};
+use crate::{
+    DataKey, Error, Escrow, EscrowStatus, LockFundsItem, ReleaseFundsItem, SimulationResult,
+    StatusAction,
+};
+use soroban_sdk::{symbol_short, token, Address, Env, Vec};
+
+/// Build a result, adding the whole-token readings of both amounts.
+fn result(
+    env: &Env,
+    error_code: u32,
+    amount: i128,
+    resulting_status: EscrowStatus,
+    remaining_amount: i128,
+) -> SimulationResult {
+    let decimals = units::escrow_decimals(env);
+    SimulationResult {
+        success: error_code == 0,
+        error_code,
+        amount,
+        resulting_status,
+        remaining_amount,
+        amount_display: units::display(amount, decimals),
+        remaining_display: units::display(remaining_amount, decimals),
+    }
+}
+
+fn failure(env: &Env, error: Error, escrow: Option<&Escrow>) -> SimulationResult {
+    let (resulting_status, remaining_amount) = match escrow {
+        Some(escrow) => (escrow.status.clone(), escrow.remaining_amount),
+        None => (EscrowStatus::Locked, 0),
+    };
+    result(env, error as u32, 0, resulting_status, remaining_amount)
+}
+
+/// Turn a projected outcome into a result, describing the unchanged escrow
+/// when the call would fail.
+fn report(env: &Env, bounty_id: u64, outcome: Result<SimulationResult, Error>) -> SimulationResult {
+    outcome.unwrap_or_else(|error| {
+        failure(
+            env,
+            error,
+            validation::load_escrow(env, bounty_id).ok().as_ref(),
+        )
+    })
+}
+
+/// Project paying `payout` out of `before`, leaving `after`.
+fn settle(
+    env: &Env,
+    before: &Escrow,
+    after: &Escrow,
+    payout: i128,
+    action: StatusAction,
+) -> Result<SimulationResult, Error> {
+    invariants::transition(&before.status, &after.status, action)?;
+    invariants::ensure_solvent_after(
+        env,
+        payout,
+        invariants::outstanding_amount(before),
+        invariants::outstanding_amount(after),
+    )?;
+    Ok(result(
+        env,
+        0,
+        payout,
+        after.status.clone(),
+        after.remaining_amount,
+    ))
+}
+
+/// `escrow` after a release of `amount` that keeps it open until empty.
+fn partially_released(escrow: &Escrow, amount: i128) -> Escrow {
+    let mut after = escrow.clone();
+    after.remaining_amount -= amount;
+    if after.remaining_amount == 0 {
+        after.status = EscrowStatus::Released;
+    }
+    after
+}
+
+/// `escrow` after being paid out in full.
+fn released(escrow: &Escrow) -> Escrow {
+    let mut after = escrow.clone();
+    after.remaining_amount = 0;
+    after.status = EscrowStatus::Released;
+    after
+}
+
+fn refunded(escrow: &Escrow, amount: i128, is_full: bool) -> Escrow {
+    let mut after = escrow.clone();
+    after.remaining_amount -= amount;
+    after.status = if is_full || after.remaining_amount == 0 {
+        EscrowStatus::Refunded
+    } else {
+        EscrowStatus::PartiallyRefunded
+    };
+    after
+}
+
+fn token_client(env: &Env) -> token::Client<'_> {
+    let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+    token::Client::new(env, &token_addr)
+}
+
+pub fn lock(
+    env: &Env,
+    depositor: &Address,
+    bounty_id: u64,
+    amount: i128,
+    deadline_mode: &DeadlineMode,
+) -> SimulationResult {
+    let outcome =
+        validation::lock(env, depositor, bounty_id, amount, Mode::Simulate).and_then(|_| {
+            // `lock_funds` relies on the escrow invariants and the token
+            // transfer to reject these; report them as errors instead.
+            if amount <= 0 {
+                return Err(Error::InvalidAmount);
+            }
+            if let DeadlineMode::Absolute(deadline) = deadline_mode {
+                if *deadline <= env.ledger().timestamp() {
+                    return Err(Error::InvalidDeadline);
+                }
+            }
+            deadlines::validate(deadline_mode)?;
+            if token_client(env).balance(depositor) < amount {
+                return Err(Error::InsufficientFunds);
+            }
+            let escrowed = fees::quote(env, depositor, amount, FeeOperationType::Lock).net;
+            Ok(result(env, 0, amount, EscrowStatus::Locked, escrowed))
+        });
+    outcome.unwrap_or_else(|error| failure(env, error, None))
+}
+
+pub fn release(env: &Env, bounty_id: u64, contributor: &Address) -> SimulationResult {
+    let outcome =
+        validation::release(env, bounty_id, contributor, Mode::Simulate).and_then(|(_, escrow)| {
+            settle(
+                env,
+                &escrow,
+                &released(&escrow),
+                escrow.amount,
+                StatusAction::Release,
+            )
+        });
+    report(env, bounty_id, outcome)
+}
+
+pub fn partial_release(
+    env: &Env,
+    bounty_id: u64,
+    contributor: &Address,
+    payout_amount: i128,
+) -> SimulationResult {
+    let outcome =
+        validation::partial_release(env, bounty_id, contributor, payout_amount, Mode::Simulate)
+            .and_then(|(_, escrow)| {
+                settle(
+                    env,
+                    &escrow,
+                    &partially_released(&escrow, payout_amount),
+                    payout_amount,
+                    StatusAction::PartialRelease,
+                )
+            });
+    report(env, bounty_id, outcome)
+}
+
+pub fn refund(env: &Env, bounty_id: u64) -> SimulationResult {
+    let outcome = validation::refund(env, bounty_id).and_then(|plan: RefundPlan| {
+        settle(
+            env,
+            &plan.escrow,
+            &refunded(&plan.escrow, plan.amount, plan.is_full),
+            plan.amount,
+            StatusAction::Refund,
+        )
+    });
+    report(env, bounty_id, outcome)
+}
+
+pub fn claim(env: &Env, bounty_id: u64) -> SimulationResult {
+    let outcome = validation::claim(env, bounty_id, Mode::Simulate).and_then(|(claim, escrow)| {
+        settle(
+            env,
+            &escrow,
+            &partially_released(&escrow, claim.amount),
+            claim.amount,
+            StatusAction::Claim,
+        )
+    });
+    report(env, bounty_id, outcome)
+}
+
+pub fn release_with_capability(
+    env: &Env,
+    bounty_id: u64,
+    contributor: &Address,
+    payout_amount: i128,
+    holder: &Address,
+    capability_id: u64,
+) -> SimulationResult {
+    let outcome = validation::release_with_capability(
+        env,
+        bounty_id,
+        contributor,
+        payout_amount,
+        holder,
+        capability_id,
+        Mode::Simulate,
+    )
+    .and_then(|(escrow, _)| {
+        settle(
+            env,
+            &escrow,
+            &partially_released(&escrow, payout_amount),
+            payout_amount,
+            StatusAction::PartialRelease,
+        )
+    });
+    report(env, bounty_id, outcome)
+}
+
+pub fn claim_with_capability(
+    env: &Env,
+    bounty_id: u64,
+    holder: &Address,
+    capability_id: u64,
+) -> SimulationResult {
+    let outcome =
+        validation::claim_with_capability(env, bounty_id, holder, capability_id, Mode::Simulate)
+            .and_then(|(claim, escrow, _)| {
+                settle(
+                    env,
+                    &escrow,
+                    &partially_released(&escrow, claim.amount),
+                    claim.amount,
+                    StatusAction::Claim,
+                )
+            });
+    report(env, bounty_id, outcome)
+}
+
+pub fn refund_with_capability(
+    env: &Env,
+    bounty_id: u64,
+    amount: i128,
+    holder: &Address,
+    capability_id: u64,
+) -> SimulationResult {
+    let outcome = validation::refund_with_capability(
+        env,
+        bounty_id,
+        amount,
+        holder,
+        capability_id,
+        Mode::Simulate,
+    )
+    .and_then(|(escrow, _)| {
+        settle(
+            env,
+            &escrow,
+            &refunded(&escrow, amount, false),
+            amount,
+            StatusAction::Refund,
+        )
+    });
+    report(env, bounty_id, outcome)
+}
+
+/// Per-item results for `batch_lock_funds`. Each depositor must hold enough
+/// to cover all of their items in the batch.
+pub fn batch_lock(env: &Env, items: &Vec<LockFundsItem>) -> Vec<SimulationResult> {
+    let batch = validation::batch(env, symbol_short!("lock"), items.len());
+    let mut results = Vec::new(env);
+    for item in items.iter() {
+        let outcome = batch
+            .and_then(|_| {
+                let duplicate =
+                    validation::is_repeated(items.iter().map(|i| i.bounty_id), item.bounty_id);
+                validation::batch_lock_item(env, &item, duplicate)
+            })
+            .and_then(|_| {
+                let requested: i128 = items
+                    .iter()
+                    .filter(|other| other.depositor == item.depositor)
+                    .map(|other| other.amount.max(0))
+                    .sum();
+                if token_client(env).balance(&item.depositor) < requested {
+                    return Err(Error::InsufficientFunds);
+                }
+                Ok(())
+            });
+        results.push_back(match outcome {
+            Ok(()) => {
+                let escrowed =
+                    fees::quote(env, &item.depositor, item.amount, FeeOperationType::Lock).net;
+                result(env, 0, item.amount, EscrowStatus::Locked, escrowed)
+            }
+            Err(error) => failure(env, error, None),
+        });
+    }
+    results
+}
+
+/// Per-item results for `batch_release_funds`. Solvency is projected
+/// cumulatively, in item order.
+pub fn batch_release(env: &Env, items: &Vec<ReleaseFundsItem>) -> Vec<SimulationResult> {
+    let batch = validation::batch(env, symbol_short!("release"), items.len());
+    let mut results = Vec::new(env);
+    let mut paid: i128 = 0;
+    let mut owed_released: i128 = 0;
+    for item in items.iter() {
+        let outcome = batch
+            .and_then(|_| {
+                let duplicate =
+                    validation::is_repeated(items.iter().map(|i| i.bounty_id), item.bounty_id);
+                validation::batch_release_item(env, &item, duplicate)
+            })
+            .and_then(|escrow| {
+                let after = released(&escrow);
+                invariants::transition(&escrow.status, &after.status, StatusAction::Release)?;
+                paid += escrow.amount;
+                owed_released += invariants::outstanding_amount(&escrow);
+                invariants::ensure_solvent_after(env, paid, owed_released, 0)?;
+                Ok(result(env, 0, escrow.amount, after.status, 0))
+            });
+        results.push_back(report(env, item.bounty_id, outcome));
+    }
+    results
+}
@@ -546,7 +546,7 @@ fn test_claim_within_window_transfers_funds() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #6)")]
+#[should_panic(expected = "Error(Contract, #48)")]
 fn test_claim_after_window_expires_panics() {
     let setup = TestSetup::new();
     let bounty_id = 101_u64;
@@ -656,7 +656,7 @@ fn test_cancel_claim_then_use_release_funds_normally() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #5)")]
+#[should_panic(expected = "Error(Contract, #49)")]
 fn test_claim_twice_panics() {
     let setup = TestSetup::new();
     let bounty_id = 105_u64;
@@ -703,7 +703,7 @@ fn test_claim_does_not_affect_other_bounties() {
 }
 
 #[test]
-#[should_panic(expected = "Error(Contract, #6)")]
+#[should_panic(expected = "Error(Contract, #48)")]
 fn test_authorize_claim_zero_window_expires_immediately() {
     let setup = TestSetup::new();
     let bounty_id = 108_u64;
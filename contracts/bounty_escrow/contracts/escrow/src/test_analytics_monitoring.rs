@@ -18,10 +18,16 @@
 /// * `get_refund_history`    – history vector is populated by approved-refund path
 /// * Monitoring event emission – lock/release/refund each emit ≥ 1 event
 /// * Error flows             – failed attempts do not corrupt metrics
-use crate::{BountyEscrowContract, BountyEscrowContractClient, EscrowStatus, RefundMode};
+/// * `get_analytics` / `get_performance_stats` – operation and user counters
+/// * `health_check`          – verdict follows pause flags and solvency
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus, LockFundsItem,
+    RefundMode,
+};
 use soroban_sdk::{
+    symbol_short,
     testutils::{Address as _, Events, Ledger},
-    token, Address, Env,
+    token, vec, Address, Env,
 };
 
 // ---------------------------------------------------------------------------
@@ -1149,3 +1155,151 @@ fn test_get_balance_zero_after_all_escrows_settled() {
         "contract balance must be zero when all escrows are settled"
     );
 }
+
+// ===========================================================================
+// 16. Monitoring counters – operations, unique users, per-operation stats
+// ===========================================================================
+
+#[test]
+fn test_monitoring_counts_operations_and_unique_users() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor_a = Address::generate(&env);
+    let depositor_b = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor_a, &10_000);
+    token_admin.mint(&depositor_b, &10_000);
+
+    env.ledger().set_timestamp(50);
+    let deadline = 1_000;
+    escrow.lock_funds(&depositor_a, &300, &1_000, &deadline);
+    escrow.lock_funds(&depositor_b, &301, &1_000, &deadline);
+    env.ledger().set_timestamp(500);
+    escrow.lock_funds(&depositor_a, &302, &1_000, &deadline);
+    escrow.release_funds(&300, &contributor);
+
+    let analytics = escrow.get_analytics();
+    assert_eq!(analytics.operation_count, 5, "init + 3 locks + release");
+    assert_eq!(analytics.unique_users, 3, "admin and two depositors");
+    assert_eq!(analytics.error_count, 0);
+    assert_eq!(analytics.error_rate, 0);
+
+    let lock_stats = escrow.get_performance_stats(&symbol_short!("lock"));
+    assert_eq!(lock_stats.call_count, 3);
+    assert_eq!(lock_stats.last_called, 500);
+    assert_eq!(
+        escrow
+            .get_performance_stats(&symbol_short!("release"))
+            .call_count,
+        1
+    );
+
+    let snapshot = escrow.get_state_snapshot();
+    assert_eq!(snapshot.total_operations, 5);
+    assert_eq!(snapshot.total_users, 3);
+    assert_eq!(snapshot.timestamp, 500);
+}
+
+#[test]
+fn test_monitoring_counters_roll_back_with_failed_calls() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &10_000);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &310, &1_000, &deadline);
+    assert!(escrow
+        .try_lock_funds(&depositor, &310, &1_000, &deadline)
+        .is_err());
+
+    // A failed invocation is reverted in full, metrics included.
+    let analytics = escrow.get_analytics();
+    assert_eq!(analytics.operation_count, 2);
+    assert_eq!(analytics.error_count, 0);
+    assert_eq!(escrow.get_error_count(&(Error::BountyExists as u32)), 0);
+    assert!(escrow.health_check().is_healthy);
+
+    // The same error absorbed by a best-effort batch is counted.
+    escrow.batch_lock_funds_best_effort(&vec![
+        &env,
+        LockFundsItem {
+            bounty_id: 310,
+            depositor: depositor.clone(),
+            amount: 1_000,
+            deadline,
+        },
+    ]);
+    let analytics = escrow.get_analytics();
+    assert_eq!(analytics.error_count, 1);
+    assert_eq!(escrow.get_error_count(&(Error::BountyExists as u32)), 1);
+}
+
+// ===========================================================================
+// 17. Health check – pause flags, invariants and solvency
+// ===========================================================================
+
+#[test]
+fn test_health_check_reports_healthy_state() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &10_000);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &320, &1_500, &deadline);
+
+    let health = escrow.health_check();
+    assert!(health.is_healthy);
+    assert!(!health.is_paused);
+    assert!(health.is_solvent);
+    assert_eq!(health.token_balance, 1_500);
+    assert_eq!(health.total_liabilities, 1_500);
+    assert_eq!(health.total_operations, 2);
+}
+
+#[test]
+fn test_health_check_flags_pause_and_insolvency() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let target = Address::generate(&env);
+    let (token, token_admin) = create_token_contract(&env, &admin);
+    let escrow = create_escrow_contract(&env);
+    escrow.init(&admin, &token.address);
+    token_admin.mint(&depositor, &10_000);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    escrow.lock_funds(&depositor, &330, &2_000, &deadline);
+
+    escrow.set_paused(&Some(true), &None, &None, &None);
+    let paused = escrow.health_check();
+    assert!(!paused.is_healthy);
+    assert!(paused.is_paused);
+    assert!(paused.is_solvent);
+
+    // Draining the balance while escrows are still locked leaves the
+    // contract unable to cover its liabilities.
+    escrow.emergency_withdraw(&target);
+    escrow.set_paused(&Some(false), &None, &None, &None);
+
+    let drained = escrow.health_check();
+    assert!(!drained.is_paused);
+    assert!(!drained.is_solvent);
+    assert!(!drained.is_healthy);
+    assert_eq!(drained.token_balance, 0);
+    assert_eq!(drained.total_liabilities, 2_000);
+}
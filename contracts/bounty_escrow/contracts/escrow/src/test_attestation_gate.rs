@@ -0,0 +1,191 @@
+#![cfg(test)]
+
+use crate::test_setup::EscrowSetup;
+use crate::{Error, PartialReleaseItem, ReleaseFundsItem};
+use attestation_registry::{AttestationRegistry, AttestationRegistryClient};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Ledger, vec};
+
+const THRESHOLD: i128 = 10_000;
+const KYC_LEVEL: u32 = 2;
+
+struct GateSetup {
+    base: EscrowSetup,
+    registry: AttestationRegistryClient<'static>,
+}
+
+impl Deref for GateSetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl GateSetup {
+    fn new() -> Self {
+        let base = EscrowSetup::with_claim_window(1_000_000);
+        let registry_id = base.env.register_contract(None, AttestationRegistry);
+        let registry = AttestationRegistryClient::new(&base.env, &registry_id);
+        registry.init(&base.admin);
+        base.client
+            .set_attestation_config(&registry_id, &THRESHOLD, &KYC_LEVEL);
+
+        Self { base, registry }
+    }
+}
+
+#[test]
+fn test_small_payout_does_not_need_attestation() {
+    let setup = GateSetup::new();
+    setup.lock(1, THRESHOLD - 1);
+
+    setup.client.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), THRESHOLD - 1);
+}
+
+#[test]
+fn test_large_release_requires_attestation() {
+    let setup = GateSetup::new();
+    setup.lock(1, THRESHOLD);
+
+    assert_eq!(
+        setup.client.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::AttestationRequired))
+    );
+    let sim = setup.client.simulate_release(&1, &setup.contributor);
+    assert_eq!(sim.error_code, Error::AttestationRequired as u32);
+
+    setup.registry.attest(&setup.contributor, &KYC_LEVEL, &0);
+    assert!(
+        setup
+            .client
+            .simulate_release(&1, &setup.contributor)
+            .success
+    );
+    setup.client.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), THRESHOLD);
+}
+
+#[test]
+fn test_insufficient_level_or_expired_attestation_is_rejected() {
+    let setup = GateSetup::new();
+    setup.lock(1, THRESHOLD * 2);
+
+    setup
+        .registry
+        .attest(&setup.contributor, &(KYC_LEVEL - 1), &0);
+    assert_eq!(
+        setup
+            .client
+            .try_partial_release(&1, &setup.contributor, &THRESHOLD),
+        Err(Ok(Error::AttestationRequired))
+    );
+
+    setup.registry.attest(&setup.contributor, &KYC_LEVEL, &500);
+    setup
+        .client
+        .partial_release(&1, &setup.contributor, &THRESHOLD);
+
+    setup.env.ledger().set_timestamp(500);
+    assert_eq!(
+        setup
+            .client
+            .try_partial_release(&1, &setup.contributor, &THRESHOLD),
+        Err(Ok(Error::AttestationRequired))
+    );
+    // A smaller payout from the same bounty is gated all the same.
+    assert_eq!(
+        setup
+            .client
+            .try_partial_release(&1, &setup.contributor, &(THRESHOLD - 1)),
+        Err(Ok(Error::AttestationRequired))
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), THRESHOLD);
+}
+
+#[test]
+fn test_split_payouts_of_a_large_bounty_are_gated() {
+    let setup = GateSetup::new();
+    setup.lock(1, THRESHOLD * 2);
+    setup.lock(2, THRESHOLD * 2);
+    let part = THRESHOLD / 2;
+
+    assert_eq!(
+        setup
+            .client
+            .try_partial_release(&1, &setup.contributor, &part),
+        Err(Ok(Error::AttestationRequired))
+    );
+    assert_eq!(
+        setup.client.try_batch_partial_release(&vec![
+            &setup.env,
+            PartialReleaseItem {
+                bounty_id: 2,
+                contributor: setup.contributor.clone(),
+                amount: part,
+            },
+        ]),
+        Err(Ok(Error::AttestationRequired))
+    );
+    setup
+        .client
+        .authorize_partial_claim(&1, &setup.contributor, &part);
+    assert_eq!(
+        setup.client.try_claim(&1),
+        Err(Ok(Error::AttestationRequired))
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+
+    setup.registry.attest(&setup.contributor, &KYC_LEVEL, &0);
+    setup.client.claim(&1);
+    setup.client.partial_release(&2, &setup.contributor, &part);
+    assert_eq!(setup.token.balance(&setup.contributor), part * 2);
+}
+
+#[test]
+fn test_claim_and_batch_release_are_gated() {
+    let setup = GateSetup::new();
+    setup.lock(1, THRESHOLD);
+    setup.lock(2, THRESHOLD);
+
+    setup.client.authorize_claim(&1, &setup.contributor);
+    assert_eq!(
+        setup.client.try_claim(&1),
+        Err(Ok(Error::AttestationRequired))
+    );
+
+    assert_eq!(
+        setup.client.try_batch_release_funds(&vec![
+            &setup.env,
+            ReleaseFundsItem {
+                bounty_id: 2,
+                contributor: setup.contributor.clone(),
+            },
+        ]),
+        Err(Ok(Error::AttestationRequired))
+    );
+
+    setup.registry.attest(&setup.contributor, &KYC_LEVEL, &0);
+    setup.client.claim(&1);
+    assert_eq!(setup.token.balance(&setup.contributor), THRESHOLD);
+}
+
+#[test]
+fn test_clearing_config_disables_gate() {
+    let setup = GateSetup::new();
+    setup.lock(1, THRESHOLD);
+    assert!(setup.client.get_attestation_config().is_some());
+
+    assert_eq!(
+        setup
+            .client
+            .try_set_attestation_config(&setup.registry.address, &0, &KYC_LEVEL),
+        Err(Ok(Error::InvalidAttestationConfig))
+    );
+
+    setup.client.clear_attestation_config();
+    assert_eq!(setup.client.get_attestation_config(), None);
+    setup.client.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), THRESHOLD);
+}
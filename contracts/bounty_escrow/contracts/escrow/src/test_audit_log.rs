@@ -0,0 +1,171 @@
+#![cfg(test)]
+
+use crate::test_setup::EscrowSetup;
+use crate::{AuditAction, RefundMode};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Ledger, String};
+
+struct AuditSetup {
+    base: EscrowSetup,
+}
+
+impl Deref for AuditSetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl AuditSetup {
+    fn new() -> Self {
+        Self {
+            base: EscrowSetup::with_claim_window(1_000_000),
+        }
+    }
+}
+
+#[test]
+fn test_audit_log_records_actions_in_order() {
+    let setup = AuditSetup::new();
+    setup.env.ledger().set_sequence_number(7);
+    setup.lock(42, 1_000);
+
+    setup.env.ledger().set_timestamp(10);
+    setup.client.partial_release(&42, &setup.contributor, &300);
+    setup
+        .client
+        .freeze_escrow(&42, &String::from_str(&setup.env, "review"));
+    setup.client.unfreeze_escrow(&42);
+    setup
+        .client
+        .approve_refund(&42, &200, &setup.depositor, &RefundMode::Partial);
+    setup.client.refund(&42);
+
+    let page = setup.client.get_audit_log(&42, &0, &50);
+    assert_eq!(page.first_seq, 0);
+    assert_eq!(page.next_cursor, None);
+    assert_eq!(page.entries.len(), 6);
+
+    let lock = page.entries.get(0).unwrap();
+    assert_eq!(lock.action, AuditAction::Lock);
+    assert_eq!(lock.actor, Some(setup.depositor.clone()));
+    assert_eq!(lock.amount, 1_000);
+    assert_eq!(lock.counterparty, None);
+    assert_eq!(lock.ledger, 7);
+
+    let release = page.entries.get(1).unwrap();
+    assert_eq!(release.action, AuditAction::PartialRelease);
+    assert_eq!(release.actor, Some(setup.admin.clone()));
+    assert_eq!(release.amount, 300);
+    assert_eq!(release.counterparty, Some(setup.contributor.clone()));
+    assert_eq!(release.timestamp, 10);
+
+    assert_eq!(page.entries.get(2).unwrap().action, AuditAction::Frozen);
+    assert_eq!(page.entries.get(3).unwrap().action, AuditAction::Unfrozen);
+    assert_eq!(
+        page.entries.get(4).unwrap().action,
+        AuditAction::RefundApproved
+    );
+
+    // The approval is deleted after use but the refund entry keeps the approver.
+    let refund = page.entries.get(5).unwrap();
+    assert_eq!(refund.action, AuditAction::Refund);
+    assert_eq!(refund.actor, Some(setup.admin.clone()));
+    assert_eq!(refund.amount, 200);
+}
+
+#[test]
+fn test_permissionless_refund_names_no_actor() {
+    let setup = AuditSetup::new();
+    setup.lock(1, 1_000);
+    setup.advance(1_001);
+    setup.client.refund(&1);
+
+    let refund = setup
+        .client
+        .get_audit_log(&1, &0, &10)
+        .entries
+        .get(1)
+        .unwrap();
+    assert_eq!(refund.action, AuditAction::Refund);
+    assert_eq!(refund.actor, None);
+    assert_eq!(refund.amount, 1_000);
+    assert_eq!(refund.counterparty, Some(setup.depositor.clone()));
+}
+
+#[test]
+fn test_audit_log_is_scoped_per_bounty_and_tracks_claims() {
+    let setup = AuditSetup::new();
+    setup.lock(1, 500);
+    setup.lock(2, 700);
+
+    setup.client.authorize_claim(&2, &setup.contributor);
+    setup.client.cancel_pending_claim(&2);
+    setup.client.authorize_claim(&2, &setup.contributor);
+    setup.client.claim(&2);
+
+    assert_eq!(setup.client.get_audit_log(&1, &0, &10).entries.len(), 1);
+
+    let log = setup.client.get_audit_log(&2, &0, &10).entries;
+    assert_eq!(log.len(), 5);
+    assert_eq!(log.get(1).unwrap().action, AuditAction::ClaimAuthorized);
+    assert_eq!(log.get(2).unwrap().action, AuditAction::ClaimCancelled);
+    let claimed = log.get(4).unwrap();
+    assert_eq!(claimed.action, AuditAction::Claimed);
+    assert_eq!(claimed.actor, Some(setup.contributor.clone()));
+    assert_eq!(claimed.amount, 700);
+
+    assert_eq!(setup.client.get_audit_log(&99, &0, &10).entries.len(), 0);
+}
+
+#[test]
+fn test_audit_log_cursor_pagination() {
+    let setup = AuditSetup::new();
+    setup.lock(1, 10_000);
+    for _ in 0..39 {
+        setup.client.partial_release(&1, &setup.contributor, &1);
+    }
+
+    let first = setup.client.get_audit_log(&1, &0, &15);
+    assert_eq!(first.entries.len(), 15);
+    assert_eq!(first.next_cursor, Some(15));
+
+    let second = setup.client.get_audit_log(&1, &15, &15);
+    assert_eq!(second.first_seq, 15);
+    assert_eq!(second.entries.len(), 15);
+    assert_eq!(second.next_cursor, Some(30));
+
+    let last = setup.client.get_audit_log(&1, &30, &15);
+    assert_eq!(last.entries.len(), 10);
+    assert_eq!(last.next_cursor, None);
+
+    // Limits above the cap are clamped.
+    let capped = setup.client.get_audit_log(&1, &0, &1_000);
+    assert_eq!(capped.entries.len(), 40);
+    assert_eq!(capped.next_cursor, None);
+}
+
+#[test]
+fn test_audit_log_retention_is_bounded() {
+    let setup = AuditSetup::new();
+    setup.env.budget().reset_unlimited();
+    setup.lock(1, 10_000);
+    for _ in 0..144 {
+        setup.client.partial_release(&1, &setup.contributor, &1);
+    }
+
+    // 145 entries written; the oldest 16-entry pages are evicted so at most
+    // 128 remain.
+    let bounds = setup.client.get_audit_log_bounds(&1);
+    assert_eq!(bounds.next, 145);
+    assert_eq!(bounds.start, 32);
+
+    let page = setup.client.get_audit_log(&1, &0, &10);
+    assert_eq!(page.first_seq, 32);
+    assert_eq!(page.entries.len(), 10);
+    assert_eq!(
+        page.entries.get(0).unwrap().action,
+        AuditAction::PartialRelease
+    );
+}
@@ -0,0 +1,289 @@
+#![cfg(test)]
+
+use crate::events::{BatchItemProcessed, BatchSummary};
+use crate::test_setup::EscrowSetup;
+use crate::{BatchItemResult, Error, EscrowStatus, LockFundsItem, ReleaseFundsItem};
+use core::ops::Deref;
+use soroban_sdk::{
+    symbol_short, testutils::Address as _, testutils::Events as _, vec, Address, Env, IntoVal,
+    Symbol, TryFromVal, Val, Vec,
+};
+
+struct BestEffortSetup {
+    base: EscrowSetup,
+}
+
+impl Deref for BestEffortSetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl BestEffortSetup {
+    fn new() -> Self {
+        Self {
+            base: EscrowSetup::new(10_000),
+        }
+    }
+
+    fn lock_item(&self, bounty_id: u64, amount: i128) -> LockFundsItem {
+        LockFundsItem {
+            bounty_id,
+            depositor: self.depositor.clone(),
+            amount,
+            deadline: self.env.ledger().timestamp() + 1_000,
+        }
+    }
+
+    fn release_item(&self, bounty_id: u64) -> ReleaseFundsItem {
+        ReleaseFundsItem {
+            bounty_id,
+            contributor: self.contributor.clone(),
+        }
+    }
+
+    /// Data of every event published under `name`, oldest first.
+    fn events_named<T>(&self, name: Symbol) -> Vec<T>
+    where
+        T: TryFromVal<Env, Val> + IntoVal<Env, Val>,
+    {
+        let mut found = Vec::new(&self.env);
+        for (_contract, topics, data) in self.env.events().all().iter() {
+            let first = topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&self.env, &t).ok());
+            if first == Some(name.clone()) {
+                found.push_back(T::try_from_val(&self.env, &data).ok().unwrap());
+            }
+        }
+        found
+    }
+}
+
+fn ok(bounty_id: u64) -> BatchItemResult {
+    BatchItemResult {
+        bounty_id,
+        ok: true,
+        error_code: 0,
+    }
+}
+
+fn skipped(bounty_id: u64, error: Error) -> BatchItemResult {
+    BatchItemResult {
+        bounty_id,
+        ok: false,
+        error_code: error as u32,
+    }
+}
+
+#[test]
+fn test_best_effort_lock_skips_invalid_items() {
+    let setup = BestEffortSetup::new();
+    setup.lock(1, 100);
+
+    let poor = Address::generate(&setup.env);
+    setup.token_admin.mint(&poor, &500);
+    let poor_item = |bounty_id| LockFundsItem {
+        bounty_id,
+        depositor: poor.clone(),
+        amount: 300,
+        deadline: setup.env.ledger().timestamp() + 1_000,
+    };
+
+    let items = vec![
+        &setup.env,
+        setup.lock_item(10, 1_000),
+        setup.lock_item(1, 500),
+        setup.lock_item(10, 200),
+        setup.lock_item(11, 0),
+        poor_item(12),
+        poor_item(13),
+        setup.lock_item(14, 2_000),
+    ];
+    assert!(setup.client.try_batch_lock_funds(&items).is_err());
+
+    let results = setup.client.batch_lock_funds_best_effort(&items);
+    assert_eq!(
+        results,
+        vec![
+            &setup.env,
+            ok(10),
+            skipped(1, Error::BountyExists),
+            skipped(10, Error::DuplicateBountyId),
+            skipped(11, Error::InvalidAmount),
+            ok(12),
+            skipped(13, Error::InsufficientFunds),
+            ok(14),
+        ]
+    );
+
+    assert_eq!(setup.client.get_escrow_info(&10).amount, 1_000);
+    assert_eq!(setup.client.get_escrow_info(&12).amount, 300);
+    assert_eq!(setup.client.get_escrow_info(&14).amount, 2_000);
+    assert!(setup.client.try_get_escrow_info(&11).is_err());
+    assert!(setup.client.try_get_escrow_info(&13).is_err());
+    assert_eq!(setup.token.balance(&setup.depositor), 10_000 - 100 - 3_000);
+    assert_eq!(setup.token.balance(&poor), 200);
+    assert_eq!(setup.client.verify_solvency().total_liabilities, 3_400);
+}
+
+#[test]
+fn test_best_effort_lock_emits_item_and_summary_events() {
+    let setup = BestEffortSetup::new();
+    setup.lock(1, 100);
+
+    let items = vec![
+        &setup.env,
+        setup.lock_item(2, 400),
+        setup.lock_item(1, 100),
+        setup.lock_item(3, 600),
+    ];
+    setup.client.batch_lock_funds_best_effort(&items);
+
+    let item_events: Vec<BatchItemProcessed> = setup.events_named(symbol_short!("b_item"));
+    assert_eq!(item_events.len(), 3);
+    let skipped_event = item_events.get(1).unwrap();
+    assert_eq!(skipped_event.operation, symbol_short!("lock"));
+    assert_eq!(skipped_event.bounty_id, 1);
+    assert!(!skipped_event.ok);
+    assert_eq!(skipped_event.error_code, Error::BountyExists as u32);
+
+    let summaries: Vec<BatchSummary> = setup.events_named(symbol_short!("b_summary"));
+    assert_eq!(summaries.len(), 1);
+    let summary = summaries.get(0).unwrap();
+    assert_eq!(summary.operation, symbol_short!("lock"));
+    assert_eq!(summary.attempted, 3);
+    assert_eq!(summary.succeeded, 2);
+    assert_eq!(summary.failed, 1);
+    assert_eq!(summary.total_amount, 1_000);
+
+    assert_eq!(
+        setup.client.get_error_count(&(Error::BountyExists as u32)),
+        1
+    );
+}
+
+#[test]
+fn test_best_effort_repeat_of_skipped_item_is_skipped() {
+    let setup = BestEffortSetup::new();
+
+    // The first item for bounty 5 is rejected; a later one with a valid
+    // amount is still a repeat of an id seen earlier in the batch.
+    let items = vec![
+        &setup.env,
+        setup.lock_item(5, 0),
+        setup.lock_item(5, 400),
+        setup.lock_item(6, 300),
+    ];
+    let results = setup.client.batch_lock_funds_best_effort(&items);
+    assert_eq!(
+        results,
+        vec![
+            &setup.env,
+            skipped(5, Error::InvalidAmount),
+            skipped(5, Error::DuplicateBountyId),
+            ok(6),
+        ]
+    );
+    assert!(setup.client.try_get_escrow_info(&5).is_err());
+    assert_eq!(setup.token.balance(&setup.depositor), 10_000 - 300);
+}
+
+#[test]
+fn test_best_effort_release_skips_invalid_items() {
+    let setup = BestEffortSetup::new();
+    for id in 1..=3 {
+        setup.lock(id, 1_000 * id as i128);
+    }
+    setup.client.release_funds(&2, &setup.contributor);
+
+    let items = vec![
+        &setup.env,
+        setup.release_item(1),
+        setup.release_item(2),
+        setup.release_item(99),
+        setup.release_item(1),
+        setup.release_item(3),
+    ];
+    assert!(setup.client.try_batch_release_funds(&items).is_err());
+
+    let results = setup.client.batch_release_funds_best_effort(&items);
+    assert_eq!(
+        results,
+        vec![
+            &setup.env,
+            ok(1),
+            skipped(2, Error::FundsNotLocked),
+            skipped(99, Error::BountyNotFound),
+            skipped(1, Error::DuplicateBountyId),
+            ok(3),
+        ]
+    );
+
+    assert_eq!(
+        setup.client.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(
+        setup.client.get_escrow_info(&3).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(setup.token.balance(&setup.contributor), 6_000);
+
+    let summaries: Vec<BatchSummary> = setup.events_named(symbol_short!("b_summary"));
+    let summary = summaries.get(0).unwrap();
+    assert_eq!(summary.operation, symbol_short!("release"));
+    assert_eq!(summary.succeeded, 2);
+    assert_eq!(summary.failed, 3);
+    assert_eq!(summary.total_amount, 4_000);
+    assert!(setup.client.verify_solvency().is_solvent);
+}
+
+#[test]
+fn test_best_effort_release_with_no_valid_items_moves_nothing() {
+    let setup = BestEffortSetup::new();
+    setup.lock(1, 500);
+    setup.client.release_funds(&1, &setup.contributor);
+
+    let items = vec![&setup.env, setup.release_item(1), setup.release_item(7)];
+    let results = setup.client.batch_release_funds_best_effort(&items);
+
+    assert!(results.iter().all(|r| !r.ok));
+    assert_eq!(setup.token.balance(&setup.contributor), 500);
+    let summaries: Vec<BatchSummary> = setup.events_named(symbol_short!("b_summary"));
+    assert_eq!(summaries.get(0).unwrap().succeeded, 0);
+    assert_eq!(summaries.get(0).unwrap().total_amount, 0);
+}
+
+#[test]
+fn test_best_effort_batch_level_errors_abort() {
+    let setup = BestEffortSetup::new();
+
+    let empty: Vec<LockFundsItem> = Vec::new(&setup.env);
+    assert_eq!(
+        setup.client.try_batch_lock_funds_best_effort(&empty),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+
+    setup.lock(1, 100);
+    setup.client.set_paused(
+        &Some(true),
+        &Some(true),
+        &None::<bool>,
+        &None::<soroban_sdk::String>,
+    );
+    let lock_items = vec![&setup.env, setup.lock_item(2, 100)];
+    assert_eq!(
+        setup.client.try_batch_lock_funds_best_effort(&lock_items),
+        Err(Ok(Error::FundsPaused))
+    );
+    let release_items = vec![&setup.env, setup.release_item(1)];
+    assert_eq!(
+        setup
+            .client
+            .try_batch_release_funds_best_effort(&release_items),
+        Err(Ok(Error::FundsPaused))
+    );
+}
@@ -0,0 +1,236 @@
+#![cfg(test)]
+
+use crate::test_setup::EscrowSetup;
+use crate::{ClaimAuthorizationItem, Error, EscrowStatus, PartialReleaseItem, RefundMode};
+use core::ops::Deref;
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events as _, Ledger as _},
+    vec, Address, Symbol, TryFromVal, Vec,
+};
+
+struct BatchSetup {
+    base: EscrowSetup,
+}
+
+impl Deref for BatchSetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl BatchSetup {
+    fn new() -> Self {
+        Self {
+            base: EscrowSetup::with_claim_window(100_000),
+        }
+    }
+
+    fn pass_deadlines(&self) {
+        self.env
+            .ledger()
+            .set_timestamp(self.env.ledger().timestamp() + 1_001);
+    }
+
+    /// Token `transfer` events published so far.
+    fn transfer_count(&self) -> u32 {
+        let transfer = Symbol::new(&self.env, "transfer");
+        let mut count = 0;
+        for (contract, topics, _data) in self.env.events().all().iter() {
+            let first = topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&self.env, &t).ok());
+            if contract == self.token.address && first == Some(transfer.clone()) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    fn payout(&self, bounty_id: u64, amount: i128) -> PartialReleaseItem {
+        PartialReleaseItem {
+            bounty_id,
+            contributor: self.contributor.clone(),
+            amount,
+        }
+    }
+}
+
+#[test]
+fn test_batch_refund_pays_each_depositor_once() {
+    let setup = BatchSetup::new();
+    for id in 1..=3 {
+        setup.lock(id, 1_000 * id as i128);
+    }
+    setup.pass_deadlines();
+
+    let before = setup.transfer_count();
+    let refunded = setup.client.batch_refund(&vec![&setup.env, 1, 2, 3]);
+
+    assert_eq!(refunded, 3);
+    assert_eq!(setup.transfer_count() - before, 1);
+    assert_eq!(setup.token.balance(&setup.depositor), 100_000);
+    for id in 1..=3 {
+        let escrow = setup.client.get_escrow_info(&id);
+        assert_eq!(escrow.status, EscrowStatus::Refunded);
+        assert_eq!(escrow.remaining_amount, 0);
+        assert_eq!(escrow.refund_history.len(), 1);
+    }
+    assert_eq!(setup.client.verify_solvency().total_liabilities, 0);
+}
+
+#[test]
+fn test_batch_refund_honours_refund_approvals() {
+    let setup = BatchSetup::new();
+    setup.lock(1, 1_000);
+    setup.lock(2, 500);
+    let other = Address::generate(&setup.env);
+    setup
+        .client
+        .approve_refund(&1, &400, &other, &RefundMode::Partial);
+    setup
+        .client
+        .approve_refund(&2, &500, &setup.depositor, &RefundMode::Full);
+
+    setup.client.batch_refund(&vec![&setup.env, 1, 2]);
+
+    let first = setup.client.get_escrow_info(&1);
+    assert_eq!(first.status, EscrowStatus::PartiallyRefunded);
+    assert_eq!(first.remaining_amount, 600);
+    assert_eq!(setup.token.balance(&other), 400);
+    assert_eq!(
+        setup.client.get_escrow_info(&2).status,
+        EscrowStatus::Refunded
+    );
+    assert!(setup.client.get_refund_eligibility(&1).3.is_none());
+}
+
+#[test]
+fn test_batch_refund_is_all_or_nothing() {
+    let setup = BatchSetup::new();
+    setup.lock(1, 1_000);
+    setup.pass_deadlines();
+    setup.lock(2, 1_000);
+
+    assert_eq!(
+        setup.client.try_batch_refund(&vec![&setup.env, 1, 2]),
+        Err(Ok(Error::DeadlineNotPassed))
+    );
+    assert_eq!(
+        setup.client.try_batch_refund(&vec![&setup.env, 1, 1]),
+        Err(Ok(Error::DuplicateBountyId))
+    );
+    assert_eq!(
+        setup.client.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+
+    let mut too_many = Vec::new(&setup.env);
+    for id in 0..21 {
+        too_many.push_back(id);
+    }
+    assert_eq!(
+        setup.client.try_batch_refund(&too_many),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
+
+#[test]
+fn test_batch_partial_release_aggregates_per_contributor() {
+    let setup = BatchSetup::new();
+    setup.lock(1, 1_000);
+    setup.lock(2, 800);
+    setup.lock(3, 100);
+    let second = Address::generate(&setup.env);
+
+    let items = vec![
+        &setup.env,
+        setup.payout(1, 300),
+        setup.payout(2, 800),
+        PartialReleaseItem {
+            bounty_id: 3,
+            contributor: second.clone(),
+            amount: 100,
+        },
+    ];
+
+    let before = setup.transfer_count();
+    assert_eq!(setup.client.batch_partial_release(&items), 3);
+    assert_eq!(setup.transfer_count() - before, 2);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 1_100);
+    assert_eq!(setup.token.balance(&second), 100);
+    let first = setup.client.get_escrow_info(&1);
+    assert_eq!(first.status, EscrowStatus::Locked);
+    assert_eq!(first.remaining_amount, 700);
+    assert_eq!(
+        setup.client.get_escrow_info(&2).status,
+        EscrowStatus::Released
+    );
+    assert_eq!(setup.client.verify_solvency().total_liabilities, 700);
+}
+
+#[test]
+fn test_batch_partial_release_is_all_or_nothing() {
+    let setup = BatchSetup::new();
+    setup.lock(1, 1_000);
+    setup.lock(2, 500);
+
+    let over = vec![&setup.env, setup.payout(1, 300), setup.payout(2, 501)];
+    assert_eq!(
+        setup.client.try_batch_partial_release(&over),
+        Err(Ok(Error::InsufficientFunds))
+    );
+    let repeated = vec![&setup.env, setup.payout(1, 300), setup.payout(1, 300)];
+    assert_eq!(
+        setup.client.try_batch_partial_release(&repeated),
+        Err(Ok(Error::DuplicateBountyId))
+    );
+    assert_eq!(setup.client.get_escrow_info(&1).remaining_amount, 1_000);
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+}
+
+#[test]
+fn test_batch_authorize_claims() {
+    let setup = BatchSetup::new();
+    setup.lock(1, 1_000);
+    setup.lock(2, 400);
+    let item = |bounty_id| ClaimAuthorizationItem {
+        bounty_id,
+        recipient: setup.contributor.clone(),
+    };
+
+    assert_eq!(
+        setup
+            .client
+            .try_batch_authorize_claims(&vec![&setup.env, item(1), item(9)]),
+        Err(Ok(Error::BountyNotFound))
+    );
+    assert!(setup.client.try_get_pending_claim(&1).is_err());
+
+    assert_eq!(
+        setup
+            .client
+            .batch_authorize_claims(&vec![&setup.env, item(1), item(2)]),
+        2
+    );
+    assert_eq!(setup.client.get_pending_claim(&2).amount, 400);
+    assert_eq!(
+        setup.client.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::ClaimPending))
+    );
+
+    setup.client.claim(&1);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+
+    let summary_topic = symbol_short!("b_claims");
+    let published = setup.env.events().all().iter().any(|(_, topics, _)| {
+        topics
+            .get(0)
+            .and_then(|t| Symbol::try_from_val(&setup.env, &t).ok())
+            == Some(summary_topic.clone())
+    });
+    assert!(published);
+}
@@ -1,17 +1,12 @@
-// Tests for blacklist / whitelist functionality.
-//
-// These tests depend on contract methods (`set_blacklist`, `set_whitelist_mode`,
-// `initialize`, and the `ParticipantNotAllowed` error variant) that have not
-// been implemented yet.  They are gated behind `cfg(feature = "access_control")`
-// so they compile-out until the feature lands (tracked in a future issue).
+// Tests for the rate-limit whitelist and the depositor / recipient access
+// lists.
 
 #![cfg(test)]
-#![cfg(feature = "access_control")]
 
 use super::*;
 use soroban_sdk::{
-    testutils::{Address as _, Ledger, LedgerInfo},
-    Address, Env,
+    testutils::{Address as _, Events, Ledger, LedgerInfo},
+    vec, Address, Env, Symbol, TryFromVal,
 };
 
 fn create_env() -> Env {
@@ -93,3 +88,168 @@ fn test_removed_from_whitelist_reenables_rate_limit_checks() {
     let second = client.try_lock_funds(&depositor, &22, &100, &deadline);
     assert!(second.is_err());
 }
+
+fn access_list_events(env: &Env) -> u32 {
+    let name = Symbol::new(env, "acc_list");
+    let mut count = 0;
+    for (_, topics, _) in env.events().all().iter() {
+        if let Some(Ok(topic)) = topics.get(0).map(|topic| Symbol::try_from_val(env, &topic)) {
+            if topic == name {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+#[test]
+fn test_allow_list_mode_admits_only_listed_depositors() {
+    let env = create_env();
+    let (client, depositor, token_client) = setup(&env);
+    let deadline = env.ledger().timestamp() + 86_400;
+
+    client.set_access_mode(&AccessRole::Depositor, &AccessMode::AllowList);
+    assert_eq!(
+        client.get_access_mode(&AccessRole::Depositor),
+        AccessMode::AllowList
+    );
+    assert_eq!(
+        client.try_lock_funds(&depositor, &1, &100, &deadline),
+        Err(Ok(Error::ParticipantNotAllowed))
+    );
+    assert_eq!(
+        client
+            .simulate_lock(&depositor, &1, &100, &deadline)
+            .error_code,
+        Error::ParticipantNotAllowed as u32
+    );
+
+    client.set_access_list(
+        &AccessRole::Depositor,
+        &AccessListKind::Allow,
+        &vec![&env, depositor.clone()],
+        &true,
+    );
+    assert!(client.is_participant_allowed(&AccessRole::Depositor, &depositor));
+    client.lock_funds(&depositor, &1, &100, &deadline);
+    assert_eq!(token_client.balance(&client.address), 100);
+
+    // Recipients are governed separately and stay open.
+    let contributor = Address::generate(&env);
+    client.release_funds(&1, &contributor);
+    assert_eq!(token_client.balance(&contributor), 100);
+}
+
+#[test]
+fn test_deny_list_mode_blocks_listed_depositors_in_batches() {
+    let env = create_env();
+    let (client, depositor, _token) = setup(&env);
+    let deadline = env.ledger().timestamp() + 86_400;
+
+    client.set_access_list(
+        &AccessRole::Depositor,
+        &AccessListKind::Deny,
+        &vec![&env, depositor.clone()],
+        &true,
+    );
+    // Entries are inert while the mode is open.
+    assert!(client.is_on_access_list(&AccessRole::Depositor, &AccessListKind::Deny, &depositor));
+    assert!(client.is_participant_allowed(&AccessRole::Depositor, &depositor));
+
+    client.set_access_mode(&AccessRole::Depositor, &AccessMode::DenyList);
+    let items = vec![
+        &env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: depositor.clone(),
+            amount: 100,
+            deadline,
+        },
+    ];
+    assert_eq!(
+        client.try_batch_lock_funds(&items),
+        Err(Ok(Error::ParticipantNotAllowed))
+    );
+
+    client.set_access_mode(&AccessRole::Depositor, &AccessMode::Open);
+    assert_eq!(client.batch_lock_funds(&items), 1);
+}
+
+#[test]
+fn test_recipient_allow_list_gates_payouts_but_not_refunds() {
+    let env = create_env();
+    let (client, depositor, token_client) = setup(&env);
+    let approved = Address::generate(&env);
+    let stranger = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 100;
+    // The rate-limit whitelist is unrelated to the access lists.
+    client.set_whitelist_entry(&depositor, &true);
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    client.lock_funds(&depositor, &2, &1_000, &deadline);
+
+    client.set_access_list(
+        &AccessRole::Recipient,
+        &AccessListKind::Allow,
+        &vec![&env, approved.clone()],
+        &true,
+    );
+    client.set_access_mode(&AccessRole::Recipient, &AccessMode::AllowList);
+
+    assert_eq!(
+        client.try_release_funds(&1, &stranger),
+        Err(Ok(Error::ParticipantNotAllowed))
+    );
+    assert_eq!(
+        client.try_authorize_claim(&1, &stranger),
+        Err(Ok(Error::ParticipantNotAllowed))
+    );
+    client.partial_release(&1, &approved, &400);
+    assert_eq!(token_client.balance(&approved), 400);
+
+    // The depositor is not an approved recipient, yet still gets refunds.
+    env.ledger().set_timestamp(deadline + 1);
+    client.refund(&2);
+    assert_eq!(token_client.balance(&depositor), 9_000);
+}
+
+#[test]
+fn test_access_list_updates_emit_events_only_on_change() {
+    let env = create_env();
+    let (client, depositor, _token) = setup(&env);
+    let other = Address::generate(&env);
+
+    client.set_access_list(
+        &AccessRole::Recipient,
+        &AccessListKind::Deny,
+        &vec![&env, depositor.clone(), other.clone()],
+        &true,
+    );
+    assert_eq!(access_list_events(&env), 2);
+
+    // Re-adding is a no-op; removing a listed address is an update.
+    client.set_access_list(
+        &AccessRole::Recipient,
+        &AccessListKind::Deny,
+        &vec![&env, other.clone()],
+        &true,
+    );
+    assert_eq!(access_list_events(&env), 2);
+    client.set_access_list(
+        &AccessRole::Recipient,
+        &AccessListKind::Deny,
+        &vec![&env, other.clone()],
+        &false,
+    );
+    assert_eq!(access_list_events(&env), 3);
+    assert!(!client.is_on_access_list(&AccessRole::Recipient, &AccessListKind::Deny, &other));
+
+    assert_eq!(
+        client.try_set_access_list(
+            &AccessRole::Recipient,
+            &AccessListKind::Deny,
+            &vec![&env],
+            &true
+        ),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
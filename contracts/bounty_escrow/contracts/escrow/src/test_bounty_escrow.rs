@@ -1,4 +1,6 @@
-use crate::{BountyEscrowContract, BountyEscrowContractClient, Error as ContractError};
+use crate::{
+    BountyEscrowContract, BountyEscrowContractClient, Error as ContractError, RateLimitOperation,
+};
 use soroban_sdk::testutils::Events;
 use soroban_sdk::{
     testutils::{Address as _, Ledger, MockAuth, MockAuthInvoke},
@@ -1240,9 +1242,9 @@ fn test_max_bounty_count_queries_accurate() {
 // Rate limit and cooldown enforcement (Issue #460)
 // =============================================================================
 
-/// Exactly at rate limit: max_operations locks succeed; the next one panics with "Rate limit exceeded".
+/// Exactly at rate limit: max_operations locks succeed; the next one fails with RateLimited.
 #[test]
-#[should_panic(expected = "Rate limit exceeded")]
+#[should_panic(expected = "Error(Contract, #31)")] // RateLimited
 fn test_anti_abuse_exact_rate_limit_then_exceeded() {
     let (env, client, _) = create_test_env();
     let admin = Address::generate(&env);
@@ -1293,9 +1295,9 @@ fn test_anti_abuse_exact_rate_limit_boundary_succeeds() {
     assert_eq!(client.get_escrow_count(), 3);
 }
 
-/// Rapid repeated lock within cooldown period must panic.
+/// Rapid repeated lock within cooldown period must fail with CooldownActive.
 #[test]
-#[should_panic(expected = "Operation in cooldown period")]
+#[should_panic(expected = "Error(Contract, #32)")] // CooldownActive
 fn test_anti_abuse_cooldown_violation_panics() {
     let (env, client, _) = create_test_env();
     let admin = Address::generate(&env);
@@ -1375,6 +1377,156 @@ fn test_anti_abuse_whitelisted_address_bypasses_checks() {
     assert_eq!(client.get_escrow_count(), 5);
 }
 
+/// Rate-limit failures surface as typed errors that `try_` callers can match on.
+#[test]
+fn test_anti_abuse_errors_are_typed() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    client.update_anti_abuse_config(&3600, &1, &10);
+    token_admin_client.mint(&depositor, &10_000);
+
+    client.lock_funds(&depositor, &1, &100, &deadline);
+    assert_eq!(
+        client.try_lock_funds(&depositor, &2, &100, &deadline),
+        Err(Ok(ContractError::CooldownActive))
+    );
+
+    env.ledger().set_timestamp(start + 11);
+    assert_eq!(
+        client.try_lock_funds(&depositor, &2, &100, &deadline),
+        Err(Ok(ContractError::RateLimited))
+    );
+}
+
+/// Claim has its own limit, independent from lock, and is unlimited until configured.
+#[test]
+fn test_anti_abuse_claim_limit_is_separate_from_lock() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    client.set_whitelist_entry(&depositor, &true);
+    client.set_claim_window(&3600);
+    token_admin_client.mint(&depositor, &10_000);
+
+    for id in 1..=3_u64 {
+        client.lock_funds(&depositor, &id, &100, &deadline);
+        client.authorize_claim(&id, &contributor);
+    }
+    assert_eq!(
+        client.get_operation_rate_limit(&RateLimitOperation::Claim),
+        None
+    );
+
+    client.claim(&1);
+    client.update_operation_rate_limit(&RateLimitOperation::Claim, &3600, &5, &30);
+    client.claim(&2);
+    assert_eq!(client.try_claim(&3), Err(Ok(ContractError::CooldownActive)));
+
+    env.ledger().set_timestamp(start + 30);
+    client.claim(&3);
+    assert_eq!(token_client.balance(&contributor), 300);
+
+    client.clear_operation_rate_limit(&RateLimitOperation::Claim);
+    assert_eq!(
+        client.get_operation_rate_limit(&RateLimitOperation::Claim),
+        None
+    );
+}
+
+/// The state view reports counters, remaining budget and cooldown per operation.
+#[test]
+fn test_get_rate_limit_state_tracks_lock_operations() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    client.update_anti_abuse_config(&3600, &3, &60);
+    token_admin_client.mint(&depositor, &10_000);
+
+    let before = client.get_rate_limit_state(&depositor);
+    assert!(!before.whitelisted);
+    assert!(before.lock.enforced);
+    assert_eq!(before.lock.remaining_operations, 3);
+    assert!(!before.claim.enforced);
+    assert!(!before.capability.enforced);
+
+    client.lock_funds(&depositor, &1, &100, &deadline);
+
+    let after = client.get_rate_limit_state(&depositor);
+    assert_eq!(after.lock.operation_count, 1);
+    assert_eq!(after.lock.remaining_operations, 2);
+    assert_eq!(after.lock.last_operation_timestamp, start);
+    assert_eq!(after.lock.cooldown_ends_at, start + 60);
+
+    client.set_whitelist_entry(&depositor, &true);
+    let whitelisted = client.get_rate_limit_state(&depositor);
+    assert!(whitelisted.whitelisted);
+    assert!(!whitelisted.lock.enforced);
+}
+
+/// simulate_lock predicts a cooldown failure without consuming the budget.
+#[test]
+fn test_simulate_lock_reports_rate_limit_errors() {
+    let (env, client, _) = create_test_env();
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let start = 1_000_000_u64;
+    env.ledger().set_timestamp(start);
+    let deadline = start + 10_000;
+
+    env.mock_all_auths();
+
+    let token_admin = Address::generate(&env);
+    let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+    client.init(&admin, &token);
+    client.update_anti_abuse_config(&3600, &10, &60);
+    token_admin_client.mint(&depositor, &10_000);
+
+    assert!(
+        client
+            .simulate_lock(&depositor, &1, &100, &deadline)
+            .success
+    );
+    assert_eq!(
+        client.get_rate_limit_state(&depositor).lock.operation_count,
+        0
+    );
+
+    client.lock_funds(&depositor, &1, &100, &deadline);
+
+    let sim = client.simulate_lock(&depositor, &2, &100, &deadline);
+    assert!(!sim.success);
+    assert_eq!(sim.error_code, ContractError::CooldownActive as u32);
+}
+
 // =============================================================================
 // Admin and config updates (Issue #465)
 // =============================================================================
@@ -0,0 +1,175 @@
+#![cfg(test)]
+
+use crate::test_setup::EscrowSetup;
+use crate::{Error, EscrowStatus};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Events},
+    Address, String, Symbol, TryFromVal,
+};
+
+struct ExpirySetup {
+    base: EscrowSetup,
+}
+
+impl Deref for ExpirySetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl ExpirySetup {
+    /// Bounties 1..=3 hold 1_000 each, with a 100-second claim window and a
+    /// deadline 1_000 seconds out.
+    fn new() -> Self {
+        let base = EscrowSetup::new(3_000);
+        base.client.set_claim_window(&100);
+        for bounty_id in 1..=3 {
+            base.lock(bounty_id, 1_000);
+        }
+        Self { base }
+    }
+
+    fn expired_events(&self) -> u32 {
+        let expired = Symbol::new(&self.env, "expired");
+        let mut count = 0;
+        for (_, topics, _) in self.env.events().all().iter() {
+            if topics.len() < 2 {
+                continue;
+            }
+            if let Ok(name) = Symbol::try_from_val(&self.env, &topics.get(1).unwrap()) {
+                if name == expired {
+                    count += 1;
+                }
+            }
+        }
+        count
+    }
+}
+
+#[test]
+fn test_expired_claim_no_longer_blocks_refund() {
+    let setup = ExpirySetup::new();
+    setup.client.authorize_claim(&1, &setup.contributor);
+    assert!(!setup.client.get_refund_eligibility(&1).0);
+
+    setup.advance(1_001);
+    let (eligible, deadline_passed, remaining, _) = setup.client.get_refund_eligibility(&1);
+    assert!(eligible && deadline_passed);
+    assert_eq!(remaining, 1_000);
+
+    setup.client.refund(&1);
+    assert_eq!(setup.token.balance(&setup.depositor), 1_000);
+    assert_eq!(
+        setup.client.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_live_claim_still_blocks_refund() {
+    let setup = ExpirySetup::new();
+    setup.client.set_bounty_claim_window(&1, &Some(5_000));
+    setup.client.authorize_claim(&1, &setup.contributor);
+
+    setup.advance(1_001);
+    assert!(!setup.client.get_refund_eligibility(&1).0);
+    assert_eq!(setup.client.try_refund(&1), Err(Ok(Error::ClaimPending)));
+}
+
+#[test]
+fn test_expired_claim_no_longer_blocks_release() {
+    let setup = ExpirySetup::new();
+    let other = Address::generate(&setup.env);
+    setup.client.authorize_claim(&1, &setup.contributor);
+    assert_eq!(
+        setup.client.try_release_funds(&1, &other),
+        Err(Ok(Error::ClaimPending))
+    );
+
+    setup.advance(101);
+    setup.client.release_funds(&1, &other);
+    assert_eq!(setup.token.balance(&other), 1_000);
+}
+
+#[test]
+fn test_sweep_pages_through_expired_claims() {
+    let setup = ExpirySetup::new();
+    setup.client.set_bounty_claim_window(&2, &Some(5_000));
+    for bounty_id in 1..=3 {
+        setup.client.authorize_claim(&bounty_id, &setup.contributor);
+    }
+    setup.advance(101);
+
+    let first = setup.client.sweep_expired_claims(&0, &2);
+    assert_eq!(first.scanned, 2);
+    assert_eq!(first.next_cursor, Some(2));
+    assert_eq!(first.swept.len(), 1);
+    assert_eq!(first.swept.get(0), Some(1));
+    assert_eq!(setup.expired_events(), 1);
+
+    let second = setup.client.sweep_expired_claims(&2, &2);
+    assert_eq!(second.scanned, 1);
+    assert_eq!(second.next_cursor, None);
+    assert_eq!(second.swept.get(0), Some(3));
+
+    assert_eq!(
+        setup.client.try_get_pending_claim(&1),
+        Err(Ok(Error::BountyNotFound))
+    );
+    // The claim on bounty 2 is still live.
+    setup.client.claim(&2);
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+
+    // Nothing left to sweep.
+    let again = setup.client.sweep_expired_claims(&0, &10);
+    assert_eq!(again.swept.len(), 0);
+    assert_eq!(again.scanned, 3);
+}
+
+#[test]
+fn test_sweep_skips_frozen_escrows() {
+    let setup = ExpirySetup::new();
+    setup.client.authorize_claim(&1, &setup.contributor);
+    setup.client.authorize_claim(&2, &setup.contributor);
+    setup
+        .client
+        .freeze_escrow(&1, &String::from_str(&setup.env, "review"));
+    setup.advance(101);
+
+    let report = setup.client.sweep_expired_claims(&0, &10);
+    assert_eq!(report.scanned, 3);
+    assert_eq!(report.swept.len(), 1);
+    assert_eq!(report.swept.get(0), Some(2));
+    assert_eq!(setup.expired_events(), 1);
+
+    // The frozen escrow keeps its claim for the investigation.
+    assert!(setup.client.try_get_pending_claim(&1).is_ok());
+    assert_eq!(
+        setup.client.try_get_pending_claim(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
+
+#[test]
+fn test_bounty_claim_window_override() {
+    let setup = ExpirySetup::new();
+    assert_eq!(setup.client.get_bounty_claim_window(&1), 100);
+
+    setup.client.set_bounty_claim_window(&1, &Some(700));
+    assert_eq!(setup.client.get_bounty_claim_window(&1), 700);
+    assert_eq!(setup.client.get_bounty_claim_window(&2), 100);
+    let now = setup.env.ledger().timestamp();
+    setup.client.authorize_claim(&1, &setup.contributor);
+    assert_eq!(setup.client.get_pending_claim(&1).expires_at, now + 700);
+
+    setup.client.set_bounty_claim_window(&1, &None);
+    assert_eq!(setup.client.get_bounty_claim_window(&1), 100);
+
+    assert_eq!(
+        setup.client.try_set_bounty_claim_window(&99, &Some(10)),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
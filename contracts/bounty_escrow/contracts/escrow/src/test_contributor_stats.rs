@@ -0,0 +1,194 @@
+#![cfg(test)]
+
+use crate::test_setup::EscrowSetup;
+use crate::{EscrowStatus, PartialReleaseItem, ReleaseFundsItem};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Ledger as _},
+    vec, Address,
+};
+
+struct ContributorSetup {
+    base: EscrowSetup,
+}
+
+impl Deref for ContributorSetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl ContributorSetup {
+    fn new() -> Self {
+        let base = EscrowSetup::with_claim_window(1_000_000);
+        base.env.ledger().set_timestamp(1_000);
+        base.client.set_whitelist_entry(&base.depositor, &true);
+        Self { base }
+    }
+
+    fn lock(&self, bounty_id: u64, amount: i128) {
+        let deadline = self.env.ledger().timestamp() + 10_000;
+        self.client
+            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline);
+    }
+}
+
+#[test]
+fn test_unpaid_contributor_has_empty_stats() {
+    let setup = ContributorSetup::new();
+    let stats = setup.client.get_contributor_stats(&setup.contributor);
+
+    assert!(stats.total_earned.is_empty());
+    assert_eq!(stats.bounties_completed, 0);
+    assert_eq!(stats.partial_payouts, 0);
+    assert_eq!(stats.disputes_lost, 0);
+    assert_eq!(stats.first_payout_at, None);
+    assert_eq!(stats.last_payout_at, None);
+    assert!(setup
+        .client
+        .query_escrows_by_contributor(&setup.contributor, &0, &10)
+        .is_empty());
+}
+
+#[test]
+fn test_stats_follow_every_payout_path() {
+    let setup = ContributorSetup::new();
+    for id in 1..=5 {
+        setup.lock(id, 1_000);
+    }
+
+    setup.client.release_funds(&1, &setup.contributor);
+
+    setup.advance(10);
+    setup.client.partial_release(&2, &setup.contributor, &400);
+    setup.client.partial_release(&2, &setup.contributor, &600);
+
+    setup.advance(10);
+    setup.client.authorize_claim(&3, &setup.contributor);
+    setup.client.claim(&3);
+
+    setup.client.batch_release_funds(&vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 4,
+            contributor: setup.contributor.clone(),
+        },
+    ]);
+    setup.advance(10);
+    setup.client.batch_partial_release(&vec![
+        &setup.env,
+        PartialReleaseItem {
+            bounty_id: 5,
+            contributor: setup.contributor.clone(),
+            amount: 250,
+        },
+    ]);
+
+    let stats = setup.client.get_contributor_stats(&setup.contributor);
+    assert_eq!(stats.total_earned.len(), 1);
+    assert_eq!(
+        stats.total_earned.get(setup.token.address.clone()),
+        Some(4_250)
+    );
+    assert_eq!(stats.bounties_completed, 4);
+    assert_eq!(stats.partial_payouts, 3);
+    assert_eq!(stats.disputes_lost, 0);
+    assert_eq!(stats.first_payout_at, Some(1_000));
+    assert_eq!(stats.last_payout_at, Some(1_030));
+}
+
+#[test]
+fn test_earnings_are_net_of_the_release_fee() {
+    let setup = ContributorSetup::new();
+    let platform = Address::generate(&setup.env);
+    setup
+        .client
+        .update_fee_config(&Some(0), &Some(500), &Some(platform), &Some(true));
+    for id in 1..=4 {
+        setup.lock(id, 1_000);
+    }
+
+    setup.client.release_funds(&1, &setup.contributor);
+    setup.client.partial_release(&2, &setup.contributor, &400);
+    setup.client.authorize_claim(&3, &setup.contributor);
+    setup.client.claim(&3);
+    setup.client.batch_release_funds(&vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 4,
+            contributor: setup.contributor.clone(),
+        },
+    ]);
+
+    // 3_400 paid out, less 5%.
+    let received = setup.token.balance(&setup.contributor);
+    assert_eq!(received, 3_230);
+    let stats = setup.client.get_contributor_stats(&setup.contributor);
+    assert_eq!(
+        stats.total_earned.get(setup.token.address.clone()),
+        Some(received)
+    );
+}
+
+#[test]
+fn test_contributor_index_lists_each_bounty_once() {
+    let setup = ContributorSetup::new();
+    let other = Address::generate(&setup.env);
+    for id in 1..=4 {
+        setup.lock(id, 1_000);
+    }
+
+    setup.client.partial_release(&3, &setup.contributor, &100);
+    setup.client.release_funds(&1, &other);
+    setup.client.partial_release(&3, &setup.contributor, &100);
+    setup.client.release_funds(&2, &setup.contributor);
+    setup.client.partial_release(&4, &setup.contributor, &100);
+
+    let page = setup
+        .client
+        .query_escrows_by_contributor(&setup.contributor, &0, &10);
+    assert_eq!(page.len(), 3);
+    let ids: [u64; 3] = core::array::from_fn(|i| page.get(i as u32).unwrap().bounty_id);
+    assert_eq!(ids, [3, 2, 4]);
+    assert_eq!(page.get(1).unwrap().escrow.status, EscrowStatus::Released);
+
+    let second_page = setup
+        .client
+        .query_escrows_by_contributor(&setup.contributor, &2, &10);
+    assert_eq!(second_page.len(), 1);
+    assert_eq!(second_page.get(0).unwrap().bounty_id, 4);
+    assert_eq!(
+        setup
+            .client
+            .query_escrows_by_contributor(&other, &0, &10)
+            .len(),
+        1
+    );
+}
+
+#[test]
+fn test_rejected_claim_counts_as_lost_dispute() {
+    let setup = ContributorSetup::new();
+    setup.lock(1, 1_000);
+
+    // A routine cancellation is not a dispute outcome.
+    setup.client.authorize_claim(&1, &setup.contributor);
+    setup.client.cancel_pending_claim(&1);
+    let stats = setup.client.get_contributor_stats(&setup.contributor);
+    assert_eq!(stats.disputes_lost, 0);
+
+    setup.client.authorize_claim(&1, &setup.contributor);
+    setup.client.reject_pending_claim(&1);
+    assert!(setup.client.try_get_pending_claim(&1).is_err());
+
+    let stats = setup.client.get_contributor_stats(&setup.contributor);
+    assert_eq!(stats.disputes_lost, 1);
+    assert_eq!(stats.bounties_completed, 0);
+    assert_eq!(stats.first_payout_at, None);
+    assert!(setup
+        .client
+        .query_escrows_by_contributor(&setup.contributor, &0, &10)
+        .is_empty());
+}
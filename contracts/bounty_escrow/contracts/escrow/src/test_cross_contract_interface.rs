@@ -1,7 +1,12 @@
 #[cfg(test)]
 mod cross_contract_interface_tests {
     use crate::{BountyEscrowContract, EscrowStatus};
+    use grainlify_core::escrow_interface::{
+        EscrowClient, BOUNTY_ESCROW_INTERFACE_ID, ESCROW_INTERFACE_ID, ESCROW_INTERFACE_VERSION,
+        PROGRAM_ESCROW_INTERFACE_ID,
+    };
     use soroban_sdk::{
+        symbol_short,
         testutils::{Address as _, Ledger},
         token, Address, Env,
     };
@@ -310,4 +315,59 @@ mod cross_contract_interface_tests {
             assert_eq!(escrow.status, EscrowStatus::Locked);
         }
     }
+
+    #[test]
+    fn test_shared_escrow_interface_discovery() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let client = crate::BountyEscrowContractClient::new(&env, &contract_id);
+        let escrow = EscrowClient::new(&env, &contract_id);
+
+        assert!(escrow.supports_interface(&ESCROW_INTERFACE_ID));
+        assert!(escrow.supports_interface(&BOUNTY_ESCROW_INTERFACE_ID));
+        assert!(!escrow.supports_interface(&PROGRAM_ESCROW_INTERFACE_ID));
+        assert!(!escrow.supports_interface(&symbol_short!("erc20")));
+        assert_eq!(escrow.interface_version(), ESCROW_INTERFACE_VERSION);
+        assert_eq!(escrow.escrow_token(), None);
+        assert_eq!(escrow.escrow_balance(), 0);
+
+        let admin = Address::generate(&env);
+        let (token, _token_admin) = create_token_contract(&env, &admin);
+        client.init(&admin, &token.address);
+
+        assert_eq!(escrow.get_version(), crate::migration::CONTRACT_VERSION);
+        assert_eq!(escrow.escrow_token(), Some(token.address.clone()));
+    }
+
+    #[test]
+    fn test_shared_escrow_interface_tracks_funds() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let client = crate::BountyEscrowContractClient::new(&env, &contract_id);
+        let escrow = EscrowClient::new(&env, &contract_id);
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let contributor = Address::generate(&env);
+        let (token, token_admin) = create_token_contract(&env, &admin);
+        client.init(&admin, &token.address);
+        token_admin.mint(&depositor, &1_000_000);
+
+        let deadline = env.ledger().timestamp() + 3600;
+        client.lock_funds(&depositor, &1, &1_000, &deadline);
+        client.lock_funds(&depositor, &2, &500, &deadline);
+        client.partial_release(&1, &contributor, &300);
+
+        assert_eq!(escrow.escrow_balance(), 1_200);
+        assert_eq!(escrow.escrow_liabilities(), 1_200);
+        assert_eq!(escrow.escrow_balance(), client.get_balance());
+        assert_eq!(
+            escrow.escrow_liabilities(),
+            client.verify_solvency().total_liabilities
+        );
+    }
 }
@@ -1,9 +1,12 @@
 #![cfg(test)]
 
-use crate::{BountyEscrowContract, BountyEscrowContractClient, Error, EscrowStatus, RefundMode};
+use crate::{
+    AuditAction, BountyEscrowContract, BountyEscrowContractClient, DeadlineMode, Error,
+    EscrowStatus, RefundMode,
+};
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    token, Address, Env,
+    testutils::{Address as _, AuthorizedFunction, Ledger, MockAuth, MockAuthInvoke},
+    token, Address, Env, IntoVal, String, Symbol,
 };
 
 fn create_token_contract<'a>(
@@ -25,7 +28,7 @@ fn create_escrow_contract<'a>(e: &Env) -> BountyEscrowContractClient<'a> {
 
 struct Setup<'a> {
     env: Env,
-    _admin: Address,
+    admin: Address,
     depositor: Address,
     contributor: Address,
     token: token::Client<'a>,
@@ -49,7 +52,7 @@ impl<'a> Setup<'a> {
 
         Self {
             env,
-            _admin: admin,
+            admin,
             depositor,
             contributor,
             token,
@@ -333,3 +336,231 @@ fn test_deadline_future_vs_no_deadline_after_expiry() {
         Error::DeadlineNotPassed
     );
 }
+
+// =============================================================================
+// Deadline modes
+//
+// `lock_funds_with_deadline_mode` replaces the `0` / `u64::MAX` conventions
+// with an explicit mode. Non-absolute escrows store `u64::MAX` as their
+// deadline; `get_refund_deadline` reports the effective one.
+// =============================================================================
+
+#[test]
+fn test_open_ended_bounty_refunds_only_with_approval() {
+    let s = Setup::new();
+    s.escrow
+        .lock_funds_with_deadline_mode(&s.depositor, &40, &1_000, &DeadlineMode::OpenEnded);
+    assert_eq!(s.escrow.get_escrow_info(&40).deadline, NO_DEADLINE);
+    assert_eq!(s.escrow.get_refund_deadline(&40), None);
+
+    s.env.ledger().set_timestamp(u64::MAX - 1);
+    let (can_refund, deadline_passed, _, _) = s.escrow.get_refund_eligibility(&40);
+    assert!(!can_refund && !deadline_passed);
+    assert_eq!(
+        s.escrow.simulate_refund(&40).error_code,
+        Error::DeadlineNotPassed as u32
+    );
+
+    s.escrow
+        .approve_refund(&40, &1_000, &s.depositor, &RefundMode::Full);
+    assert!(s.escrow.simulate_refund(&40).success);
+    s.escrow.refund(&40);
+    assert_eq!(s.escrow.get_escrow_info(&40).status, EscrowStatus::Refunded);
+}
+
+#[test]
+fn test_after_assignment_clock_starts_on_assignment() {
+    let s = Setup::new();
+    s.escrow.lock_funds_with_deadline_mode(
+        &s.depositor,
+        &41,
+        &1_000,
+        &DeadlineMode::AfterAssignment(500),
+    );
+
+    // Unassigned: never refundable, however long it waits.
+    s.env.ledger().set_timestamp(10_000);
+    assert_eq!(s.escrow.get_refund_deadline(&41), None);
+    assert_eq!(s.escrow.try_refund(&41), Err(Ok(Error::DeadlineNotPassed)));
+
+    s.escrow.assign_contributor(&41, &s.contributor);
+    assert_eq!(s.escrow.get_assignment(&41).unwrap().assigned_at, 10_000);
+    assert_eq!(s.escrow.get_refund_deadline(&41), Some(10_500));
+
+    s.env.ledger().set_timestamp(10_499);
+    assert!(!s.escrow.get_refund_eligibility(&41).0);
+    s.env.ledger().set_timestamp(10_500);
+    assert!(s.escrow.get_refund_eligibility(&41).0);
+    s.escrow.refund(&41);
+}
+
+#[test]
+fn test_inactivity_deadline_extends_with_activity() {
+    let s = Setup::new();
+    s.env.ledger().set_timestamp(1_000);
+    s.escrow.lock_funds_with_deadline_mode(
+        &s.depositor,
+        &42,
+        &1_000,
+        &DeadlineMode::Inactivity(300),
+    );
+    assert_eq!(s.escrow.get_refund_deadline(&42), Some(1_300));
+
+    // A milestone payout restarts the clock.
+    s.env.ledger().set_timestamp(1_200);
+    s.escrow.partial_release(&42, &s.contributor, &100);
+    assert_eq!(s.escrow.get_refund_deadline(&42), Some(1_500));
+
+    // So does authorizing a claim; the claim then lapses.
+    s.escrow.set_claim_window(&50);
+    s.env.ledger().set_timestamp(1_400);
+    s.escrow.authorize_claim(&42, &s.contributor);
+    s.env.ledger().set_timestamp(1_650);
+    assert_eq!(
+        s.escrow.simulate_refund(&42).error_code,
+        Error::DeadlineNotPassed as u32
+    );
+
+    s.env.ledger().set_timestamp(1_700);
+    let result = s.escrow.simulate_refund(&42);
+    assert!(result.success);
+    assert_eq!(result.amount, 900);
+    s.escrow.refund(&42);
+    assert_eq!(s.token.balance(&s.contributor), 100);
+}
+
+#[test]
+fn test_admin_converts_sentinel_deadline_to_explicit_mode() {
+    let s = Setup::new();
+    s.escrow.lock_funds(&s.depositor, &43, &1_000, &NO_DEADLINE);
+    assert_eq!(
+        s.escrow.get_deadline_mode(&43),
+        DeadlineMode::Absolute(NO_DEADLINE)
+    );
+
+    s.escrow.set_deadline_mode(&43, &DeadlineMode::OpenEnded);
+    assert_eq!(s.escrow.get_deadline_mode(&43), DeadlineMode::OpenEnded);
+
+    let deadline = s.env.ledger().timestamp() + 100;
+    s.escrow
+        .set_deadline_mode(&43, &DeadlineMode::Absolute(deadline));
+    assert_eq!(s.escrow.get_escrow_info(&43).deadline, deadline);
+    assert_eq!(s.escrow.get_refund_deadline(&43), Some(deadline));
+}
+
+#[test]
+fn test_deadline_mode_change_needs_the_depositor() {
+    let s = Setup::new();
+    s.escrow.lock_funds(&s.depositor, &44, &1_000, &NO_DEADLINE);
+
+    s.escrow.set_deadline_mode(&44, &DeadlineMode::OpenEnded);
+    let auths = s.env.auths();
+    assert_eq!(auths.len(), 2);
+    let set_mode = Symbol::new(&s.env, "set_deadline_mode");
+    for (signer, (address, invocation)) in [&s.admin, &s.depositor].into_iter().zip(auths) {
+        assert_eq!(address, *signer);
+        assert!(matches!(
+            invocation.function,
+            AuthorizedFunction::Contract((_, ref name, _)) if *name == set_mode
+        ));
+    }
+
+    // The admin alone cannot push the refund date out of the depositor's reach.
+    let mode = DeadlineMode::Absolute(s.env.ledger().timestamp() + 100);
+    s.env.mock_auths(&[MockAuth {
+        address: &s.admin,
+        invoke: &MockAuthInvoke {
+            contract: &s.escrow.address,
+            fn_name: "set_deadline_mode",
+            args: (44u64, mode.clone()).into_val(&s.env),
+            sub_invokes: &[],
+        },
+    }]);
+    assert!(s.escrow.try_set_deadline_mode(&44, &mode).is_err());
+    s.env.mock_all_auths();
+    assert_eq!(s.escrow.get_deadline_mode(&44), DeadlineMode::OpenEnded);
+}
+
+#[test]
+fn test_relative_modes_need_a_period() {
+    let s = Setup::new();
+    let zero = DeadlineMode::Inactivity(0);
+    assert_eq!(
+        s.escrow
+            .simulate_lock_with_deadline_mode(&s.depositor, &44, &1_000, &zero)
+            .error_code,
+        Error::InvalidDeadline as u32
+    );
+    assert_eq!(
+        s.escrow
+            .try_lock_funds_with_deadline_mode(&s.depositor, &44, &1_000, &zero),
+        Err(Ok(Error::InvalidDeadline))
+    );
+    assert!(
+        s.escrow
+            .simulate_lock_with_deadline_mode(
+                &s.depositor,
+                &44,
+                &1_000,
+                &DeadlineMode::AfterAssignment(60)
+            )
+            .success
+    );
+
+    s.escrow.lock_funds(&s.depositor, &45, &1_000, &0);
+    s.escrow.release_funds(&45, &s.contributor);
+    assert_eq!(
+        s.escrow.try_assign_contributor(&45, &s.contributor),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
+
+#[test]
+fn test_deadline_admin_entrypoints_respect_status_and_freeze() {
+    let s = Setup::new();
+    s.escrow.lock_funds(&s.depositor, &46, &1_000, &0);
+    s.escrow.release_funds(&46, &s.contributor);
+    assert_eq!(
+        s.escrow
+            .try_set_deadline_mode(&46, &DeadlineMode::OpenEnded),
+        Err(Ok(Error::FundsNotLocked))
+    );
+
+    s.escrow.lock_funds(&s.depositor, &47, &1_000, &NO_DEADLINE);
+    s.escrow
+        .freeze_escrow(&47, &String::from_str(&s.env, "review"));
+    assert_eq!(
+        s.escrow
+            .try_set_deadline_mode(&47, &DeadlineMode::OpenEnded),
+        Err(Ok(Error::EscrowFrozen))
+    );
+    assert_eq!(
+        s.escrow.try_assign_contributor(&47, &s.contributor),
+        Err(Ok(Error::EscrowFrozen))
+    );
+    assert_eq!(
+        s.escrow.get_deadline_mode(&47),
+        DeadlineMode::Absolute(NO_DEADLINE)
+    );
+    assert!(s.escrow.get_assignment(&47).is_none());
+}
+
+#[test]
+fn test_deadline_admin_entrypoints_are_audited() {
+    let s = Setup::new();
+    s.escrow.lock_funds(&s.depositor, &48, &1_000, &NO_DEADLINE);
+    s.escrow
+        .set_deadline_mode(&48, &DeadlineMode::AfterAssignment(60));
+    s.escrow.assign_contributor(&48, &s.contributor);
+
+    let log = s.escrow.get_audit_log(&48, &0, &10);
+    assert_eq!(log.entries.len(), 3);
+    let updated = log.entries.get(1).unwrap();
+    assert_eq!(updated.action, AuditAction::DeadlineModeUpdated);
+    assert_eq!(updated.actor, Some(s.admin.clone()));
+    assert_eq!(updated.counterparty, None);
+    let assigned = log.entries.get(2).unwrap();
+    assert_eq!(assigned.action, AuditAction::ContributorAssigned);
+    assert_eq!(assigned.actor, Some(s.admin.clone()));
+    assert_eq!(assigned.counterparty, Some(s.contributor.clone()));
+}
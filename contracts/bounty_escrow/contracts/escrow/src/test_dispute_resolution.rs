@@ -115,6 +115,7 @@ fn test_open_dispute_blocks_refund() {
     setup
         .escrow
         .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
+    setup.escrow.set_claim_window(&1_000_u64);
     setup.escrow.authorize_claim(&bounty_id, &setup.contributor);
 
     setup.env.ledger().set_timestamp(deadline + 1);
@@ -366,7 +366,7 @@ fn test_simulate_refund_with_pending_claim_fails() {
     let deadline = s.env.ledger().timestamp() + 10_000;
 
     s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
-    s.escrow.set_claim_window(&500_u64);
+    s.escrow.set_claim_window(&20_000_u64);
     s.escrow.authorize_claim(&1_u64, &s.contributor);
 
     s.env.ledger().set_timestamp(deadline + 1);
@@ -376,3 +376,432 @@ fn test_simulate_refund_with_pending_claim_fails() {
     assert!(!result.success);
     assert_eq!(result.error_code, Error::ClaimPending as u32);
 }
+
+// ===========================================================================
+// simulate_claim / simulate_partial_release
+// ===========================================================================
+
+#[test]
+fn test_simulate_release_with_pending_claim_fails() {
+    let s = SimSetup::new();
+    let deadline = s.env.ledger().timestamp() + 5_000;
+
+    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow.set_claim_window(&500_u64);
+    s.escrow.authorize_claim(&1_u64, &s.contributor);
+
+    let sim = s.escrow.simulate_release(&1_u64, &s.contributor);
+    assert!(!sim.success);
+    assert_eq!(sim.error_code, Error::ClaimPending as u32);
+    assert_eq!(
+        s.escrow.try_release_funds(&1_u64, &s.contributor),
+        Err(Ok(Error::ClaimPending))
+    );
+}
+
+#[test]
+fn test_simulate_claim_matches_real_execution() {
+    let s = SimSetup::new();
+    let deadline = s.env.ledger().timestamp() + 5_000;
+
+    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow.set_claim_window(&500_u64);
+    s.escrow.authorize_claim(&1_u64, &s.contributor);
+
+    let sim = s.escrow.simulate_claim(&1_u64);
+    assert!(sim.success);
+    assert_eq!(sim.amount, 1_000);
+    assert!(!s.escrow.get_pending_claim(&1_u64).claimed);
+
+    s.escrow.claim(&1_u64);
+    let info = s.escrow.get_escrow_info(&1_u64);
+    assert_eq!(info.status, sim.resulting_status);
+    assert_eq!(info.remaining_amount, sim.remaining_amount);
+    assert_eq!(s.token.balance(&s.contributor), sim.amount);
+}
+
+#[test]
+fn test_simulate_claim_without_pending_claim_fails() {
+    let s = SimSetup::new();
+    let deadline = s.env.ledger().timestamp() + 5_000;
+    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+
+    let sim = s.escrow.simulate_claim(&1_u64);
+    assert!(!sim.success);
+    assert_eq!(sim.error_code, Error::BountyNotFound as u32);
+    assert_eq!(sim.resulting_status, EscrowStatus::Locked);
+    assert_eq!(sim.remaining_amount, 1_000);
+    assert_eq!(s.escrow.try_claim(&1_u64), Err(Ok(Error::BountyNotFound)));
+}
+
+#[test]
+fn test_simulate_claim_after_window_fails() {
+    let s = SimSetup::new();
+    let deadline = s.env.ledger().timestamp() + 5_000;
+
+    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow.set_claim_window(&500_u64);
+    s.escrow.authorize_claim(&1_u64, &s.contributor);
+    s.env
+        .ledger()
+        .set_timestamp(s.env.ledger().timestamp() + 501);
+
+    let sim = s.escrow.simulate_claim(&1_u64);
+    assert!(!sim.success);
+    assert_eq!(sim.error_code, Error::ClaimExpired as u32);
+    assert_eq!(s.escrow.try_claim(&1_u64), Err(Ok(Error::ClaimExpired)));
+}
+
+#[test]
+fn test_simulate_claim_respects_claim_rate_limit() {
+    let s = SimSetup::new();
+    s.env.ledger().set_timestamp(1_000);
+    let deadline = 10_000;
+
+    s.escrow.set_whitelist_entry(&s.depositor, &true);
+    s.escrow.set_claim_window(&3_600_u64);
+    for id in 1..=2_u64 {
+        s.escrow.lock_funds(&s.depositor, &id, &100, &deadline);
+        s.escrow.authorize_claim(&id, &s.contributor);
+    }
+    s.escrow
+        .update_operation_rate_limit(&RateLimitOperation::Claim, &3_600, &5, &30);
+    s.escrow.claim(&1_u64);
+
+    let sim = s.escrow.simulate_claim(&2_u64);
+    assert!(!sim.success);
+    assert_eq!(sim.error_code, Error::CooldownActive as u32);
+    assert_eq!(s.escrow.try_claim(&2_u64), Err(Ok(Error::CooldownActive)));
+
+    s.env.ledger().set_timestamp(1_030);
+    assert!(s.escrow.simulate_claim(&2_u64).success);
+}
+
+#[test]
+fn test_simulate_partial_release_matches_real_execution() {
+    let s = SimSetup::new();
+    let deadline = s.env.ledger().timestamp() + 5_000;
+    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+
+    let sim = s
+        .escrow
+        .simulate_partial_release(&1_u64, &s.contributor, &400);
+    assert!(sim.success);
+    assert_eq!(sim.amount, 400);
+    assert_eq!(sim.resulting_status, EscrowStatus::Locked);
+    assert_eq!(sim.remaining_amount, 600);
+
+    s.escrow.partial_release(&1_u64, &s.contributor, &400);
+    assert_eq!(s.escrow.get_escrow_info(&1_u64).remaining_amount, 600);
+
+    let rest = s
+        .escrow
+        .simulate_partial_release(&1_u64, &s.contributor, &600);
+    assert!(rest.success);
+    assert_eq!(rest.resulting_status, EscrowStatus::Released);
+    assert_eq!(rest.remaining_amount, 0);
+
+    let too_much = s
+        .escrow
+        .simulate_partial_release(&1_u64, &s.contributor, &601);
+    assert!(!too_much.success);
+    assert_eq!(too_much.error_code, Error::InsufficientFunds as u32);
+    assert_eq!(
+        s.escrow.try_partial_release(&1_u64, &s.contributor, &601),
+        Err(Ok(Error::InsufficientFunds))
+    );
+}
+
+// ===========================================================================
+// simulate_*_with_capability
+// ===========================================================================
+
+#[test]
+fn test_simulate_release_with_capability_matches_real_execution() {
+    let s = SimSetup::new();
+    let deadline = s.env.ledger().timestamp() + 5_000;
+    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+
+    let delegate = Address::generate(&s.env);
+    let expiry = s.env.ledger().timestamp() + 300;
+    let cap = s.escrow.issue_capability(
+        &s._admin,
+        &delegate,
+        &CapabilityAction::Release,
+        &1_u64,
+        &600,
+        &expiry,
+        &2,
+    );
+
+    let sim =
+        s.escrow
+            .simulate_release_with_capability(&1_u64, &s.contributor, &400, &delegate, &cap);
+    assert!(sim.success);
+    assert_eq!(sim.remaining_amount, 600);
+    // Simulating does not spend the capability.
+    assert_eq!(s.escrow.get_capability(&cap).remaining_uses, 2);
+
+    s.escrow
+        .release_with_capability(&1_u64, &s.contributor, &400, &delegate, &cap);
+    assert_eq!(
+        s.escrow.get_escrow_info(&1_u64).remaining_amount,
+        sim.remaining_amount
+    );
+
+    let over_limit =
+        s.escrow
+            .simulate_release_with_capability(&1_u64, &s.contributor, &300, &delegate, &cap);
+    assert!(!over_limit.success);
+    assert_eq!(
+        over_limit.error_code,
+        Error::CapabilityAmountExceeded as u32
+    );
+
+    let stranger = Address::generate(&s.env);
+    let wrong_holder =
+        s.escrow
+            .simulate_release_with_capability(&1_u64, &s.contributor, &100, &stranger, &cap);
+    assert!(!wrong_holder.success);
+    assert_eq!(wrong_holder.error_code, Error::Unauthorized as u32);
+    assert_eq!(
+        s.escrow
+            .try_release_with_capability(&1_u64, &s.contributor, &100, &stranger, &cap),
+        Err(Ok(Error::Unauthorized))
+    );
+}
+
+#[test]
+fn test_simulate_refund_with_capability_action_mismatch() {
+    let s = SimSetup::new();
+    let deadline = s.env.ledger().timestamp() + 5_000;
+    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+
+    let delegate = Address::generate(&s.env);
+    let expiry = s.env.ledger().timestamp() + 300;
+    let release_cap = s.escrow.issue_capability(
+        &s._admin,
+        &delegate,
+        &CapabilityAction::Release,
+        &1_u64,
+        &500,
+        &expiry,
+        &1,
+    );
+    let refund_cap = s.escrow.issue_capability(
+        &s._admin,
+        &delegate,
+        &CapabilityAction::Refund,
+        &1_u64,
+        &500,
+        &expiry,
+        &1,
+    );
+
+    let mismatch = s
+        .escrow
+        .simulate_refund_with_capability(&1_u64, &200, &delegate, &release_cap);
+    assert!(!mismatch.success);
+    assert_eq!(mismatch.error_code, Error::CapabilityActionMismatch as u32);
+
+    let sim = s
+        .escrow
+        .simulate_refund_with_capability(&1_u64, &200, &delegate, &refund_cap);
+    assert!(sim.success);
+    assert_eq!(sim.resulting_status, EscrowStatus::PartiallyRefunded);
+    assert_eq!(sim.remaining_amount, 800);
+
+    s.escrow
+        .refund_with_capability(&1_u64, &200, &delegate, &refund_cap);
+    let info = s.escrow.get_escrow_info(&1_u64);
+    assert_eq!(info.status, sim.resulting_status);
+    assert_eq!(info.remaining_amount, sim.remaining_amount);
+}
+
+#[test]
+fn test_simulate_claim_with_capability_matches_real_execution() {
+    let s = SimSetup::new();
+    let deadline = s.env.ledger().timestamp() + 5_000;
+    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow.set_claim_window(&600_u64);
+    s.escrow.authorize_claim(&1_u64, &s.contributor);
+
+    let delegate = Address::generate(&s.env);
+    let expiry = s.env.ledger().timestamp() + 300;
+    let cap = s.escrow.issue_capability(
+        &s.contributor,
+        &delegate,
+        &CapabilityAction::Claim,
+        &1_u64,
+        &1_000,
+        &expiry,
+        &1,
+    );
+
+    let sim = s
+        .escrow
+        .simulate_claim_with_capability(&1_u64, &delegate, &cap);
+    assert!(sim.success);
+    assert_eq!(sim.amount, 1_000);
+
+    s.escrow.claim_with_capability(&1_u64, &delegate, &cap);
+    assert_eq!(s.token.balance(&s.contributor), sim.amount);
+
+    let again = s
+        .escrow
+        .simulate_claim_with_capability(&1_u64, &delegate, &cap);
+    assert!(!again.success);
+    let real = s
+        .escrow
+        .try_claim_with_capability(&1_u64, &delegate, &cap)
+        .unwrap_err()
+        .unwrap();
+    assert_eq!(again.error_code, real as u32);
+}
+
+// ===========================================================================
+// simulate_batch_lock / simulate_batch_release
+// ===========================================================================
+
+fn lock_item(s: &SimSetup, bounty_id: u64, amount: i128) -> LockFundsItem {
+    LockFundsItem {
+        bounty_id,
+        depositor: s.depositor.clone(),
+        amount,
+        deadline: s.env.ledger().timestamp() + 5_000,
+    }
+}
+
+#[test]
+fn test_simulate_batch_lock_reports_each_item() {
+    let s = SimSetup::new();
+    let deadline = s.env.ledger().timestamp() + 5_000;
+    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+
+    let items = vec![
+        &s.env,
+        lock_item(&s, 10, 500),
+        lock_item(&s, 1, 500),
+        lock_item(&s, 11, 0),
+    ];
+    let results = s.escrow.simulate_batch_lock(&items);
+
+    assert_eq!(results.len(), 3);
+    assert!(results.get(0).unwrap().success);
+    assert_eq!(results.get(0).unwrap().remaining_amount, 500);
+    assert_eq!(
+        results.get(1).unwrap().error_code,
+        Error::BountyExists as u32
+    );
+    assert_eq!(
+        results.get(2).unwrap().error_code,
+        Error::InvalidAmount as u32
+    );
+    assert_eq!(
+        s.escrow.try_batch_lock_funds(&items),
+        Err(Ok(Error::BountyExists))
+    );
+    assert!(s.escrow.try_get_escrow_info(&10_u64).is_err());
+}
+
+#[test]
+fn test_simulate_batch_lock_checks_cumulative_balance() {
+    let s = SimSetup::new();
+    let poor = Address::generate(&s.env);
+    s.token_admin.mint(&poor, &700);
+    let deadline = s.env.ledger().timestamp() + 5_000;
+    let poor_item = |bounty_id| LockFundsItem {
+        bounty_id,
+        depositor: poor.clone(),
+        amount: 400,
+        deadline,
+    };
+
+    let items = vec![&s.env, poor_item(1), lock_item(&s, 2, 400), poor_item(3)];
+    let results = s.escrow.simulate_batch_lock(&items);
+
+    assert_eq!(
+        results.get(0).unwrap().error_code,
+        Error::InsufficientFunds as u32
+    );
+    assert!(results.get(1).unwrap().success);
+    assert_eq!(
+        results.get(2).unwrap().error_code,
+        Error::InsufficientFunds as u32
+    );
+    assert!(s.escrow.try_batch_lock_funds(&items).is_err());
+}
+
+#[test]
+fn test_simulate_batch_release_reports_each_item() {
+    let s = SimSetup::new();
+    let deadline = s.env.ledger().timestamp() + 5_000;
+    for id in 1..=3_u64 {
+        s.escrow.lock_funds(&s.depositor, &id, &1_000, &deadline);
+    }
+    s.escrow.release_funds(&2_u64, &s.contributor);
+
+    let item = |bounty_id| ReleaseFundsItem {
+        bounty_id,
+        contributor: s.contributor.clone(),
+    };
+    let items = vec![&s.env, item(1), item(2), item(3), item(99)];
+    let results = s.escrow.simulate_batch_release(&items);
+
+    assert!(results.get(0).unwrap().success);
+    assert_eq!(
+        results.get(0).unwrap().resulting_status,
+        EscrowStatus::Released
+    );
+    let already = results.get(1).unwrap();
+    assert_eq!(already.error_code, Error::FundsNotLocked as u32);
+    assert_eq!(already.resulting_status, EscrowStatus::Released);
+    assert!(results.get(2).unwrap().success);
+    assert_eq!(
+        results.get(3).unwrap().error_code,
+        Error::BountyNotFound as u32
+    );
+
+    let valid = vec![&s.env, item(1), item(3)];
+    assert!(s
+        .escrow
+        .simulate_batch_release(&valid)
+        .iter()
+        .all(|r| r.success));
+    assert_eq!(s.escrow.batch_release_funds(&valid), 2);
+}
+
+#[test]
+fn test_simulate_batch_release_batch_error_applies_to_every_item() {
+    let s = SimSetup::new();
+    let deadline = s.env.ledger().timestamp() + 5_000;
+    s.escrow.lock_funds(&s.depositor, &1_u64, &1_000, &deadline);
+    s.escrow.set_paused(
+        &None::<bool>,
+        &Some(true),
+        &None::<bool>,
+        &Some(soroban_sdk::String::from_str(&s.env, "test")),
+    );
+
+    let items = vec![
+        &s.env,
+        ReleaseFundsItem {
+            bounty_id: 1,
+            contributor: s.contributor.clone(),
+        },
+        ReleaseFundsItem {
+            bounty_id: 2,
+            contributor: s.contributor.clone(),
+        },
+    ];
+    let results = s.escrow.simulate_batch_release(&items);
+
+    assert_eq!(results.len(), 2);
+    for result in results.iter() {
+        assert_eq!(result.error_code, Error::FundsPaused as u32);
+    }
+    assert_eq!(
+        s.escrow.try_batch_release_funds(&items),
+        Err(Ok(Error::FundsPaused))
+    );
+}
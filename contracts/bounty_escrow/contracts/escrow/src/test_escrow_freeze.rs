@@ -0,0 +1,236 @@
+#![cfg(test)]
+
+use crate::test_setup::EscrowSetup;
+use crate::{CapabilityAction, Error, EscrowStatus, RefundMode};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    Address, Env, String, Symbol, TryFromVal,
+};
+
+struct FreezeSetup {
+    base: EscrowSetup,
+}
+
+impl Deref for FreezeSetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl FreezeSetup {
+    fn new() -> Self {
+        Self {
+            base: EscrowSetup::with_claim_window(100_000),
+        }
+    }
+
+    fn freeze(&self, bounty_id: u64) {
+        self.client
+            .freeze_escrow(&bounty_id, &String::from_str(&self.env, "fraud review"));
+    }
+}
+
+fn has_event_topic(env: &Env, topic_name: &str) -> bool {
+    let expected = Symbol::new(env, topic_name);
+    for (_contract, topics, _data) in env.events().all().iter() {
+        if let Some(first) = topics.get(0) {
+            if Symbol::try_from_val(env, &first).ok() == Some(expected.clone()) {
+                return true;
+            }
+        }
+    }
+    false
+}
+
+#[test]
+fn test_freeze_marks_escrow_frozen_and_records_reason() {
+    let setup = FreezeSetup::new();
+    setup.lock(1, 1_000);
+
+    setup.freeze(1);
+    assert!(has_event_topic(&setup.env, "frozen"));
+
+    let escrow = setup.client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Frozen);
+    assert_eq!(escrow.remaining_amount, 1_000);
+
+    let record = setup.client.get_freeze_record(&1).unwrap();
+    assert_eq!(record.reason, String::from_str(&setup.env, "fraud review"));
+    assert_eq!(record.frozen_by, setup.admin);
+    assert_eq!(record.previous_status, EscrowStatus::Locked);
+}
+
+#[test]
+fn test_freeze_blocks_release_refund_and_partial_release() {
+    let setup = FreezeSetup::new();
+    setup.lock(1, 1_000);
+    setup.freeze(1);
+
+    assert_eq!(
+        setup.client.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::EscrowFrozen))
+    );
+    assert_eq!(
+        setup
+            .client
+            .try_partial_release(&1, &setup.contributor, &100),
+        Err(Ok(Error::EscrowFrozen))
+    );
+    assert_eq!(
+        setup
+            .client
+            .try_approve_refund(&1, &100, &setup.depositor, &RefundMode::Partial),
+        Err(Ok(Error::EscrowFrozen))
+    );
+
+    setup.env.ledger().set_timestamp(2_000);
+    assert_eq!(setup.client.try_refund(&1), Err(Ok(Error::EscrowFrozen)));
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+}
+
+#[test]
+fn test_freeze_blocks_claims_and_capabilities() {
+    let setup = FreezeSetup::new();
+    setup.lock(1, 1_000);
+    setup.client.authorize_claim(&1, &setup.contributor);
+
+    let delegate = Address::generate(&setup.env);
+    let expiry = setup.env.ledger().timestamp() + 300;
+    let capability_id = setup.client.issue_capability(
+        &setup.admin,
+        &delegate,
+        &CapabilityAction::Refund,
+        &1,
+        &500,
+        &expiry,
+        &1,
+    );
+
+    setup.freeze(1);
+
+    assert_eq!(setup.client.try_claim(&1), Err(Ok(Error::EscrowFrozen)));
+    assert_eq!(
+        setup.client.try_cancel_pending_claim(&1),
+        Err(Ok(Error::EscrowFrozen))
+    );
+    assert_eq!(
+        setup
+            .client
+            .try_refund_with_capability(&1, &500, &delegate, &capability_id),
+        Err(Ok(Error::EscrowFrozen))
+    );
+    assert_eq!(
+        setup.client.try_issue_capability(
+            &setup.admin,
+            &delegate,
+            &CapabilityAction::Release,
+            &1,
+            &500,
+            &expiry,
+            &1,
+        ),
+        Err(Ok(Error::EscrowFrozen))
+    );
+}
+
+#[test]
+fn test_frozen_escrow_visible_in_queries_and_eligibility() {
+    let setup = FreezeSetup::new();
+    setup.lock(1, 1_000);
+    setup.lock(2, 2_000);
+    setup.freeze(2);
+
+    let frozen = setup
+        .client
+        .query_escrows_by_status(&EscrowStatus::Frozen, &0, &10);
+    assert_eq!(frozen.len(), 1);
+    assert_eq!(frozen.get(0).unwrap().bounty_id, 2);
+
+    let stats = setup.client.get_aggregate_stats();
+    assert_eq!(stats.count_locked, 1);
+    assert_eq!(stats.count_frozen, 1);
+    assert_eq!(stats.total_frozen, 2_000);
+
+    setup.env.ledger().set_timestamp(2_000);
+    let (can_refund, deadline_passed, remaining, _) = setup.client.get_refund_eligibility(&2);
+    assert!(!can_refund);
+    assert!(deadline_passed);
+    assert_eq!(remaining, 2_000);
+
+    let sim = setup.client.simulate_refund(&2);
+    assert!(!sim.success);
+    assert_eq!(sim.error_code, Error::EscrowFrozen as u32);
+}
+
+#[test]
+fn test_freeze_is_scoped_to_one_bounty() {
+    let setup = FreezeSetup::new();
+    setup.lock(1, 1_000);
+    setup.lock(2, 2_000);
+    setup.freeze(1);
+
+    setup.client.release_funds(&2, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 2_000);
+}
+
+#[test]
+fn test_unfreeze_restores_previous_status_and_allows_release() {
+    let setup = FreezeSetup::new();
+    setup.lock(1, 1_000);
+    setup
+        .client
+        .approve_refund(&1, &400, &setup.depositor, &RefundMode::Partial);
+    setup.client.refund(&1);
+    assert_eq!(
+        setup.client.get_escrow_info(&1).status,
+        EscrowStatus::PartiallyRefunded
+    );
+
+    setup.freeze(1);
+    setup.client.unfreeze_escrow(&1);
+    assert!(has_event_topic(&setup.env, "unfrozen"));
+    assert_eq!(setup.client.get_freeze_record(&1), None);
+
+    let escrow = setup.client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::PartiallyRefunded);
+    assert_eq!(escrow.remaining_amount, 600);
+
+    setup.env.ledger().set_timestamp(2_000);
+    setup.client.refund(&1);
+    assert_eq!(
+        setup.client.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_freeze_rejects_invalid_targets() {
+    let setup = FreezeSetup::new();
+    let reason = String::from_str(&setup.env, "x");
+    assert_eq!(
+        setup.client.try_freeze_escrow(&9, &reason),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    setup.lock(1, 1_000);
+    assert_eq!(
+        setup.client.try_unfreeze_escrow(&1),
+        Err(Ok(Error::EscrowNotFrozen))
+    );
+
+    setup.freeze(1);
+    assert_eq!(
+        setup.client.try_freeze_escrow(&1, &reason),
+        Err(Ok(Error::EscrowFrozen))
+    );
+
+    setup.lock(2, 500);
+    setup.client.release_funds(&2, &setup.contributor);
+    assert_eq!(
+        setup.client.try_freeze_escrow(&2, &reason),
+        Err(Ok(Error::FundsNotLocked))
+    );
+}
@@ -0,0 +1,197 @@
+#![cfg(test)]
+
+use crate::test_setup::EscrowSetup;
+use crate::{
+    BountyEventKind, BountyEventV3, EscrowStatus, EventSchema, RateLimitOperation, RefundMode,
+};
+use core::ops::Deref;
+use soroban_sdk::{
+    symbol_short, testutils::Events, Address, Map, String, Symbol, TryFromVal, Val, Vec,
+};
+
+struct SchemaSetup {
+    base: EscrowSetup,
+}
+
+impl Deref for SchemaSetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl SchemaSetup {
+    fn new() -> Self {
+        Self {
+            base: EscrowSetup::with_claim_window(1_000_000),
+        }
+    }
+
+    /// Versions of the escrow contract's events emitted while running `f`.
+    fn versions(&self, f: impl FnOnce()) -> Vec<u32> {
+        let before = self.env.events().all().len();
+        f();
+        let mut versions = Vec::new(&self.env);
+        for (contract, _topics, data) in self.env.events().all().iter().skip(before as usize) {
+            if contract != self.client.address {
+                continue;
+            }
+            let map: Map<Symbol, Val> = Map::try_from_val(&self.env, &data).unwrap();
+            let version = map.get(Symbol::new(&self.env, "version")).unwrap();
+            versions.push_back(u32::try_from_val(&self.env, &version).unwrap());
+        }
+        versions
+    }
+
+    /// The most recent v3 lifecycle event, with its topics.
+    fn last_v3(&self) -> (Vec<Val>, BountyEventV3) {
+        for (contract, topics, data) in self.env.events().all().iter().rev() {
+            if contract != self.client.address {
+                continue;
+            }
+            if let Ok(event) = BountyEventV3::try_from_val(&self.env, &data) {
+                return (topics, event);
+            }
+        }
+        panic!("no v3 bounty event emitted");
+    }
+}
+
+#[test]
+fn test_default_schema_emits_only_v2() {
+    let setup = SchemaSetup::new();
+    assert_eq!(setup.client.get_event_schema(), EventSchema::V2);
+
+    let versions = setup.versions(|| setup.lock(1, 1_000));
+    assert_eq!(versions, Vec::from_array(&setup.env, [2]));
+
+    // Configuration changes have no v2 event.
+    let versions = setup.versions(|| setup.client.set_claim_window(&7_200));
+    assert_eq!(versions.len(), 0);
+}
+
+#[test]
+fn test_dual_schema_emits_both_versions() {
+    let setup = SchemaSetup::new();
+    setup.client.set_event_schema(&EventSchema::Dual);
+
+    let versions = setup.versions(|| setup.lock(1, 1_000));
+    assert_eq!(versions, Vec::from_array(&setup.env, [2, 3]));
+
+    let versions = setup.versions(|| setup.client.release_funds(&1, &setup.contributor));
+    assert_eq!(versions, Vec::from_array(&setup.env, [2, 3]));
+}
+
+#[test]
+fn test_v3_lifecycle_events_have_party_topics() {
+    let setup = SchemaSetup::new();
+    setup.client.set_event_schema(&EventSchema::V3);
+
+    let versions = setup.versions(|| setup.lock(1, 1_000));
+    assert_eq!(versions, Vec::from_array(&setup.env, [3]));
+    let (topics, event) = setup.last_v3();
+    assert_eq!(event.kind, BountyEventKind::Locked);
+    assert_eq!(event.depositor, setup.depositor);
+    assert_eq!(event.recipient, None);
+    assert_eq!(topics.len(), 4);
+    let kind_topic = Symbol::try_from_val(&setup.env, &topics.get(0).unwrap()).unwrap();
+    assert_eq!(kind_topic, symbol_short!("lock_v3"));
+    let depositor_topic = Address::try_from_val(&setup.env, &topics.get(2).unwrap()).unwrap();
+    assert_eq!(depositor_topic, setup.depositor);
+
+    setup.client.partial_release(&1, &setup.contributor, &400);
+    let (topics, event) = setup.last_v3();
+    assert_eq!(event.kind, BountyEventKind::PartiallyReleased);
+    assert_eq!(event.amount, 400);
+    assert_eq!(event.remaining_amount, 600);
+    assert_eq!(event.status, EscrowStatus::Locked);
+    let recipient_topic =
+        Option::<Address>::try_from_val(&setup.env, &topics.get(3).unwrap()).unwrap();
+    assert_eq!(recipient_topic, Some(setup.contributor.clone()));
+
+    setup
+        .client
+        .approve_refund(&1, &600, &setup.depositor, &RefundMode::Full);
+    assert_eq!(setup.last_v3().1.kind, BountyEventKind::RefundApproved);
+
+    let versions = setup.versions(|| setup.client.refund(&1));
+    assert_eq!(versions, Vec::from_array(&setup.env, [3]));
+    let (_, event) = setup.last_v3();
+    assert_eq!(event.kind, BountyEventKind::Refunded);
+    assert_eq!(event.actor, Some(setup.admin.clone()));
+    assert_eq!(event.status, EscrowStatus::Refunded);
+
+    // A refund after the deadline is permissionless and names no actor.
+    setup.lock(2, 500);
+    setup.advance(1_001);
+    setup.client.refund(&2);
+    let (_, event) = setup.last_v3();
+    assert_eq!(event.kind, BountyEventKind::Refunded);
+    assert_eq!(event.actor, None);
+}
+
+#[test]
+fn test_v3_claim_events_replace_tuple_topics() {
+    let setup = SchemaSetup::new();
+    setup.client.set_event_schema(&EventSchema::V3);
+    setup.lock(1, 500);
+
+    setup.client.authorize_claim(&1, &setup.contributor);
+    let (_, event) = setup.last_v3();
+    assert_eq!(event.kind, BountyEventKind::ClaimAuthorized);
+    assert_eq!(event.recipient, Some(setup.contributor.clone()));
+
+    let versions = setup.versions(|| setup.client.claim(&1));
+    assert_eq!(versions, Vec::from_array(&setup.env, [3]));
+    let (_, event) = setup.last_v3();
+    assert_eq!(event.kind, BountyEventKind::Claimed);
+    assert_eq!(event.actor, Some(setup.contributor.clone()));
+    assert_eq!(event.status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_v3_config_changes_emit_typed_events() {
+    let setup = SchemaSetup::new();
+    setup.client.set_event_schema(&EventSchema::V3);
+    let v3 = Vec::from_array(&setup.env, [3]);
+
+    assert_eq!(setup.versions(|| setup.client.set_claim_window(&7_200)), v3);
+    assert_eq!(
+        setup.versions(|| setup.client.update_anti_abuse_config(&3_600, &10, &0)),
+        v3
+    );
+    assert_eq!(
+        setup.versions(|| setup.client.update_operation_rate_limit(
+            &RateLimitOperation::Claim,
+            &3_600,
+            &5,
+            &0
+        )),
+        v3
+    );
+    assert_eq!(
+        setup.versions(|| setup
+            .client
+            .clear_operation_rate_limit(&RateLimitOperation::Claim)),
+        v3
+    );
+    assert_eq!(
+        setup.versions(|| setup.client.set_whitelist_entry(&setup.depositor, &true)),
+        v3
+    );
+    assert_eq!(
+        setup.versions(|| setup.client.set_amount_policy(&setup.admin, &1, &10_000)),
+        v3
+    );
+    assert_eq!(
+        setup.versions(|| setup.client.update_metadata(
+            &setup.admin,
+            &9,
+            &42,
+            &7,
+            &String::from_str(&setup.env, "bug"),
+        )),
+        v3
+    );
+}
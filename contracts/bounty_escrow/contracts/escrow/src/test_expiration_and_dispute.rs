@@ -70,7 +70,8 @@ fn test_pending_claim_blocks_refund() {
     let amount = 1000;
     let now = setup.env.ledger().timestamp();
     let deadline = now + 1000;
-    let claim_window = 500;
+    // Long enough for the claim to outlive the deadline.
+    let claim_window = 2_000;
 
     setup.escrow.set_claim_window(&claim_window);
 
@@ -0,0 +1,256 @@
+#![cfg(test)]
+
+use crate::events::FeeOperationType;
+use crate::fees::{FeeOverride, FeeQuote, FeeSchedule, FeeTier};
+use crate::test_setup::EscrowSetup;
+use crate::{Error, LockFundsItem};
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+struct FeeSetup {
+    base: EscrowSetup,
+    treasury: Address,
+}
+
+impl Deref for FeeSetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl FeeSetup {
+    /// Escrow with fees enabled at a flat 1% lock / 2% release.
+    fn new() -> Self {
+        let base = EscrowSetup::new(10_000_000);
+        let treasury = Address::generate(&base.env);
+        base.client
+            .update_fee_config(&Some(100), &Some(200), &Some(treasury.clone()), &Some(true));
+        Self { base, treasury }
+    }
+
+    fn quote(&self, amount: i128, op: FeeOperationType) -> FeeQuote {
+        self.client.quote_fee(&self.depositor, &amount, &op)
+    }
+
+    fn lock(&self, bounty_id: u64, amount: i128) {
+        self.client
+            .lock_funds(&self.depositor, &bounty_id, &amount, &1_000);
+    }
+}
+
+fn tier(min_amount: i128, lock_fee_rate: i128, release_fee_rate: i128) -> FeeTier {
+    FeeTier {
+        min_amount,
+        lock_fee_rate,
+        release_fee_rate,
+    }
+}
+
+fn schedule(env: &Env, min_fee: i128, max_fee: Option<i128>) -> FeeSchedule {
+    FeeSchedule {
+        tiers: vec![env, tier(1_000, 100, 200), tier(100_000, 50, 100)],
+        min_fee,
+        max_fee,
+    }
+}
+
+#[test]
+fn test_tier_selected_by_amount() {
+    let setup = FeeSetup::new();
+    setup.client.set_fee_schedule(&Some(FeeSchedule {
+        tiers: vec![&setup.env, tier(1_000, 300, 300), tier(100_000, 50, 100)],
+        min_fee: 0,
+        max_fee: None,
+    }));
+
+    // Below the first tier the flat config applies.
+    assert_eq!(setup.quote(999, FeeOperationType::Lock).fee_rate, 100);
+    assert_eq!(setup.quote(1_000, FeeOperationType::Lock).fee_rate, 300);
+    assert_eq!(setup.quote(99_999, FeeOperationType::Lock).fee_rate, 300);
+    assert_eq!(setup.quote(100_000, FeeOperationType::Lock).fee_rate, 50);
+    assert_eq!(
+        setup.quote(100_000, FeeOperationType::Release).fee_rate,
+        100
+    );
+
+    // Floor rounding: 1_999 * 3% = 59.97.
+    let quote = setup.quote(1_999, FeeOperationType::Lock);
+    assert_eq!((quote.fee, quote.net), (59, 1_940));
+}
+
+#[test]
+fn test_override_takes_precedence() {
+    let setup = FeeSetup::new();
+    setup
+        .client
+        .set_fee_schedule(&Some(schedule(&setup.env, 0, None)));
+    setup.client.set_fee_override(
+        &setup.depositor,
+        &Some(FeeOverride {
+            lock_fee_rate: 0,
+            release_fee_rate: 10,
+        }),
+    );
+
+    let quote = setup.quote(200_000, FeeOperationType::Lock);
+    assert!(quote.overridden);
+    assert_eq!(quote.fee, 0);
+    assert_eq!(setup.quote(200_000, FeeOperationType::Release).fee, 200);
+
+    let other = Address::generate(&setup.env);
+    let quote = setup
+        .client
+        .quote_fee(&other, &200_000, &FeeOperationType::Lock);
+    assert!(!quote.overridden);
+    assert_eq!(quote.fee, 1_000);
+
+    setup.client.set_fee_override(&setup.depositor, &None);
+    assert_eq!(setup.client.get_fee_override(&setup.depositor), None);
+    assert_eq!(setup.quote(200_000, FeeOperationType::Lock).fee, 1_000);
+}
+
+#[test]
+fn test_min_and_max_fee_caps() {
+    let setup = FeeSetup::new();
+    setup
+        .client
+        .set_fee_schedule(&Some(schedule(&setup.env, 25, Some(300))));
+
+    // 1% of 1_000 is 10, raised to the minimum.
+    assert_eq!(setup.quote(1_000, FeeOperationType::Lock).fee, 25);
+    // 0.5% of 1_000_000 is 5_000, lowered to the maximum.
+    assert_eq!(setup.quote(1_000_000, FeeOperationType::Lock).fee, 300);
+    // The minimum never exceeds the amount itself.
+    let quote = setup.quote(10, FeeOperationType::Lock);
+    assert_eq!((quote.fee, quote.net), (10, 0));
+
+    // A deposit the fee would consume entirely is rejected.
+    assert_eq!(
+        setup
+            .client
+            .try_lock_funds(&setup.depositor, &1, &10, &1_000),
+        Err(Ok(Error::InvalidAmount))
+    );
+}
+
+#[test]
+fn test_quote_matches_lock_and_release() {
+    let setup = FeeSetup::new();
+    setup
+        .client
+        .set_fee_schedule(&Some(schedule(&setup.env, 25, Some(300))));
+
+    let lock = setup.quote(50_000, FeeOperationType::Lock);
+    let simulated = setup
+        .client
+        .simulate_lock(&setup.depositor, &1, &50_000, &1_000);
+    setup.lock(1, 50_000);
+    assert_eq!(setup.token.balance(&setup.treasury), lock.fee);
+    assert_eq!(setup.token.balance(&setup.client.address), lock.net);
+    assert_eq!(setup.client.get_escrow_info(&1).amount, lock.net);
+    assert_eq!(simulated.remaining_amount, lock.net);
+
+    let release = setup.quote(lock.net, FeeOperationType::Release);
+    setup.client.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), release.net);
+    assert_eq!(setup.token.balance(&setup.treasury), lock.fee + release.fee);
+    assert_eq!(setup.token.balance(&setup.client.address), 0);
+}
+
+#[test]
+fn test_quote_matches_partial_and_batch() {
+    let setup = FeeSetup::new();
+    let env = &setup.env;
+    setup.client.set_fee_schedule(&Some(schedule(env, 0, None)));
+
+    let items = vec![
+        env,
+        LockFundsItem {
+            bounty_id: 1,
+            depositor: setup.depositor.clone(),
+            amount: 200_000,
+            deadline: 1_000,
+        },
+        LockFundsItem {
+            bounty_id: 2,
+            depositor: setup.depositor.clone(),
+            amount: 5_000,
+            deadline: 1_000,
+        },
+    ];
+    setup.client.batch_lock_funds(&items);
+    let first = setup.quote(200_000, FeeOperationType::Lock);
+    let second = setup.quote(5_000, FeeOperationType::Lock);
+    assert_eq!(setup.client.get_escrow_info(&1).amount, first.net);
+    assert_eq!(setup.client.get_escrow_info(&2).amount, second.net);
+    assert_eq!(setup.token.balance(&setup.treasury), first.fee + second.fee);
+
+    let payout = setup.quote(1_234, FeeOperationType::Release);
+    setup.client.partial_release(&1, &setup.contributor, &1_234);
+    assert_eq!(setup.token.balance(&setup.contributor), payout.net);
+    assert_eq!(
+        setup.client.get_escrow_info(&1).remaining_amount,
+        first.net - 1_234
+    );
+}
+
+#[test]
+fn test_invalid_schedule_is_rejected() {
+    let setup = FeeSetup::new();
+    let env = &setup.env;
+    let client = &setup.client;
+
+    let unsorted = FeeSchedule {
+        tiers: vec![env, tier(5_000, 10, 10), tier(5_000, 20, 20)],
+        min_fee: 0,
+        max_fee: None,
+    };
+    assert_eq!(
+        client.try_set_fee_schedule(&Some(unsorted)),
+        Err(Ok(Error::InvalidFeeSchedule))
+    );
+    assert_eq!(
+        client.try_set_fee_schedule(&Some(schedule(env, 500, Some(100)))),
+        Err(Ok(Error::InvalidFeeSchedule))
+    );
+    let too_high = FeeSchedule {
+        tiers: vec![env, tier(0, 10_001, 0)],
+        min_fee: 0,
+        max_fee: None,
+    };
+    assert_eq!(
+        client.try_set_fee_schedule(&Some(too_high)),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+    assert_eq!(
+        client.try_set_fee_override(
+            &setup.depositor,
+            &Some(FeeOverride {
+                lock_fee_rate: -1,
+                release_fee_rate: 0,
+            })
+        ),
+        Err(Ok(Error::InvalidFeeRate))
+    );
+    assert_eq!(client.get_fee_schedule(), None);
+}
+
+#[test]
+fn test_disabled_fees_quote_zero() {
+    let setup = FeeSetup::new();
+    setup
+        .client
+        .set_fee_schedule(&Some(schedule(&setup.env, 25, None)));
+    setup
+        .client
+        .update_fee_config(&None, &None, &None, &Some(false));
+
+    let quote = setup.quote(50_000, FeeOperationType::Lock);
+    assert_eq!((quote.fee, quote.net, quote.fee_rate), (0, 50_000, 0));
+
+    setup.lock(1, 50_000);
+    assert_eq!(setup.client.get_escrow_info(&1).amount, 50_000);
+    assert_eq!(setup.token.balance(&setup.treasury), 0);
+}
@@ -0,0 +1,168 @@
+#![cfg(test)]
+
+use crate::fees::FeeSplit;
+use crate::test_setup::EscrowSetup;
+use crate::Error;
+use core::ops::Deref;
+use soroban_sdk::{testutils::Address as _, Address, String};
+
+struct SplitSetup {
+    base: EscrowSetup,
+    platform: Address,
+}
+
+impl Deref for SplitSetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl SplitSetup {
+    /// Escrow with a flat 10% lock fee and a 70/20/10 split.
+    fn new() -> Self {
+        let base = EscrowSetup::new(1_000_000);
+        let platform = Address::generate(&base.env);
+        base.client
+            .update_fee_config(&Some(1_000), &Some(0), &Some(platform.clone()), &Some(true));
+        base.client.set_fee_split(&Some(FeeSplit {
+            platform_bps: 7_000,
+            maintainer_bps: 2_000,
+            referrer_bps: 1_000,
+        }));
+
+        Self { base, platform }
+    }
+
+    fn tag_repo(&self, bounty_id: u64, repo_id: u64) {
+        self.client.update_metadata(
+            &self.admin,
+            &bounty_id,
+            &repo_id,
+            &1,
+            &String::from_str(&self.env, "bug"),
+        );
+    }
+}
+
+#[test]
+fn test_fee_shares_accrue_to_each_party() {
+    let setup = SplitSetup::new();
+    let treasury = Address::generate(&setup.env);
+    let referrer = Address::generate(&setup.env);
+    setup.client.set_repo_treasury(&42, &Some(treasury.clone()));
+    setup.tag_repo(1, 42);
+
+    setup
+        .client
+        .lock_funds_with_referrer(&setup.depositor, &1, &10_110, &1_000, &referrer);
+    assert_eq!(setup.client.get_referrer(&1), Some(referrer.clone()));
+
+    // Fee 1_011 split 707.7 / 202.2 / 101.1: the leftover unit goes to the
+    // platform's 0.7.
+    assert_eq!(setup.client.get_fee_balance(&setup.platform), 708);
+    assert_eq!(setup.client.get_fee_balance(&treasury), 202);
+    assert_eq!(setup.client.get_fee_balance(&referrer), 101);
+    assert_eq!(setup.token.balance(&setup.platform), 0);
+
+    let solvency = setup.client.verify_solvency();
+    assert_eq!(solvency.unclaimed_fees, 1_011);
+    assert_eq!(solvency.total_liabilities, 9_099);
+    assert_eq!(solvency.surplus, 0);
+}
+
+#[test]
+fn test_unresolved_shares_go_to_platform() {
+    let setup = SplitSetup::new();
+    // No metadata and no referrer.
+    setup
+        .client
+        .lock_funds(&setup.depositor, &1, &10_000, &1_000);
+    assert_eq!(setup.client.get_fee_balance(&setup.platform), 1_000);
+
+    // Metadata naming a repository without a treasury.
+    setup.tag_repo(2, 7);
+    setup
+        .client
+        .lock_funds(&setup.depositor, &2, &10_000, &1_000);
+    assert_eq!(setup.client.get_fee_balance(&setup.platform), 2_000);
+}
+
+#[test]
+fn test_release_fee_shares_and_withdrawal() {
+    let setup = SplitSetup::new();
+    let treasury = Address::generate(&setup.env);
+    setup.client.set_repo_treasury(&42, &Some(treasury.clone()));
+    setup
+        .client
+        .update_fee_config(&Some(0), &Some(500), &None, &None);
+    setup.tag_repo(1, 42);
+    setup
+        .client
+        .lock_funds(&setup.depositor, &1, &10_000, &1_000);
+
+    setup.client.release_funds(&1, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 9_500);
+    assert_eq!(setup.client.get_fee_balance(&treasury), 100);
+    assert_eq!(setup.token.balance(&setup.client.address), 500);
+
+    assert_eq!(setup.client.withdraw_fees(&treasury), 100);
+    assert_eq!(setup.token.balance(&treasury), 100);
+    assert_eq!(setup.client.get_fee_balance(&treasury), 0);
+    assert_eq!(
+        setup.client.try_withdraw_fees(&treasury),
+        Err(Ok(Error::NothingToWithdraw))
+    );
+
+    assert_eq!(setup.client.withdraw_fees(&setup.platform), 400);
+    let solvency = setup.client.verify_solvency();
+    assert_eq!(solvency.token_balance, 0);
+    assert_eq!(solvency.unclaimed_fees, 0);
+    assert!(solvency.is_solvent);
+}
+
+#[test]
+fn test_invalid_split_and_self_referral_are_rejected() {
+    let setup = SplitSetup::new();
+    assert_eq!(
+        setup.client.try_set_fee_split(&Some(FeeSplit {
+            platform_bps: 7_000,
+            maintainer_bps: 2_000,
+            referrer_bps: 999,
+        })),
+        Err(Ok(Error::InvalidFeeSplit))
+    );
+    assert_eq!(
+        setup.client.try_set_fee_split(&Some(FeeSplit {
+            platform_bps: 11_000,
+            maintainer_bps: 0,
+            referrer_bps: -1_000,
+        })),
+        Err(Ok(Error::InvalidFeeSplit))
+    );
+    assert_eq!(
+        setup.client.try_lock_funds_with_referrer(
+            &setup.depositor,
+            &1,
+            &10_000,
+            &1_000,
+            &setup.depositor
+        ),
+        Err(Ok(Error::InvalidReferrer))
+    );
+}
+
+#[test]
+fn test_cleared_split_pays_recipient_directly() {
+    let setup = SplitSetup::new();
+    setup.client.set_fee_split(&None);
+    assert_eq!(setup.client.get_fee_split(), None);
+
+    setup
+        .client
+        .lock_funds(&setup.depositor, &1, &10_000, &1_000);
+    assert_eq!(setup.token.balance(&setup.platform), 1_000);
+    assert_eq!(setup.client.get_fee_balance(&setup.platform), 0);
+    assert_eq!(setup.client.verify_solvency().unclaimed_fees, 0);
+}
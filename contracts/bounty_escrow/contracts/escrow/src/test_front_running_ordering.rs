@@ -106,7 +106,7 @@ fn test_release_race_first_recipient_wins_order_ba() {
 }
 
 #[test]
-fn test_authorize_claim_race_last_authorization_wins() {
+fn test_authorize_claim_race_first_authorization_holds() {
     let setup = TestSetup::new();
     let bounty_id = 9103_u64;
     let amount = 90_000_i128;
@@ -120,20 +120,21 @@ fn test_authorize_claim_race_last_authorization_wins() {
         .lock_funds(&setup.depositor, &bounty_id, &amount, &deadline);
 
     setup.escrow.authorize_claim(&bounty_id, &claimant_a);
-    setup.escrow.authorize_claim(&bounty_id, &claimant_b);
+    let second = setup.escrow.try_authorize_claim(&bounty_id, &claimant_b);
+    assert_eq!(second, Err(Ok(Error::ClaimPending)));
 
     let pending = setup.escrow.get_pending_claim(&bounty_id);
-    assert_eq!(pending.recipient, claimant_b);
+    assert_eq!(pending.recipient, claimant_a);
     assert_eq!(pending.amount, amount);
 
     setup.escrow.claim(&bounty_id);
 
-    assert_eq!(setup.token.balance(&claimant_a), 0);
-    assert_eq!(setup.token.balance(&claimant_b), amount);
+    assert_eq!(setup.token.balance(&claimant_a), amount);
+    assert_eq!(setup.token.balance(&claimant_b), 0);
     assert_eq!(setup.token.balance(&setup.escrow.address), 0);
 
     let second_claim = setup.escrow.try_claim(&bounty_id);
-    assert_eq!(second_claim, Err(Ok(Error::FundsNotLocked)));
+    assert_eq!(second_claim, Err(Ok(Error::ClaimAlreadyExecuted)));
 }
 
 // Auto-refund race: multiple parties try to trigger refund after deadline
@@ -93,3 +93,63 @@ fn test_invariant_checker_ci_panics_when_disabled() {
 
     client.lock_funds(&depositor, &7_u64, &5_000_i128, &500);
 }
+
+#[test]
+fn test_transition_rejects_moves_outside_table() {
+    use crate::invariants::StatusAction;
+
+    assert_eq!(
+        invariants::transition(
+            &EscrowStatus::Locked,
+            &EscrowStatus::Released,
+            StatusAction::Claim
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        invariants::transition(
+            &EscrowStatus::Locked,
+            &EscrowStatus::Locked,
+            StatusAction::PartialRelease
+        ),
+        Ok(())
+    );
+    assert_eq!(
+        invariants::transition(
+            &EscrowStatus::Released,
+            &EscrowStatus::Locked,
+            StatusAction::Unfreeze
+        ),
+        Err(Error::InvalidStatusTransition)
+    );
+    assert_eq!(
+        invariants::transition(
+            &EscrowStatus::Frozen,
+            &EscrowStatus::Released,
+            StatusAction::Release
+        ),
+        Err(Error::InvalidStatusTransition)
+    );
+    // A release may not end in a refund status even from a valid start.
+    assert_eq!(
+        invariants::transition(
+            &EscrowStatus::Locked,
+            &EscrowStatus::Refunded,
+            StatusAction::Release
+        ),
+        Err(Error::InvalidStatusTransition)
+    );
+
+    assert!(invariants::allows(
+        &EscrowStatus::PartiallyRefunded,
+        StatusAction::Refund
+    ));
+    assert!(!invariants::allows(
+        &EscrowStatus::PartiallyRefunded,
+        StatusAction::PartialRelease
+    ));
+    assert!(!invariants::allows(
+        &EscrowStatus::Refunded,
+        StatusAction::Freeze
+    ));
+}
@@ -0,0 +1,319 @@
+#![cfg(test)]
+
+use crate::test_setup::EscrowSetup;
+use crate::{
+    AuditAction, Error, EscrowStatus, LegacyEscrow, LegacyEscrowState, LegacyEscrowStatus,
+};
+use core::ops::Deref;
+use soroban_sdk::{
+    contract, contractimpl,
+    testutils::{Address as _, Ledger},
+    vec, Address, Env, String, Vec,
+};
+
+/// Stand-in for the legacy escrow contract: serves the records it holds
+/// through `get_escrow` and closes them on `mark_migrated`. It holds no
+/// tokens, so the funder pays imports out of its own balance. The run
+/// against the real one is `test_bounty_escrow_import` in
+/// soroban/contracts/escrow.
+#[contract]
+struct MockLegacyEscrow;
+
+#[contractimpl]
+impl MockLegacyEscrow {
+    pub fn hold(env: Env, escrow: LegacyEscrow) {
+        let state = LegacyEscrowState {
+            depositor: escrow.depositor,
+            amount: escrow.amount,
+            remaining_amount: escrow.remaining_amount,
+            status: escrow.status,
+            deadline: escrow.deadline,
+        };
+        env.storage().persistent().set(&escrow.bounty_id, &state);
+    }
+
+    pub fn get_escrow(env: Env, bounty_id: u64) -> LegacyEscrowState {
+        env.storage().persistent().get(&bounty_id).unwrap()
+    }
+
+    pub fn mark_migrated(env: Env, bounty_ids: Vec<u64>, _to: Address) -> i128 {
+        let mut total = 0;
+        for bounty_id in bounty_ids.iter() {
+            let mut state = Self::get_escrow(env.clone(), bounty_id);
+            total += state.remaining_amount;
+            state.remaining_amount = 0;
+            state.status = LegacyEscrowStatus::Migrated;
+            env.storage().persistent().set(&bounty_id, &state);
+        }
+        total
+    }
+}
+
+struct ImportSetup {
+    base: EscrowSetup,
+    /// Pays in the imported balances; the fixture's depositor.
+    funder: Address,
+    legacy: MockLegacyEscrowClient<'static>,
+    source: Address,
+}
+
+impl Deref for ImportSetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl ImportSetup {
+    /// The funder holds 10_000 for the migration.
+    fn new() -> Self {
+        let base = EscrowSetup::new(10_000);
+        let source = base.env.register_contract(None, MockLegacyEscrow);
+        Self {
+            funder: base.depositor.clone(),
+            legacy: MockLegacyEscrowClient::new(&base.env, &source),
+            source,
+            base,
+        }
+    }
+
+    /// A record as the legacy contract's `export_all` returns it, held by
+    /// the legacy contract.
+    fn exported(
+        &self,
+        bounty_id: u64,
+        amount: i128,
+        remaining_amount: i128,
+        status: LegacyEscrowStatus,
+        deadline: u64,
+    ) -> LegacyEscrow {
+        let escrow = LegacyEscrow {
+            bounty_id,
+            depositor: Address::generate(&self.env),
+            amount,
+            remaining_amount,
+            status,
+            deadline,
+        };
+        self.legacy.hold(&escrow);
+        escrow
+    }
+
+    fn import(&self, escrows: &Vec<LegacyEscrow>) -> u32 {
+        self.client
+            .import_escrows(&self.source, &self.funder, escrows)
+    }
+}
+
+#[test]
+fn test_import_preserves_legacy_escrows() {
+    let setup = ImportSetup::new();
+    let now = setup.env.ledger().timestamp();
+    let first = setup.exported(7, 1_000, 1_000, LegacyEscrowStatus::Locked, now + 500);
+    let second = setup.exported(42, 2_000, 1_500, LegacyEscrowStatus::Locked, now + 900);
+
+    let imported = setup.import(&vec![&setup.env, first.clone(), second.clone()]);
+    assert_eq!(imported, 2);
+
+    for legacy in [&first, &second] {
+        let escrow = setup.client.get_escrow_info(&legacy.bounty_id);
+        assert_eq!(escrow.depositor, legacy.depositor);
+        assert_eq!(escrow.amount, legacy.amount);
+        assert_eq!(escrow.remaining_amount, legacy.remaining_amount);
+        assert_eq!(escrow.deadline, legacy.deadline);
+        assert_eq!(escrow.status, EscrowStatus::Locked);
+
+        let metadata = setup.client.get_metadata(&legacy.bounty_id);
+        assert_eq!(
+            metadata.bounty_type,
+            String::from_str(&setup.env, "legacy_import")
+        );
+
+        let record = setup.client.get_import_record(&legacy.bounty_id).unwrap();
+        assert_eq!(record.source, setup.source);
+        assert_eq!(record.imported_by, setup.admin);
+        assert_eq!(record.imported_at, now);
+
+        let log = setup.client.get_audit_log(&legacy.bounty_id, &0, &10);
+        assert_eq!(log.entries.len(), 1);
+        let entry = log.entries.get(0).unwrap();
+        assert_eq!(entry.action, AuditAction::Imported);
+        assert_eq!(entry.amount, legacy.remaining_amount);
+        assert_eq!(entry.counterparty, Some(legacy.depositor.clone()));
+    }
+
+    // Only the remaining amounts are paid in.
+    assert_eq!(setup.token.balance(&setup.client.address), 2_500);
+    assert_eq!(setup.token.balance(&setup.funder), 7_500);
+    assert!(setup.client.get_import_record(&1).is_none());
+
+    let by_depositor = setup
+        .client
+        .query_escrows_by_depositor(&second.depositor, &0, &10);
+    assert_eq!(by_depositor.len(), 1);
+    assert_eq!(by_depositor.get(0).unwrap().bounty_id, 42);
+}
+
+#[test]
+fn test_import_refuses_double_import() {
+    let setup = ImportSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 500;
+    let legacy = setup.exported(7, 1_000, 1_000, LegacyEscrowStatus::Locked, deadline);
+    setup.import(&vec![&setup.env, legacy.clone()]);
+
+    let result =
+        setup
+            .client
+            .try_import_escrows(&setup.source, &setup.funder, &vec![&setup.env, legacy]);
+    assert_eq!(result, Err(Ok(Error::BountyExists)));
+    assert_eq!(setup.token.balance(&setup.client.address), 1_000);
+}
+
+#[test]
+fn test_import_closes_escrows_on_the_source() {
+    let setup = ImportSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 500;
+    let legacy = setup.exported(7, 1_000, 800, LegacyEscrowStatus::Locked, deadline);
+    setup.import(&vec![&setup.env, legacy]);
+
+    let closed = setup.legacy.get_escrow(&7);
+    assert_eq!(closed.status, LegacyEscrowStatus::Migrated);
+    assert_eq!(closed.remaining_amount, 0);
+}
+
+#[test]
+fn test_import_rejects_records_the_source_does_not_hold() {
+    let setup = ImportSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 500;
+    let held = setup.exported(7, 1_000, 1_000, LegacyEscrowStatus::Locked, deadline);
+
+    let mut inflated = held.clone();
+    inflated.remaining_amount = 900;
+    let mut redirected = held.clone();
+    redirected.depositor = setup.funder.clone();
+    let mut unknown = held;
+    unknown.bounty_id = 8;
+
+    for forged in [inflated, redirected, unknown] {
+        let result = setup.client.try_import_escrows(
+            &setup.source,
+            &setup.funder,
+            &vec![&setup.env, forged],
+        );
+        assert_eq!(result, Err(Ok(Error::ImportMismatch)));
+    }
+
+    // A source that is not an escrow contract at all.
+    let stranger = setup.exported(9, 1_000, 1_000, LegacyEscrowStatus::Locked, deadline);
+    let result = setup.client.try_import_escrows(
+        &Address::generate(&setup.env),
+        &setup.funder,
+        &vec![&setup.env, stranger],
+    );
+    assert_eq!(result, Err(Ok(Error::ImportMismatch)));
+    assert_eq!(setup.token.balance(&setup.client.address), 0);
+}
+
+#[test]
+fn test_import_refuses_existing_bounty_id() {
+    let setup = ImportSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 500;
+    setup
+        .client
+        .lock_funds(&setup.funder, &7, &1_000, &deadline);
+
+    let legacy = setup.exported(7, 1_000, 1_000, LegacyEscrowStatus::Locked, deadline);
+    let result =
+        setup
+            .client
+            .try_import_escrows(&setup.source, &setup.funder, &vec![&setup.env, legacy]);
+    assert_eq!(result, Err(Ok(Error::BountyExists)));
+}
+
+#[test]
+fn test_import_rejects_settled_and_malformed_records() {
+    let setup = ImportSetup::new();
+    let now = setup.env.ledger().timestamp();
+    let deadline = now + 500;
+    let locked = setup.exported(1, 1_000, 1_000, LegacyEscrowStatus::Locked, deadline);
+
+    for (bad, error) in [
+        (
+            setup.exported(2, 1_000, 0, LegacyEscrowStatus::Released, deadline),
+            Error::FundsNotLocked,
+        ),
+        (
+            setup.exported(2, 1_000, 0, LegacyEscrowStatus::Refunded, deadline),
+            Error::FundsNotLocked,
+        ),
+        (
+            setup.exported(2, 1_000, 1_001, LegacyEscrowStatus::Locked, deadline),
+            Error::InvalidAmount,
+        ),
+        (
+            setup.exported(2, 1_000, 1_000, LegacyEscrowStatus::Locked, now),
+            Error::InvalidDeadline,
+        ),
+        (locked.clone(), Error::DuplicateBountyId),
+    ] {
+        // The bad records share a bounty id; the source holds each in turn.
+        setup.legacy.hold(&bad);
+        let result = setup.client.try_import_escrows(
+            &setup.source,
+            &setup.funder,
+            &vec![&setup.env, locked.clone(), bad],
+        );
+        assert_eq!(result, Err(Ok(error)));
+    }
+
+    // Nothing from the rejected batches was written or paid in.
+    assert!(setup.client.try_get_escrow_info(&1).is_err());
+    assert!(setup.client.get_import_record(&1).is_none());
+    assert_eq!(setup.token.balance(&setup.client.address), 0);
+
+    let empty: Vec<LegacyEscrow> = vec![&setup.env];
+    let result = setup
+        .client
+        .try_import_escrows(&setup.source, &setup.funder, &empty);
+    assert_eq!(result, Err(Ok(Error::InvalidBatchSize)));
+}
+
+#[test]
+fn test_import_proceeds_while_locks_are_paused() {
+    let setup = ImportSetup::new();
+    setup.client.set_paused(&Some(true), &None, &None, &None);
+    let deadline = setup.env.ledger().timestamp() + 500;
+    let legacy = setup.exported(7, 1_000, 1_000, LegacyEscrowStatus::Locked, deadline);
+
+    assert_eq!(setup.import(&vec![&setup.env, legacy]), 1);
+    assert_eq!(
+        setup.client.get_escrow_info(&7).status,
+        EscrowStatus::Locked
+    );
+    assert_eq!(
+        setup
+            .client
+            .try_lock_funds(&setup.funder, &8, &1_000, &deadline),
+        Err(Ok(Error::FundsPaused))
+    );
+}
+
+#[test]
+fn test_imported_escrow_refunds_after_legacy_deadline() {
+    let setup = ImportSetup::new();
+    let deadline = setup.env.ledger().timestamp() + 500;
+    let legacy = setup.exported(7, 1_000, 600, LegacyEscrowStatus::Locked, deadline);
+    setup.import(&vec![&setup.env, legacy.clone()]);
+
+    assert!(setup.client.try_refund(&7).is_err());
+
+    setup.env.ledger().set_timestamp(deadline + 1);
+    setup.client.refund(&7);
+    assert_eq!(setup.token.balance(&legacy.depositor), 600);
+    assert_eq!(setup.token.balance(&setup.client.address), 0);
+    assert_eq!(
+        setup.client.get_escrow_info(&7).status,
+        EscrowStatus::Refunded
+    );
+}
@@ -0,0 +1,205 @@
+#![cfg(test)]
+
+use crate::test_setup::EscrowSetup;
+use crate::{ClaimAuthorizationItem, Error, EscrowStatus};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, AuthorizedFunction, Ledger},
+    vec, Address, Symbol,
+};
+
+struct ClaimSetup {
+    base: EscrowSetup,
+}
+
+impl Deref for ClaimSetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl ClaimSetup {
+    /// Bounty 1 holds 1_000 with a one-hour claim window.
+    fn new() -> Self {
+        let base = EscrowSetup::with_claim_window(1_000);
+        base.client.lock_funds(&base.depositor, &1, &1_000, &10_000);
+        Self { base }
+    }
+}
+
+#[test]
+fn test_claim_after_partial_release_covers_remaining_only() {
+    let setup = ClaimSetup::new();
+    let other = Address::generate(&setup.env);
+    setup.client.partial_release(&1, &other, &400);
+
+    setup.client.authorize_claim(&1, &setup.contributor);
+    assert_eq!(setup.client.get_pending_claim(&1).amount, 600);
+
+    setup.client.claim(&1);
+    assert_eq!(setup.token.balance(&setup.contributor), 600);
+    assert_eq!(
+        setup.client.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    assert!(setup.client.verify_solvency().is_solvent);
+}
+
+#[test]
+fn test_sequential_partial_claims() {
+    let setup = ClaimSetup::new();
+    let second = Address::generate(&setup.env);
+
+    setup
+        .client
+        .authorize_partial_claim(&1, &setup.contributor, &300);
+    setup.client.claim(&1);
+    let escrow = setup.client.get_escrow_info(&1);
+    assert_eq!(escrow.status, EscrowStatus::Locked);
+    assert_eq!(escrow.remaining_amount, 700);
+    assert_eq!(
+        setup.client.try_claim(&1),
+        Err(Ok(Error::ClaimAlreadyExecuted))
+    );
+
+    setup.client.authorize_partial_claim(&1, &second, &700);
+    assert_eq!(setup.client.simulate_claim(&1).remaining_amount, 0);
+    setup.client.claim(&1);
+    assert_eq!(setup.token.balance(&setup.contributor), 300);
+    assert_eq!(setup.token.balance(&second), 700);
+    assert_eq!(
+        setup.client.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+
+    let stats = setup.client.get_contributor_stats(&second);
+    assert_eq!((stats.partial_payouts, stats.bounties_completed), (1, 1));
+}
+
+#[test]
+fn test_claim_amount_is_bounded_by_remaining() {
+    let setup = ClaimSetup::new();
+    assert_eq!(
+        setup
+            .client
+            .try_authorize_partial_claim(&1, &setup.contributor, &1_001),
+        Err(Ok(Error::InsufficientFunds))
+    );
+    assert_eq!(
+        setup
+            .client
+            .try_authorize_partial_claim(&1, &setup.contributor, &0),
+        Err(Ok(Error::InvalidAmount))
+    );
+
+    // A release after authorization leaves too little for the claim.
+    setup
+        .client
+        .authorize_partial_claim(&1, &setup.contributor, &800);
+    setup
+        .client
+        .partial_release(&1, &Address::generate(&setup.env), &300);
+    assert_eq!(
+        setup.client.try_claim(&1),
+        Err(Ok(Error::InsufficientFunds))
+    );
+}
+
+#[test]
+fn test_recipient_redirects_payout() {
+    let setup = ClaimSetup::new();
+    let wallet = Address::generate(&setup.env);
+    setup.client.authorize_claim(&1, &setup.contributor);
+
+    setup.client.redirect_claim(&1, &wallet);
+    let (signer, invocation) = setup.env.auths().pop().unwrap();
+    assert_eq!(signer, setup.contributor);
+    let redirect = Symbol::new(&setup.env, "redirect_claim");
+    assert!(matches!(
+        invocation.function,
+        AuthorizedFunction::Contract((_, ref name, _)) if *name == redirect
+    ));
+    assert_eq!(setup.client.get_claim_payout_address(&1), wallet);
+
+    setup.client.claim(&1);
+    assert_eq!(setup.token.balance(&wallet), 1_000);
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+    // The contributor is still the one credited with the bounty.
+    assert_eq!(
+        setup
+            .client
+            .get_contributor_stats(&setup.contributor)
+            .bounties_completed,
+        1
+    );
+}
+
+#[test]
+fn test_live_claim_cannot_be_replaced() {
+    let setup = ClaimSetup::new();
+    let wallet = Address::generate(&setup.env);
+    let other = Address::generate(&setup.env);
+    setup
+        .client
+        .authorize_partial_claim(&1, &setup.contributor, &400);
+    setup.client.redirect_claim(&1, &wallet);
+
+    assert_eq!(
+        setup.client.try_authorize_claim(&1, &other),
+        Err(Ok(Error::ClaimPending))
+    );
+    assert_eq!(
+        setup.client.try_authorize_partial_claim(&1, &other, &100),
+        Err(Ok(Error::ClaimPending))
+    );
+    let items = vec![
+        &setup.env,
+        ClaimAuthorizationItem {
+            bounty_id: 1,
+            recipient: other.clone(),
+        },
+    ];
+    assert_eq!(
+        setup.client.try_batch_authorize_claims(&items),
+        Err(Ok(Error::ClaimPending))
+    );
+
+    // The original claim and its redirect are untouched.
+    let pending = setup.client.get_pending_claim(&1);
+    assert_eq!(pending.recipient, setup.contributor);
+    assert_eq!(pending.amount, 400);
+    assert_eq!(setup.client.get_claim_payout_address(&1), wallet);
+
+    // Once it is executed, a new claim can be authorised.
+    setup.client.claim(&1);
+    assert_eq!(setup.token.balance(&wallet), 400);
+    setup.client.batch_authorize_claims(&items);
+    assert_eq!(setup.client.get_pending_claim(&1).recipient, other);
+}
+
+#[test]
+fn test_expired_and_executed_claims_have_dedicated_errors() {
+    let setup = ClaimSetup::new();
+    setup.client.authorize_claim(&1, &setup.contributor);
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 3_601);
+
+    assert_eq!(setup.client.try_claim(&1), Err(Ok(Error::ClaimExpired)));
+    assert_eq!(
+        setup
+            .client
+            .try_redirect_claim(&1, &Address::generate(&setup.env)),
+        Err(Ok(Error::ClaimExpired))
+    );
+
+    setup.client.authorize_claim(&1, &setup.contributor);
+    setup.client.claim(&1);
+    assert_eq!(
+        setup.client.try_cancel_pending_claim(&1),
+        Err(Ok(Error::ClaimAlreadyExecuted))
+    );
+}
@@ -0,0 +1,262 @@
+#![cfg(test)]
+
+use crate::events::HookFailed;
+use crate::test_setup::EscrowSetup;
+use crate::{CapabilityAction, Error, EscrowStatus, PartialReleaseItem, ReleaseFundsItem};
+use core::ops::Deref;
+use escrow_hook_mock::{EscrowHookMock, EscrowHookMockClient, HookCall, HookMode};
+use soroban_sdk::{
+    symbol_short,
+    testutils::{Address as _, Events as _, Ledger},
+    vec, Address, Symbol, TryFromVal,
+};
+
+struct HookSetup {
+    base: EscrowSetup,
+    delegate: Address,
+}
+
+impl Deref for HookSetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl HookSetup {
+    fn new() -> Self {
+        let base = EscrowSetup::with_claim_window(100_000);
+        let delegate = Address::generate(&base.env);
+        Self { base, delegate }
+    }
+
+    fn hook(&self, mode: HookMode) -> EscrowHookMockClient<'static> {
+        let hook_id = self.env.register_contract(None, EscrowHookMock);
+        let hook = EscrowHookMockClient::new(&self.env, &hook_id);
+        hook.init(&self.client.address, &mode);
+        self.client.register_hook(&hook_id);
+        hook
+    }
+
+    /// Single-use capability for `delegate`, issued by `owner`.
+    fn capability(
+        &self,
+        owner: &Address,
+        action: CapabilityAction,
+        bounty_id: u64,
+        amount: i128,
+    ) -> u64 {
+        let expiry = self.env.ledger().timestamp() + 3_000;
+        self.client.issue_capability(
+            owner,
+            &self.delegate,
+            &action,
+            &bounty_id,
+            &amount,
+            &expiry,
+            &1,
+        )
+    }
+
+    fn call(&self, kind: Symbol, bounty_id: u64, amount: i128) -> HookCall {
+        HookCall {
+            kind,
+            bounty_id,
+            recipient: self.contributor.clone(),
+            amount,
+        }
+    }
+
+    /// `hook_fail` events published so far.
+    fn hook_failures(&self) -> soroban_sdk::Vec<HookFailed> {
+        let mut found = soroban_sdk::Vec::new(&self.env);
+        for (_contract, topics, data) in self.env.events().all().iter() {
+            let first = topics
+                .get(0)
+                .and_then(|t| Symbol::try_from_val(&self.env, &t).ok());
+            if first == Some(symbol_short!("hook_fail")) {
+                found.push_back(HookFailed::try_from_val(&self.env, &data).unwrap());
+            }
+        }
+        found
+    }
+}
+
+#[test]
+fn test_hook_is_notified_on_every_payout_path() {
+    let setup = HookSetup::new();
+    let hook = setup.hook(HookMode::Record);
+    for id in 1..=7 {
+        setup.lock(id, 1_000);
+    }
+
+    setup.client.release_funds(&1, &setup.contributor);
+    setup.client.partial_release(&2, &setup.contributor, &300);
+    setup.client.authorize_claim(&3, &setup.contributor);
+    setup.client.claim(&3);
+    setup.client.batch_release_funds(&vec![
+        &setup.env,
+        ReleaseFundsItem {
+            bounty_id: 4,
+            contributor: setup.contributor.clone(),
+        },
+    ]);
+    setup.client.batch_partial_release(&vec![
+        &setup.env,
+        PartialReleaseItem {
+            bounty_id: 5,
+            contributor: setup.contributor.clone(),
+            amount: 200,
+        },
+    ]);
+    let release_cap = setup.capability(&setup.admin, CapabilityAction::Release, 6, 250);
+    setup.client.release_with_capability(
+        &6,
+        &setup.contributor,
+        &250,
+        &setup.delegate,
+        &release_cap,
+    );
+    setup.client.authorize_claim(&7, &setup.contributor);
+    let claim_cap = setup.capability(&setup.contributor, CapabilityAction::Claim, 7, 1_000);
+    setup
+        .client
+        .claim_with_capability(&7, &setup.delegate, &claim_cap);
+
+    assert_eq!(
+        hook.calls(),
+        vec![
+            &setup.env,
+            setup.call(symbol_short!("release"), 1, 1_000),
+            setup.call(symbol_short!("partial"), 2, 300),
+            setup.call(symbol_short!("claim"), 3, 1_000),
+            setup.call(symbol_short!("release"), 4, 1_000),
+            setup.call(symbol_short!("partial"), 5, 200),
+            setup.call(symbol_short!("partial"), 6, 250),
+            setup.call(symbol_short!("claim"), 7, 1_000),
+        ]
+    );
+    assert!(setup.hook_failures().is_empty());
+}
+
+#[test]
+fn test_refund_does_not_notify_hooks() {
+    let setup = HookSetup::new();
+    let hook = setup.hook(HookMode::Record);
+    setup.lock(1, 1_000);
+    setup
+        .client
+        .approve_refund(&1, &1_000, &setup.depositor, &crate::RefundMode::Full);
+
+    setup.client.refund(&1);
+    assert!(hook.calls().is_empty());
+}
+
+#[test]
+fn test_failing_hook_does_not_block_payout() {
+    let setup = HookSetup::new();
+    let failing = setup.hook(HookMode::Panic);
+    let recording = setup.hook(HookMode::Record);
+    setup.lock(1, 1_000);
+
+    setup.client.release_funds(&1, &setup.contributor);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 1_000);
+    assert_eq!(
+        setup.client.get_escrow_info(&1).status,
+        EscrowStatus::Released
+    );
+    assert!(failing.calls().is_empty());
+    assert_eq!(recording.calls().len(), 1);
+
+    let failures = setup.hook_failures();
+    assert_eq!(failures.len(), 1);
+    let failure = failures.get(0).unwrap();
+    assert_eq!(failure.hook, failing.address);
+    assert_eq!(failure.kind, symbol_short!("release"));
+    assert_eq!(failure.bounty_id, 1);
+}
+
+#[test]
+fn test_reentrant_hook_cannot_touch_escrow() {
+    let setup = HookSetup::new();
+    let hook = setup.hook(HookMode::Reenter);
+    for id in 1..=4 {
+        setup.lock(id, 1_000);
+    }
+    setup
+        .client
+        .authorize_partial_claim(&3, &setup.contributor, &400);
+    let release_cap = setup.capability(&setup.admin, CapabilityAction::Release, 2, 400);
+    let claim_cap = setup.capability(&setup.contributor, CapabilityAction::Claim, 3, 400);
+
+    // Past the deadline, so the refund the hook attempts would otherwise be
+    // valid.
+    setup
+        .env
+        .ledger()
+        .set_timestamp(setup.env.ledger().timestamp() + 1_001);
+
+    setup.client.partial_release(&1, &setup.contributor, &400);
+    setup.client.release_with_capability(
+        &2,
+        &setup.contributor,
+        &400,
+        &setup.delegate,
+        &release_cap,
+    );
+    setup
+        .client
+        .claim_with_capability(&3, &setup.delegate, &claim_cap);
+
+    for id in 1..=3 {
+        let escrow = setup.client.get_escrow_info(&id);
+        assert_eq!(escrow.status, EscrowStatus::Locked);
+        assert_eq!(escrow.remaining_amount, 600);
+        assert!(escrow.refund_history.is_empty());
+    }
+    assert!(hook.calls().is_empty());
+    assert_eq!(setup.hook_failures().len(), 3);
+
+    // The guard was released normally once the payouts finished.
+    hook.set_mode(&HookMode::Record);
+    setup.client.release_funds(&4, &setup.contributor);
+    assert_eq!(setup.token.balance(&setup.contributor), 2_200);
+    assert_eq!(hook.calls().len(), 1);
+}
+
+#[test]
+fn test_hook_registration() {
+    let setup = HookSetup::new();
+    let hook = setup.hook(HookMode::Record);
+
+    assert_eq!(
+        setup.client.get_hooks(),
+        vec![&setup.env, hook.address.clone()]
+    );
+    assert_eq!(
+        setup.client.try_register_hook(&hook.address),
+        Err(Ok(Error::HookAlreadyRegistered))
+    );
+    for _ in 1..crate::hooks::MAX_HOOKS {
+        setup.client.register_hook(&Address::generate(&setup.env));
+    }
+    assert_eq!(
+        setup
+            .client
+            .try_register_hook(&Address::generate(&setup.env)),
+        Err(Ok(Error::TooManyHooks))
+    );
+
+    setup.client.unregister_hook(&hook.address);
+    assert_eq!(
+        setup.client.try_unregister_hook(&hook.address),
+        Err(Ok(Error::HookNotRegistered))
+    );
+    assert_eq!(setup.client.get_hooks().len(), crate::hooks::MAX_HOOKS - 1);
+
+    setup.lock(1, 1_000);
+    setup.client.release_funds(&1, &setup.contributor);
+    assert!(hook.calls().is_empty());
+}
@@ -0,0 +1,203 @@
+#![cfg(test)]
+
+use crate::test_setup::EscrowSetup;
+use crate::{Error, EscrowStatus, RefundMode, ReleaseFundsItem};
+use core::ops::Deref;
+use soroban_sdk::{
+    testutils::{Address as _, Events, Ledger},
+    vec, Address, Env, Symbol, TryFromVal,
+};
+
+const SANCTIONS_CODE: u32 = 7;
+
+struct DenySetup {
+    base: EscrowSetup,
+}
+
+impl Deref for DenySetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl DenySetup {
+    fn new() -> Self {
+        Self {
+            base: EscrowSetup::with_claim_window(100_000),
+        }
+    }
+
+    fn deny(&self, address: &Address) {
+        self.client
+            .add_to_deny_list(&vec![&self.env, address.clone()], &SANCTIONS_CODE);
+    }
+}
+
+fn count_event_topic(env: &Env, topic_name: &str) -> u32 {
+    let expected = Symbol::new(env, topic_name);
+    let mut count = 0;
+    for (_contract, topics, _data) in env.events().all().iter() {
+        if let Some(first) = topics.get(0) {
+            if Symbol::try_from_val(env, &first).ok() == Some(expected.clone()) {
+                count += 1;
+            }
+        }
+    }
+    count
+}
+
+#[test]
+fn test_add_and_remove_deny_list_entries() {
+    let setup = DenySetup::new();
+    let other = Address::generate(&setup.env);
+
+    setup.client.add_to_deny_list(
+        &vec![&setup.env, setup.contributor.clone(), other.clone()],
+        &SANCTIONS_CODE,
+    );
+    assert_eq!(count_event_topic(&setup.env, "deny"), 2);
+    assert!(setup.client.is_recipient_blocked(&setup.contributor));
+    assert!(setup.client.is_recipient_blocked(&other));
+
+    let entry = setup
+        .client
+        .get_deny_list_entry(&setup.contributor)
+        .unwrap();
+    assert_eq!(entry.reason_code, SANCTIONS_CODE);
+    assert_eq!(entry.listed_by, setup.admin);
+
+    setup
+        .client
+        .remove_from_deny_list(&vec![&setup.env, setup.contributor.clone()]);
+    assert!(!setup.client.is_recipient_blocked(&setup.contributor));
+    assert_eq!(setup.client.get_deny_list_entry(&setup.contributor), None);
+    assert!(setup.client.is_recipient_blocked(&other));
+}
+
+#[test]
+fn test_deny_list_rejects_empty_and_oversized_batches() {
+    let setup = DenySetup::new();
+    assert_eq!(
+        setup
+            .client
+            .try_add_to_deny_list(&vec![&setup.env], &SANCTIONS_CODE),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+
+    let mut many = vec![&setup.env];
+    for _ in 0..21 {
+        many.push_back(Address::generate(&setup.env));
+    }
+    assert_eq!(
+        setup.client.try_remove_from_deny_list(&many),
+        Err(Ok(Error::InvalidBatchSize))
+    );
+}
+
+#[test]
+fn test_blocked_recipient_cannot_receive_release_or_partial_release() {
+    let setup = DenySetup::new();
+    setup.lock(1, 1_000);
+    setup.deny(&setup.contributor);
+
+    assert_eq!(
+        setup.client.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::RecipientBlocked))
+    );
+    assert_eq!(
+        setup
+            .client
+            .try_partial_release(&1, &setup.contributor, &100),
+        Err(Ok(Error::RecipientBlocked))
+    );
+    assert_eq!(
+        setup.client.try_batch_release_funds(&vec![
+            &setup.env,
+            ReleaseFundsItem {
+                bounty_id: 1,
+                contributor: setup.contributor.clone(),
+            },
+        ]),
+        Err(Ok(Error::RecipientBlocked))
+    );
+
+    let sim = setup.client.simulate_release(&1, &setup.contributor);
+    assert!(!sim.success);
+    assert_eq!(sim.error_code, Error::RecipientBlocked as u32);
+
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+    assert_eq!(
+        setup.client.get_escrow_info(&1).status,
+        EscrowStatus::Locked
+    );
+}
+
+#[test]
+fn test_blocked_recipient_cannot_be_authorized_or_claim() {
+    let setup = DenySetup::new();
+    setup.lock(1, 1_000);
+    setup.lock(2, 1_000);
+
+    setup.deny(&setup.contributor);
+    assert_eq!(
+        setup.client.try_authorize_claim(&1, &setup.contributor),
+        Err(Ok(Error::RecipientBlocked))
+    );
+
+    setup
+        .client
+        .remove_from_deny_list(&vec![&setup.env, setup.contributor.clone()]);
+    setup.client.authorize_claim(&2, &setup.contributor);
+    setup.deny(&setup.contributor);
+
+    assert_eq!(setup.client.try_claim(&2), Err(Ok(Error::RecipientBlocked)));
+    assert_eq!(setup.token.balance(&setup.contributor), 0);
+}
+
+#[test]
+fn test_pending_claim_of_blocked_recipient_does_not_block_refund() {
+    let setup = DenySetup::new();
+    setup.lock(1, 1_000);
+    setup.client.authorize_claim(&1, &setup.contributor);
+    setup.deny(&setup.contributor);
+
+    setup.env.ledger().set_timestamp(2_000);
+    assert!(setup.client.simulate_refund(&1).success);
+    setup.client.refund(&1);
+
+    assert_eq!(setup.token.balance(&setup.depositor), 100_000);
+    assert_eq!(
+        setup.client.get_escrow_info(&1).status,
+        EscrowStatus::Refunded
+    );
+}
+
+#[test]
+fn test_blocked_refund_recipient_and_emergency_target() {
+    let setup = DenySetup::new();
+    setup.lock(1, 1_000);
+
+    let custom = Address::generate(&setup.env);
+    setup.deny(&custom);
+    assert_eq!(
+        setup
+            .client
+            .try_approve_refund(&1, &500, &custom, &RefundMode::Partial),
+        Err(Ok(Error::RecipientBlocked))
+    );
+
+    setup.deny(&setup.depositor);
+    setup.env.ledger().set_timestamp(2_000);
+    assert_eq!(
+        setup.client.try_refund(&1),
+        Err(Ok(Error::RecipientBlocked))
+    );
+
+    setup.client.set_paused(&Some(true), &None, &None, &None);
+    assert_eq!(
+        setup.client.try_emergency_withdraw(&custom),
+        Err(Ok(Error::RecipientBlocked))
+    );
+}
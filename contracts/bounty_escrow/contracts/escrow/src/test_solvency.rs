@@ -0,0 +1,176 @@
+#![cfg(test)]
+
+use crate::test_setup::EscrowSetup;
+use crate::{DiscrepancyKind, Error, LockFundsItem, RefundMode};
+use core::ops::Deref;
+use soroban_sdk::vec;
+
+struct SolvencySetup {
+    base: EscrowSetup,
+}
+
+impl Deref for SolvencySetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl SolvencySetup {
+    fn new() -> Self {
+        Self {
+            base: EscrowSetup::with_claim_window(1_000_000),
+        }
+    }
+
+    fn liabilities(&self) -> i128 {
+        self.client.verify_solvency().total_liabilities
+    }
+}
+
+#[test]
+fn test_liabilities_follow_every_payout_path() {
+    let setup = SolvencySetup::new();
+    let report = setup.client.verify_solvency();
+    assert_eq!(report.total_liabilities, 0);
+    assert!(report.is_solvent);
+
+    setup.lock(1, 1_000);
+    setup.lock(2, 500);
+    setup.lock(3, 200);
+    assert_eq!(setup.liabilities(), 1_700);
+
+    setup.client.partial_release(&1, &setup.contributor, &300);
+    assert_eq!(setup.liabilities(), 1_400);
+
+    setup
+        .client
+        .approve_refund(&2, &100, &setup.depositor, &RefundMode::Partial);
+    setup.client.refund(&2);
+    assert_eq!(setup.liabilities(), 1_300);
+
+    setup.client.release_funds(&3, &setup.contributor);
+    assert_eq!(setup.liabilities(), 1_100);
+
+    setup.lock(4, 250);
+    setup.client.authorize_claim(&4, &setup.contributor);
+    setup.client.claim(&4);
+    assert_eq!(setup.liabilities(), 1_100);
+
+    let report = setup.client.verify_solvency();
+    assert_eq!(report.token_balance, 1_100);
+    assert_eq!(report.surplus, 0);
+    assert!(report.is_solvent);
+}
+
+#[test]
+fn test_payout_that_would_draw_on_other_escrows_is_rejected() {
+    let setup = SolvencySetup::new();
+    setup.lock(1, 1_000);
+    setup.lock(2, 1_000);
+    setup.client.partial_release(&1, &setup.contributor, &300);
+
+    // `release_funds` pays the original amount; after a partial release that
+    // would be funded from bounty 2's deposit.
+    assert_eq!(
+        setup.client.try_release_funds(&1, &setup.contributor),
+        Err(Ok(Error::SolvencyViolation))
+    );
+    assert_eq!(setup.liabilities(), 1_700);
+    assert!(setup.client.verify_solvency().is_solvent);
+}
+
+#[test]
+fn test_emergency_withdraw_leaves_contract_insolvent() {
+    let setup = SolvencySetup::new();
+    setup.lock(1, 1_000);
+
+    setup.client.set_paused(&Some(true), &None, &None, &None);
+    setup.client.emergency_withdraw(&setup.contributor);
+
+    let report = setup.client.verify_solvency();
+    assert_eq!(report.token_balance, 0);
+    assert_eq!(report.total_liabilities, 1_000);
+    assert_eq!(report.surplus, -1_000);
+    assert!(!report.is_solvent);
+}
+
+#[test]
+fn test_reconcile_pages_through_all_escrows() {
+    let setup = SolvencySetup::new();
+    for id in 0..5u64 {
+        setup.lock(id, 100 + id as i128);
+    }
+    setup.client.release_funds(&0, &setup.contributor);
+
+    let first = setup.client.reconcile(&0, &2);
+    assert_eq!(first.scanned, 2);
+    assert_eq!(first.next_cursor, Some(2));
+    assert_eq!(first.page_liabilities, 101);
+
+    let second = setup.client.reconcile(&2, &2);
+    assert_eq!(second.next_cursor, Some(4));
+
+    let last = setup.client.reconcile(&4, &2);
+    assert_eq!(last.scanned, 1);
+    assert_eq!(last.next_cursor, None);
+
+    let total = first.page_liabilities + second.page_liabilities + last.page_liabilities;
+    assert_eq!(total, last.total_liabilities);
+    assert_eq!(total, last.token_balance);
+    assert!(first.discrepancies.is_empty());
+    assert!(second.discrepancies.is_empty());
+    assert!(last.discrepancies.is_empty());
+}
+
+#[test]
+fn test_batch_locked_escrows_are_reconciled() {
+    let setup = SolvencySetup::new();
+    let deadline = setup.env.ledger().timestamp() + 1_000;
+    setup.client.batch_lock_funds(&vec![
+        &setup.env,
+        LockFundsItem {
+            bounty_id: 10,
+            depositor: setup.depositor.clone(),
+            amount: 400,
+            deadline,
+        },
+        LockFundsItem {
+            bounty_id: 11,
+            depositor: setup.depositor.clone(),
+            amount: 600,
+            deadline,
+        },
+    ]);
+
+    let report = setup.client.reconcile(&0, &50);
+    assert_eq!(report.scanned, 2);
+    assert_eq!(report.page_liabilities, 1_000);
+    assert_eq!(report.total_liabilities, 1_000);
+}
+
+#[test]
+fn test_reconcile_flags_refunded_escrow_with_remaining_funds() {
+    let setup = SolvencySetup::new();
+    setup.lock(1, 1_000);
+
+    // A full-mode approval for less than the remaining amount closes the
+    // escrow while funds are still held for it.
+    setup
+        .client
+        .approve_refund(&1, &400, &setup.depositor, &RefundMode::Full);
+    setup.client.refund(&1);
+
+    let report = setup.client.reconcile(&0, &10);
+    assert_eq!(report.discrepancies.len(), 1);
+    let discrepancy = report.discrepancies.get(0).unwrap();
+    assert_eq!(discrepancy.bounty_id, 1);
+    assert_eq!(discrepancy.kind, DiscrepancyKind::RefundedWithRemaining);
+    assert_eq!(discrepancy.actual, 600);
+
+    // The stranded remainder is surplus rather than a liability.
+    let solvency = setup.client.verify_solvency();
+    assert_eq!(solvency.total_liabilities, 0);
+    assert_eq!(solvency.surplus, 600);
+}
@@ -430,3 +430,42 @@ fn test_partially_refunded_to_released_fails() {
 
     setup.escrow.release_funds(&bounty_id, &setup.contributor);
 }
+
+// The published table is the single source of truth for the rules above.
+#[test]
+fn test_status_transition_table_view() {
+    let setup = TestSetup::new();
+    let table = setup.escrow.get_status_transitions();
+    assert_eq!(table.len(), 13);
+
+    let has = |from: EscrowStatus, to: EscrowStatus, action: StatusAction| {
+        table
+            .iter()
+            .any(|t| t.from == from && t.to == to && t.action == action)
+    };
+    assert!(has(
+        EscrowStatus::Locked,
+        EscrowStatus::Released,
+        StatusAction::Release
+    ));
+    assert!(has(
+        EscrowStatus::PartiallyRefunded,
+        EscrowStatus::Refunded,
+        StatusAction::Refund
+    ));
+    assert!(has(
+        EscrowStatus::Frozen,
+        EscrowStatus::PartiallyRefunded,
+        StatusAction::Unfreeze
+    ));
+    assert!(!has(
+        EscrowStatus::PartiallyRefunded,
+        EscrowStatus::Released,
+        StatusAction::Release
+    ));
+
+    // Released and Refunded are terminal.
+    assert!(table
+        .iter()
+        .all(|t| t.from != EscrowStatus::Released && t.from != EscrowStatus::Refunded));
+}
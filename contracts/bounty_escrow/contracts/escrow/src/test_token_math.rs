@@ -245,3 +245,43 @@ fn fee_monotonic_with_amount() {
         prev = fee;
     }
 }
+
+// ===========================================================================
+// 7. split_largest_remainder
+// ===========================================================================
+
+#[test]
+fn largest_remainder_exact_split() {
+    assert_eq!(
+        token_math::split_largest_remainder(1_000, [7_000, 2_000, 1_000]),
+        Some([700, 200, 100])
+    );
+}
+
+#[test]
+fn largest_remainder_gives_leftover_to_largest_fractions() {
+    // 7.7 / 2.2 / 1.1 → floors 7 / 2 / 1, one unit left for the 0.7.
+    assert_eq!(
+        token_math::split_largest_remainder(11, [7_000, 2_000, 1_000]),
+        Some([8, 2, 1])
+    );
+    // 3.33 / 3.33 / 3.33 → ties go to the earlier entries.
+    assert_eq!(
+        token_math::split_largest_remainder(10, [1, 1, 1]),
+        Some([4, 3, 3])
+    );
+}
+
+#[test]
+fn largest_remainder_always_sums_to_amount() {
+    for amount in [0_i128, 1, 2, 3, 7, 99, 101, 9_999, 1_000_003] {
+        let shares = token_math::split_largest_remainder(amount, [3_333, 3_334, 3_333]).unwrap();
+        assert_eq!(shares.iter().sum::<i128>(), amount);
+    }
+}
+
+#[test]
+fn largest_remainder_rejects_invalid_weights() {
+    assert_eq!(token_math::split_largest_remainder(10, [0, 0]), None);
+    assert_eq!(token_math::split_largest_remainder(10, [5, -1]), None);
+}
@@ -0,0 +1,200 @@
+#![cfg(test)]
+
+use crate::test_setup::EscrowSetup;
+use crate::units::{AmountPolicyView, DisplayAmount};
+use crate::{BountyEscrowContract, BountyEscrowContractClient, Error};
+use core::ops::Deref;
+use soroban_sdk::{contract, contractimpl, testutils::Address as _, Address, Env};
+
+/// Stand-in for a 6-decimal token such as USDC; only `decimals` is needed.
+#[contract]
+struct SixDecimalToken;
+
+#[contractimpl]
+impl SixDecimalToken {
+    pub fn decimals(_env: Env) -> u32 {
+        6
+    }
+}
+
+const XLM: i128 = 10_000_000;
+
+struct UnitsSetup {
+    base: EscrowSetup,
+}
+
+impl Deref for UnitsSetup {
+    type Target = EscrowSetup;
+
+    fn deref(&self) -> &EscrowSetup {
+        &self.base
+    }
+}
+
+impl UnitsSetup {
+    fn new() -> Self {
+        Self {
+            base: EscrowSetup::new(1_000 * XLM),
+        }
+    }
+
+    fn try_lock(&self, bounty_id: u64, amount: i128) -> Result<(), Error> {
+        match self
+            .client
+            .try_lock_funds(&self.depositor, &bounty_id, &amount, &1_000)
+        {
+            Ok(Ok(())) => Ok(()),
+            Err(Ok(error)) => Err(error),
+            other => panic!("unexpected result {:?}", other),
+        }
+    }
+}
+
+fn display(base_units: i128, whole: i128, fraction: i128, decimals: u32) -> DisplayAmount {
+    DisplayAmount {
+        base_units,
+        whole,
+        fraction,
+        decimals,
+    }
+}
+
+#[test]
+fn test_decimals_are_cached_at_init() {
+    let setup = UnitsSetup::new();
+    assert_eq!(setup.client.get_token_decimals(), 7);
+    assert_eq!(
+        setup.client.to_display_amount(&(25 * XLM + 5)),
+        display(25 * XLM + 5, 25, 5, 7)
+    );
+
+    let env = &setup.env;
+    let usdc = env.register_contract(None, SixDecimalToken);
+    let usdc_escrow =
+        BountyEscrowContractClient::new(env, &env.register_contract(None, BountyEscrowContract));
+    usdc_escrow.init(&setup.admin, &usdc);
+    assert_eq!(usdc_escrow.get_token_decimals(), 6);
+    assert_eq!(
+        usdc_escrow.to_display_amount(&12_345_678),
+        display(12_345_678, 12, 345_678, 6)
+    );
+}
+
+#[test]
+fn test_token_policy_is_set_in_whole_units() {
+    let setup = UnitsSetup::new();
+    let usdc = setup.env.register_contract(None, SixDecimalToken);
+
+    setup
+        .client
+        .set_token_amount_policy(&setup.admin, &setup.token.address, &10, &100);
+    setup
+        .client
+        .set_token_amount_policy(&setup.admin, &usdc, &10, &100);
+
+    // The same whole-token policy, scaled to each token's decimals.
+    assert_eq!(
+        setup.client.get_amount_policy(&setup.token.address),
+        Some(AmountPolicyView {
+            token: setup.token.address.clone(),
+            min: display(10 * XLM, 10, 0, 7),
+            max: display(100 * XLM, 100, 0, 7),
+            per_token: true,
+        })
+    );
+    assert_eq!(
+        setup.client.get_amount_policy(&usdc).unwrap().min,
+        display(10_000_000, 10, 0, 6)
+    );
+
+    assert_eq!(
+        setup.try_lock(1, 10 * XLM - 1),
+        Err(Error::AmountBelowMinimum)
+    );
+    assert_eq!(
+        setup.try_lock(2, 100 * XLM + 1),
+        Err(Error::AmountAboveMaximum)
+    );
+    assert_eq!(setup.try_lock(3, 10 * XLM), Ok(()));
+}
+
+#[test]
+fn test_token_policy_overrides_global_policy() {
+    let setup = UnitsSetup::new();
+    setup.client.set_amount_policy(&setup.admin, &1, &500);
+
+    let global = setup
+        .client
+        .get_amount_policy(&setup.token.address)
+        .unwrap();
+    assert!(!global.per_token);
+    assert_eq!(global.max, display(500, 0, 500, 7));
+    assert_eq!(setup.try_lock(1, XLM), Err(Error::AmountAboveMaximum));
+
+    setup
+        .client
+        .set_token_amount_policy(&setup.admin, &setup.token.address, &1, &50);
+    assert!(
+        setup
+            .client
+            .get_amount_policy(&setup.token.address)
+            .unwrap()
+            .per_token
+    );
+    assert_eq!(setup.try_lock(2, XLM), Ok(()));
+    assert_eq!(setup.try_lock(3, 500), Err(Error::AmountBelowMinimum));
+}
+
+#[test]
+fn test_invalid_token_policy_is_rejected() {
+    let setup = UnitsSetup::new();
+    let client = &setup.client;
+
+    assert_eq!(
+        client.try_set_token_amount_policy(&setup.admin, &setup.token.address, &10, &5),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_token_amount_policy(&setup.admin, &setup.token.address, &-1, &5),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_token_amount_policy(&setup.admin, &setup.token.address, &1, &i128::MAX),
+        Err(Ok(Error::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_set_token_amount_policy(
+            &Address::generate(&setup.env),
+            &setup.token.address,
+            &1,
+            &5
+        ),
+        Err(Ok(Error::Unauthorized))
+    );
+    assert_eq!(client.get_amount_policy(&setup.token.address), None);
+}
+
+#[test]
+fn test_escrow_and_simulation_amounts_in_units() {
+    let setup = UnitsSetup::new();
+    setup.try_lock(1, 25 * XLM + XLM / 2).unwrap();
+
+    let amounts = setup.client.get_escrow_amounts(&1);
+    assert_eq!(amounts.amount, display(255_000_000, 25, 5_000_000, 7));
+    assert_eq!(amounts.remaining_amount, amounts.amount);
+    assert_eq!(
+        setup.client.try_get_escrow_amounts(&2),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    let contributor = Address::generate(&setup.env);
+    let result = setup
+        .client
+        .simulate_partial_release(&1, &contributor, &(10 * XLM));
+    assert!(result.success);
+    assert_eq!(result.amount_display, display(10 * XLM, 10, 0, 7));
+    assert_eq!(
+        result.remaining_display,
+        display(155_000_000, 15, 5_000_000, 7)
+    );
+}
@@ -141,4 +141,249 @@ fn test_upgrade_partial_release_then_complete() {
     let escrow = client.get_escrow_info(&3);
     assert_eq!(escrow.remaining_amount, 0);
     assert_eq!(escrow.status, EscrowStatus::Released);
-}
\ No newline at end of file
+}
+// ── UPGRADE GATING AND MIGRATION ─────────────────────────────────────────────
+
+use crate::migration::{
+    self, MigrationState, UpgradeRecord, VersionedEscrow, CONTRACT_VERSION, MIGRATION_PAGE_SIZE,
+};
+use crate::{DataKey, Error, Escrow};
+use soroban_sdk::{vec, Bytes, BytesN, TryFromVal, Val};
+
+/// Smallest module the host accepts as contract code: just the
+/// `contractenvmetav0` section declaring the protocol 21 interface.
+fn empty_contract_wasm(env: &Env) -> Bytes {
+    let mut wasm = Bytes::from_slice(env, b"\0asm\x01\0\0\0");
+    let name = b"contractenvmetav0";
+    // section id, section size, name length
+    wasm.extend_from_array(&[0, (1 + name.len() + 12) as u8, name.len() as u8]);
+    wasm.extend_from_slice(name);
+    // SCEnvMetaEntry::InterfaceVersion(21 << 32)
+    wasm.extend_from_array(&[0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0]);
+    wasm
+}
+
+struct UpgradeSetup {
+    env: Env,
+    client: BountyEscrowContractClient<'static>,
+    contract_id: Address,
+    admin: Address,
+    depositor: Address,
+}
+
+impl UpgradeSetup {
+    fn new() -> Self {
+        let (env, client, contract_id) = create_test_env();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let token_admin = Address::generate(&env);
+        let (token, _token_client, token_admin_client) = create_token_contract(&env, &token_admin);
+
+        client.init(&admin, &token);
+        token_admin_client.mint(&depositor, &10_000);
+
+        Self {
+            env,
+            client,
+            contract_id,
+            admin,
+            depositor,
+        }
+    }
+
+    fn stored(&self, bounty_id: u64) -> Val {
+        self.env.as_contract(&self.contract_id, || {
+            self.env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(bounty_id))
+                .unwrap()
+        })
+    }
+}
+
+#[test]
+fn test_upgrade_requires_multisig_approvals() {
+    let setup = UpgradeSetup::new();
+    let env = &setup.env;
+    let signer_a = Address::generate(env);
+    let signer_b = Address::generate(env);
+    setup
+        .client
+        .update_multisig_config(&1_000, &vec![env, signer_a.clone(), signer_b.clone()], &2);
+    let wasm_hash = BytesN::from_array(env, &[7; 32]);
+    let other_hash = BytesN::from_array(env, &[8; 32]);
+
+    assert_eq!(
+        setup
+            .client
+            .try_approve_upgrade(&wasm_hash, &Address::generate(env)),
+        Err(Ok(Error::Unauthorized))
+    );
+
+    setup.client.approve_upgrade(&wasm_hash, &signer_a);
+    setup.client.approve_upgrade(&wasm_hash, &signer_a);
+    setup.client.approve_upgrade(&other_hash, &signer_b);
+    assert_eq!(
+        setup.client.get_upgrade_approvals(&wasm_hash),
+        vec![env, signer_a.clone()]
+    );
+    assert_eq!(
+        setup.client.try_upgrade(&wasm_hash),
+        Err(Ok(Error::UpgradeNotApproved))
+    );
+    assert!(setup.client.get_upgrade_history().is_empty());
+}
+
+#[test]
+fn test_approved_upgrade_is_recorded() {
+    let setup = UpgradeSetup::new();
+    let env = &setup.env;
+    let signer = Address::generate(env);
+    setup
+        .client
+        .update_multisig_config(&1_000, &vec![env, signer.clone()], &1);
+    setup
+        .client
+        .lock_funds(&setup.depositor, &1, &5_000, &1_000);
+
+    let wasm_hash = env
+        .deployer()
+        .upload_contract_wasm(empty_contract_wasm(env));
+    setup.client.approve_upgrade(&wasm_hash, &signer);
+    setup.client.upgrade(&wasm_hash);
+
+    // The new code is live, so inspect storage directly.
+    env.as_contract(&setup.contract_id, || {
+        assert_eq!(
+            migration::get_upgrade_history(env),
+            vec![
+                env,
+                UpgradeRecord {
+                    wasm_hash: wasm_hash.clone(),
+                    from_version: CONTRACT_VERSION,
+                    upgraded_by: setup.admin.clone(),
+                    upgraded_at: env.ledger().timestamp(),
+                }
+            ]
+        );
+        assert!(migration::get_upgrade_approvals(env, &wasm_hash).is_empty());
+        assert_eq!(migration::load_escrow(env, 1).unwrap().amount, 5_000);
+    });
+}
+
+#[test]
+fn test_legacy_escrow_records_migrate_lazily() {
+    let setup = UpgradeSetup::new();
+    let env = &setup.env;
+    let contributor = Address::generate(env);
+    setup
+        .client
+        .lock_funds(&setup.depositor, &1, &5_000, &1_000);
+    setup
+        .client
+        .lock_funds(&setup.depositor, &2, &3_000, &1_000);
+    assert!(VersionedEscrow::try_from_val(env, &setup.stored(1)).is_ok());
+
+    // Rewrite both records in the layout written by version 1.
+    for bounty_id in [1u64, 2] {
+        let escrow = setup.client.get_escrow_info(&bounty_id);
+        env.as_contract(&setup.contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(bounty_id), &escrow);
+        });
+    }
+    assert!(VersionedEscrow::try_from_val(env, &setup.stored(1)).is_err());
+    assert_eq!(setup.client.get_escrow_info(&1).remaining_amount, 5_000);
+
+    setup.client.partial_release(&1, &contributor, &2_000);
+
+    match VersionedEscrow::try_from_val(env, &setup.stored(1)).unwrap() {
+        VersionedEscrow::V2(escrow) => assert_eq!(escrow.remaining_amount, 3_000),
+    }
+    // Untouched records keep the old layout and stay readable.
+    assert!(Escrow::try_from_val(env, &setup.stored(2)).is_ok());
+    assert_eq!(setup.client.get_escrow_info(&2).remaining_amount, 3_000);
+    assert!(setup.client.reconcile(&0, &10).discrepancies.is_empty());
+}
+
+#[test]
+fn test_migrate_seeds_version_and_liabilities() {
+    let setup = UpgradeSetup::new();
+    let env = &setup.env;
+    assert_eq!(setup.client.get_version(), CONTRACT_VERSION);
+    setup
+        .client
+        .lock_funds(&setup.depositor, &1, &5_000, &1_000);
+    setup
+        .client
+        .lock_funds(&setup.depositor, &2, &3_000, &1_000);
+
+    // A deployment that predates version and liability tracking.
+    env.as_contract(&setup.contract_id, || {
+        env.storage().instance().remove(&DataKey::Version);
+        env.storage().instance().remove(&DataKey::TotalLiabilities);
+    });
+    assert_eq!(setup.client.get_version(), 1);
+    assert_eq!(setup.client.get_migration_state(), None);
+
+    let state = setup.client.migrate();
+    assert_eq!(
+        state,
+        MigrationState {
+            from_version: 1,
+            to_version: CONTRACT_VERSION,
+            total_liabilities: 8_000,
+            next_cursor: None,
+            migrated_at: env.ledger().timestamp(),
+        }
+    );
+    assert_eq!(setup.client.get_migration_state(), Some(state));
+    assert_eq!(setup.client.get_version(), CONTRACT_VERSION);
+    assert!(setup.client.verify_solvency().is_solvent);
+
+    // Running it again is harmless.
+    let again = setup.client.migrate();
+    assert_eq!(again.from_version, CONTRACT_VERSION);
+    assert_eq!(again.total_liabilities, 8_000);
+}
+
+#[test]
+fn test_migrate_counts_a_large_index_over_several_calls() {
+    let setup = UpgradeSetup::new();
+    let env = &setup.env;
+    let bounties = MIGRATION_PAGE_SIZE as u64 + 10;
+    for bounty_id in 0..bounties {
+        setup
+            .client
+            .lock_funds(&setup.depositor, &bounty_id, &100, &1_000);
+    }
+    env.as_contract(&setup.contract_id, || {
+        env.storage().instance().remove(&DataKey::Version);
+        env.storage().instance().remove(&DataKey::TotalLiabilities);
+    });
+
+    // A tally spread over several calls would miss changes made in between.
+    assert_eq!(setup.client.try_migrate(), Err(Ok(Error::NotPaused)));
+    setup
+        .client
+        .set_paused(&Some(true), &Some(true), &Some(true), &None);
+
+    let first = setup.client.migrate();
+    assert_eq!(first.next_cursor, Some(MIGRATION_PAGE_SIZE));
+    assert_eq!(first.total_liabilities, MIGRATION_PAGE_SIZE as i128 * 100);
+    assert_eq!(setup.client.get_version(), 1);
+
+    let last = setup.client.migrate();
+    assert_eq!(last.from_version, 1);
+    assert_eq!(last.next_cursor, None);
+    assert_eq!(last.total_liabilities, bounties as i128 * 100);
+    assert_eq!(setup.client.get_version(), CONTRACT_VERSION);
+    assert_eq!(
+        setup.client.verify_solvency().total_liabilities,
+        bounties as i128 * 100
+    );
+}
@@ -66,3 +66,48 @@ pub fn to_base_units(amount: i128, decimals: u32) -> Option<i128> {
     let factor = 10_i128.checked_pow(decimals)?;
     amount.checked_mul(factor)
 }
+
+/// Split `amount` in proportion to `weights` with largest-remainder
+/// rounding: every share is floored, then the units still unallocated go
+/// one each to the shares with the largest remainders (earlier entries win
+/// ties). The shares always sum to `amount`.
+///
+/// Returns `None` when a weight is negative, all weights are zero, or on
+/// overflow.
+pub fn split_largest_remainder<const N: usize>(
+    amount: i128,
+    weights: [i128; N],
+) -> Option<[i128; N]> {
+    let mut total: i128 = 0;
+    for weight in weights {
+        if weight < 0 {
+            return None;
+        }
+        total = total.checked_add(weight)?;
+    }
+    if total == 0 {
+        return None;
+    }
+
+    let mut shares = [0_i128; N];
+    let mut remainders = [0_i128; N];
+    let mut leftover = amount;
+    for (i, weight) in weights.iter().enumerate() {
+        let scaled = amount.checked_mul(*weight)?;
+        shares[i] = scaled / total;
+        remainders[i] = scaled % total;
+        leftover -= shares[i];
+    }
+    while leftover > 0 {
+        let mut largest = 0;
+        for (i, remainder) in remainders.iter().enumerate() {
+            if *remainder > remainders[largest] {
+                largest = i;
+            }
+        }
+        shares[largest] += 1;
+        remainders[largest] = -1;
+        leftover -= 1;
+    }
+    Some(shares)
+}
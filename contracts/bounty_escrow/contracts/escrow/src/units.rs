@@ -0,0 +1,152 @@
+//! # Token Units
+//!
+//! Amounts are stored and transferred in the token's base units (stroops
+//! for XLM, micro-units for 6-decimal USDC). This module caches each token's
+//! `decimals()` so policies can be configured in whole tokens and views can
+//! report amounts in both scales via [`crate::token_math`].
+//!
+//! ## Amount policies
+//!
+//! `set_token_amount_policy(token, min, max)` takes whole-token units and
+//! stores the limits in that token's base units, so the same policy means
+//! the same value for tokens with different decimals. A per-token policy
+//! takes precedence over the legacy base-unit `set_amount_policy`, which
+//! still applies to tokens without one.
+
+use crate::token_math;
+use crate::{DataKey, Error};
+use soroban_sdk::{contracttype, token, Address, Env};
+
+/// An amount in base units alongside its whole-token reading.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DisplayAmount {
+    pub base_units: i128,
+    /// Whole tokens, rounded toward zero.
+    pub whole: i128,
+    /// Remainder below one whole token, in base units.
+    pub fraction: i128,
+    pub decimals: u32,
+}
+
+/// Lock limits for one token, in base units.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmountPolicy {
+    pub min_amount: i128,
+    pub max_amount: i128,
+}
+
+/// Effective lock limits for a token, in both scales.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AmountPolicyView {
+    pub token: Address,
+    pub min: DisplayAmount,
+    pub max: DisplayAmount,
+    /// `true` for a per-token policy, `false` for the legacy global one.
+    pub per_token: bool,
+}
+
+/// Escrowed amounts of one bounty, in both scales.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowAmounts {
+    pub amount: DisplayAmount,
+    pub remaining_amount: DisplayAmount,
+}
+
+pub fn escrow_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::Token)
+}
+
+/// Query `token.decimals()` and cache it.
+pub fn cache_decimals(env: &Env, token: &Address) -> u32 {
+    let decimals = token::Client::new(env, token).decimals();
+    env.storage()
+        .instance()
+        .set(&DataKey::TokenDecimals(token.clone()), &decimals);
+    decimals
+}
+
+/// Like [`cache_decimals`], but leaves the cache empty when the token does
+/// not answer, so initialization never depends on the token contract.
+pub fn try_cache_decimals(env: &Env, token: &Address) {
+    if let Ok(Ok(decimals)) = token::Client::new(env, token).try_decimals() {
+        env.storage()
+            .instance()
+            .set(&DataKey::TokenDecimals(token.clone()), &decimals);
+    }
+}
+
+/// Decimals of `token`, from the cache when available, else 0 for a token
+/// that does not answer. Never writes, so it is safe in views and
+/// simulations.
+pub fn decimals(env: &Env, token: &Address) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TokenDecimals(token.clone()))
+        .unwrap_or_else(|| match token::Client::new(env, token).try_decimals() {
+            Ok(Ok(decimals)) => decimals,
+            _ => 0,
+        })
+}
+
+/// Decimals of the escrow token; 0 before initialization.
+pub fn escrow_decimals(env: &Env) -> u32 {
+    escrow_token(env)
+        .map(|token| decimals(env, &token))
+        .unwrap_or(0)
+}
+
+pub fn display(amount: i128, decimals: u32) -> DisplayAmount {
+    let one = token_math::to_base_units(1, decimals).unwrap_or(i128::MAX);
+    DisplayAmount {
+        base_units: amount,
+        whole: amount / one,
+        fraction: amount % one,
+        decimals,
+    }
+}
+
+/// `amount` of the escrow token in both scales.
+pub fn display_escrow(env: &Env, amount: i128) -> DisplayAmount {
+    display(amount, escrow_decimals(env))
+}
+
+/// Whole tokens to base units of a token with `decimals`.
+pub fn from_whole(amount: i128, decimals: u32) -> Result<i128, Error> {
+    token_math::to_base_units(amount, decimals).ok_or(Error::InvalidAmount)
+}
+
+pub fn get_token_policy(env: &Env, token: &Address) -> Option<AmountPolicy> {
+    env.storage()
+        .instance()
+        .get(&DataKey::TokenAmountPolicy(token.clone()))
+}
+
+pub fn set_token_policy(env: &Env, token: &Address, policy: &AmountPolicy) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TokenAmountPolicy(token.clone()), policy);
+}
+
+/// Limits enforced on locks of `token`: its own policy, else the global
+/// base-unit policy. The second value is `true` for a per-token policy.
+pub fn effective_policy(env: &Env, token: &Address) -> Option<(AmountPolicy, bool)> {
+    if let Some(policy) = get_token_policy(env, token) {
+        return Some((policy, true));
+    }
+    env.storage()
+        .instance()
+        .get::<DataKey, (i128, i128)>(&DataKey::AmountPolicy)
+        .map(|(min_amount, max_amount)| {
+            (
+                AmountPolicy {
+                    min_amount,
+                    max_amount,
+                },
+                false,
+            )
+        })
+}
@@ -0,0 +1,654 @@
+//! # Shared Pre-Flight Validation
+//!
+//! Every check a payout or lock entrypoint makes before it touches state
+//! lives here, in the order the entrypoint makes it. The entrypoints call
+//! these functions with [`Mode::Execute`]; the `simulate_*` views call the
+//! same functions with [`Mode::Simulate`], so a simulation reports exactly
+//! the error the real call would return.
+//!
+//! The two modes differ only where execution has side effects: `Execute`
+//! requires auth and consumes rate-limit quota, `Simulate` skips auth and
+//! peeks at the rate limit without recording the operation.
+
+use crate::access_list::{self, AccessRole};
+use crate::events::FeeOperationType;
+use crate::{
+    anti_abuse, attestation, claims, deadlines, deny_list, fees, invariants, migration, units,
+    BountyEscrowContract, Capability, CapabilityAction, ClaimAuthorizationItem, ClaimRecord,
+    DataKey, Error, Escrow, LockFundsItem, PartialReleaseItem, RateLimitOperation, RefundApproval,
+    RefundMode, ReleaseFundsItem, StatusAction, MAX_BATCH_SIZE,
+};
+use soroban_sdk::{symbol_short, token, Address, Env, Symbol, Vec};
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// Inside a state-changing call: require auth and record rate limits.
+    Execute,
+    /// Inside a `simulate_*` view: no auth, no writes.
+    Simulate,
+}
+
+/// What a validated refund would pay, and to whom.
+pub struct RefundPlan {
+    pub escrow: Escrow,
+    pub amount: i128,
+    pub refund_to: Address,
+    pub is_full: bool,
+    pub approval: Option<RefundApproval>,
+}
+
+fn authorize(address: &Address, mode: Mode) {
+    if mode == Mode::Execute {
+        address.require_auth();
+    }
+}
+
+fn rate_limit(
+    env: &Env,
+    address: &Address,
+    operation: RateLimitOperation,
+    mode: Mode,
+) -> Result<(), Error> {
+    match mode {
+        Mode::Execute => anti_abuse::check_rate_limit(env, address.clone(), operation),
+        Mode::Simulate => anti_abuse::peek_rate_limit(env, address, operation),
+    }
+}
+
+fn ensure_not_paused(env: &Env, operation: Symbol) -> Result<(), Error> {
+    if BountyEscrowContract::check_paused(env, operation) {
+        return Err(Error::FundsPaused);
+    }
+    Ok(())
+}
+
+/// Payees must clear both the compliance deny-list and the recipient
+/// access list. Refunds skip this: they return funds to their depositor.
+fn ensure_payee_allowed(env: &Env, payee: &Address) -> Result<(), Error> {
+    deny_list::ensure_recipient_allowed(env, payee)?;
+    access_list::ensure_allowed(env, AccessRole::Recipient, payee)
+}
+
+/// The stored admin, authorised in `Execute` mode.
+fn admin(env: &Env, mode: Mode) -> Result<Address, Error> {
+    if !env.storage().instance().has(&DataKey::Admin) {
+        return Err(Error::NotInitialized);
+    }
+    let admin: Address = env.storage().instance().get(&DataKey::Admin).unwrap();
+    authorize(&admin, mode);
+    Ok(admin)
+}
+
+pub fn load_escrow(env: &Env, bounty_id: u64) -> Result<Escrow, Error> {
+    migration::load_escrow(env, bounty_id).ok_or(Error::BountyNotFound)
+}
+
+/// The bounty's claim if one has been authorised and can still be
+/// executed: not yet claimed and not void.
+fn open_claim(env: &Env, bounty_id: u64) -> Option<ClaimRecord> {
+    env.storage()
+        .persistent()
+        .get::<DataKey, ClaimRecord>(&DataKey::PendingClaim(bounty_id))
+        .filter(|claim| !claim.claimed && !claims::is_void(env, claim))
+}
+
+/// Refunds are blocked by an open claim unless its recipient has been
+/// deny-listed, since such a claim can never execute.
+pub fn ensure_no_claim_blocks_refund(env: &Env, bounty_id: u64) -> Result<(), Error> {
+    if let Some(claim) = open_claim(env, bounty_id) {
+        if !deny_list::is_listed(env, &claim.recipient) {
+            return Err(Error::ClaimPending);
+        }
+    }
+    Ok(())
+}
+
+fn load_claim(env: &Env, bounty_id: u64) -> Result<ClaimRecord, Error> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::PendingClaim(bounty_id))
+        .ok_or(Error::BountyNotFound)
+}
+
+pub fn ensure_claim_live(env: &Env, claim: &ClaimRecord) -> Result<(), Error> {
+    if claim.claimed {
+        return Err(Error::ClaimAlreadyExecuted);
+    }
+    if env.ledger().timestamp() > claim.expires_at {
+        return Err(Error::ClaimExpired);
+    }
+    Ok(())
+}
+
+/// The claim's escrow, which must still hold the claimed amount.
+fn escrow_for_claim(env: &Env, claim: &ClaimRecord) -> Result<Escrow, Error> {
+    let escrow = claimable_escrow(env, claim.bounty_id)?;
+    if claim.amount > escrow.remaining_amount {
+        return Err(Error::InsufficientFunds);
+    }
+    Ok(escrow)
+}
+
+/// Where a claim pays out: the recipient, or the address they redirected
+/// it to.
+pub fn claim_payout_address(env: &Env, claim: &ClaimRecord) -> Address {
+    env.storage()
+        .persistent()
+        .get(&DataKey::ClaimRedirect(claim.bounty_id))
+        .unwrap_or_else(|| claim.recipient.clone())
+}
+
+/// Checks for `lock_funds`.
+pub fn lock(
+    env: &Env,
+    depositor: &Address,
+    bounty_id: u64,
+    amount: i128,
+    mode: Mode,
+) -> Result<(), Error> {
+    rate_limit(env, depositor, RateLimitOperation::Lock, mode)?;
+    ensure_not_paused(env, symbol_short!("lock"))?;
+    authorize(depositor, mode);
+    access_list::ensure_allowed(env, AccessRole::Depositor, depositor)?;
+
+    if !env.storage().instance().has(&DataKey::Admin) {
+        return Err(Error::NotInitialized);
+    }
+    if env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+        return Err(Error::BountyExists);
+    }
+
+    // Enforce min/max amount policy if one has been configured (Issue #62).
+    let token = units::escrow_token(env).ok_or(Error::NotInitialized)?;
+    if let Some((policy, _)) = units::effective_policy(env, &token) {
+        if amount < policy.min_amount {
+            return Err(Error::AmountBelowMinimum);
+        }
+        if amount > policy.max_amount {
+            return Err(Error::AmountAboveMaximum);
+        }
+    }
+    if amount > 0 && !leaves_deposit(env, depositor, amount) {
+        return Err(Error::InvalidAmount);
+    }
+    Ok(())
+}
+
+/// Whether a deposit of `amount` is still positive once its lock fee is
+/// taken out.
+fn leaves_deposit(env: &Env, depositor: &Address, amount: i128) -> bool {
+    fees::quote(env, depositor, amount, FeeOperationType::Lock).net > 0
+}
+
+/// Checks for `release_funds`. Returns the admin and the escrow to release.
+pub fn release(
+    env: &Env,
+    bounty_id: u64,
+    contributor: &Address,
+    mode: Mode,
+) -> Result<(Address, Escrow), Error> {
+    ensure_not_paused(env, symbol_short!("release"))?;
+    BountyEscrowContract::ensure_not_frozen(env, bounty_id)?;
+    ensure_payee_allowed(env, contributor)?;
+
+    // Block direct release while an active dispute (pending claim) exists.
+    if open_claim(env, bounty_id).is_some() {
+        return Err(Error::ClaimPending);
+    }
+
+    let admin = admin(env, mode)?;
+    let escrow = load_escrow(env, bounty_id)?;
+    if !invariants::allows(&escrow.status, StatusAction::Release) {
+        return Err(Error::FundsNotLocked);
+    }
+    attestation::ensure_payout_attested(env, contributor, escrow.amount)?;
+    Ok((admin, escrow))
+}
+
+/// Checks for `partial_release`. Returns the admin and the escrow.
+pub fn partial_release(
+    env: &Env,
+    bounty_id: u64,
+    contributor: &Address,
+    payout_amount: i128,
+    mode: Mode,
+) -> Result<(Address, Escrow), Error> {
+    let admin = admin(env, mode)?;
+    let escrow = partial_payout(env, bounty_id, contributor, payout_amount)?;
+    Ok((admin, escrow))
+}
+
+/// Escrow and payee checks shared by `partial_release` and its batch form.
+fn partial_payout(
+    env: &Env,
+    bounty_id: u64,
+    contributor: &Address,
+    payout_amount: i128,
+) -> Result<Escrow, Error> {
+    let escrow = load_escrow(env, bounty_id)?;
+    BountyEscrowContract::ensure_not_frozen(env, bounty_id)?;
+
+    if !invariants::allows(&escrow.status, StatusAction::PartialRelease) {
+        return Err(Error::FundsNotLocked);
+    }
+    // Guard: zero or negative payout makes no sense and would corrupt state
+    if payout_amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    // Guard: prevent overpayment — payout cannot exceed what is still owed
+    if payout_amount > escrow.remaining_amount {
+        return Err(Error::InsufficientFunds);
+    }
+
+    ensure_payee_allowed(env, contributor)?;
+    attestation::ensure_payout_attested(env, contributor, escrow.amount)?;
+    Ok(escrow)
+}
+
+/// Checks for `refund`. Works out the amount, recipient and mode from the
+/// refund approval, or a full refund to the depositor after the deadline.
+pub fn refund(env: &Env, bounty_id: u64) -> Result<RefundPlan, Error> {
+    ensure_not_paused(env, symbol_short!("refund"))?;
+    BountyEscrowContract::ensure_not_frozen(env, bounty_id)?;
+
+    let escrow = load_escrow(env, bounty_id)?;
+    if !invariants::allows(&escrow.status, StatusAction::Refund) {
+        return Err(Error::FundsNotLocked);
+    }
+    ensure_no_claim_blocks_refund(env, bounty_id)?;
+
+    let approval: Option<RefundApproval> = env
+        .storage()
+        .persistent()
+        .get(&DataKey::RefundApproval(bounty_id));
+
+    // Refund is allowed if:
+    // 1. Deadline has passed under the escrow's deadline mode (returns full
+    //    amount to depositor)
+    // 2. An administrative approval exists (can be early, partial, and to custom recipient)
+    if !deadlines::has_passed(env, bounty_id, &escrow) && approval.is_none() {
+        return Err(Error::DeadlineNotPassed);
+    }
+
+    let (amount, refund_to, is_full) = match &approval {
+        Some(app) => {
+            let full = app.mode == RefundMode::Full || app.amount >= escrow.remaining_amount;
+            (app.amount, app.recipient.clone(), full)
+        }
+        None => (escrow.remaining_amount, escrow.depositor.clone(), true),
+    };
+
+    if amount <= 0 || amount > escrow.remaining_amount {
+        return Err(Error::InvalidAmount);
+    }
+    deny_list::ensure_recipient_allowed(env, &refund_to)?;
+
+    Ok(RefundPlan {
+        escrow,
+        amount,
+        refund_to,
+        is_full,
+        approval,
+    })
+}
+
+/// Checks for `authorize_claim` and `authorize_partial_claim`. `amount`
+/// defaults to the escrow's remaining amount. Returns the admin and the
+/// amount of the claim.
+pub fn authorize_claim(
+    env: &Env,
+    bounty_id: u64,
+    recipient: &Address,
+    amount: Option<i128>,
+    mode: Mode,
+) -> Result<(Address, i128), Error> {
+    ensure_not_paused(env, symbol_short!("release"))?;
+    BountyEscrowContract::ensure_not_frozen(env, bounty_id)?;
+    ensure_payee_allowed(env, recipient)?;
+    let admin = admin(env, mode)?;
+    let escrow = claimable_escrow(env, bounty_id)?;
+    // A live claim must be executed, cancelled or left to expire first.
+    if open_claim(env, bounty_id).is_some() {
+        return Err(Error::ClaimPending);
+    }
+    let amount = amount.unwrap_or(escrow.remaining_amount);
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+    if amount > escrow.remaining_amount {
+        return Err(Error::InsufficientFunds);
+    }
+    Ok((admin, amount))
+}
+
+fn claimable_escrow(env: &Env, bounty_id: u64) -> Result<Escrow, Error> {
+    let escrow = load_escrow(env, bounty_id)?;
+    if !invariants::allows(&escrow.status, StatusAction::Claim) {
+        return Err(Error::FundsNotLocked);
+    }
+    Ok(escrow)
+}
+
+/// Checks for `claim`. Returns the pending claim and its escrow.
+pub fn claim(env: &Env, bounty_id: u64, mode: Mode) -> Result<(ClaimRecord, Escrow), Error> {
+    ensure_not_paused(env, symbol_short!("release"))?;
+    BountyEscrowContract::ensure_not_frozen(env, bounty_id)?;
+
+    let claim = load_claim(env, bounty_id)?;
+    authorize(&claim.recipient, mode);
+    rate_limit(env, &claim.recipient, RateLimitOperation::Claim, mode)?;
+    ensure_claim_live(env, &claim)?;
+    ensure_claim_recipients_allowed(env, &claim)?;
+    let escrow = escrow_for_claim(env, &claim)?;
+    attestation::ensure_payout_attested(env, &claim.recipient, escrow.amount)?;
+    Ok((claim, escrow))
+}
+
+fn ensure_claim_recipients_allowed(env: &Env, claim: &ClaimRecord) -> Result<(), Error> {
+    ensure_payee_allowed(env, &claim.recipient)?;
+    ensure_payee_allowed(env, &claim_payout_address(env, claim))
+}
+
+/// Checks for `redirect_claim`. Returns the pending claim.
+pub fn redirect_claim(
+    env: &Env,
+    bounty_id: u64,
+    payout_to: &Address,
+    mode: Mode,
+) -> Result<ClaimRecord, Error> {
+    BountyEscrowContract::ensure_not_frozen(env, bounty_id)?;
+    let claim = load_claim(env, bounty_id)?;
+    authorize(&claim.recipient, mode);
+    ensure_claim_live(env, &claim)?;
+    ensure_payee_allowed(env, payout_to)?;
+    Ok(claim)
+}
+
+/// Checks a capability for use by `holder`, without spending it.
+pub fn capability(
+    env: &Env,
+    holder: &Address,
+    capability_id: u64,
+    expected_action: CapabilityAction,
+    bounty_id: u64,
+    amount: i128,
+    mode: Mode,
+) -> Result<Capability, Error> {
+    let capability = BountyEscrowContract::load_capability(env, capability_id)?;
+
+    if capability.revoked {
+        return Err(Error::CapabilityRevoked);
+    }
+    if capability.action != expected_action {
+        return Err(Error::CapabilityActionMismatch);
+    }
+    if capability.bounty_id != bounty_id {
+        return Err(Error::CapabilityActionMismatch);
+    }
+    if capability.holder != holder.clone() {
+        return Err(Error::Unauthorized);
+    }
+    if env.ledger().timestamp() > capability.expiry {
+        return Err(Error::CapabilityExpired);
+    }
+    if capability.remaining_uses == 0 {
+        return Err(Error::CapabilityUsesExhausted);
+    }
+    if amount > capability.remaining_amount {
+        return Err(Error::CapabilityAmountExceeded);
+    }
+
+    authorize(holder, mode);
+    rate_limit(env, holder, RateLimitOperation::Capability, mode)?;
+    BountyEscrowContract::ensure_owner_still_authorized(env, &capability, amount)?;
+    Ok(capability)
+}
+
+/// Checks for `release_with_capability`. Returns the escrow and capability.
+pub fn release_with_capability(
+    env: &Env,
+    bounty_id: u64,
+    contributor: &Address,
+    payout_amount: i128,
+    holder: &Address,
+    capability_id: u64,
+    mode: Mode,
+) -> Result<(Escrow, Capability), Error> {
+    ensure_not_paused(env, symbol_short!("release"))?;
+    BountyEscrowContract::ensure_not_frozen(env, bounty_id)?;
+    ensure_payee_allowed(env, contributor)?;
+    if payout_amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let escrow = load_escrow(env, bounty_id)?;
+    if !invariants::allows(&escrow.status, StatusAction::PartialRelease) {
+        return Err(Error::FundsNotLocked);
+    }
+    if payout_amount > escrow.remaining_amount {
+        return Err(Error::InsufficientFunds);
+    }
+    attestation::ensure_payout_attested(env, contributor, escrow.amount)?;
+
+    let capability = capability(
+        env,
+        holder,
+        capability_id,
+        CapabilityAction::Release,
+        bounty_id,
+        payout_amount,
+        mode,
+    )?;
+    Ok((escrow, capability))
+}
+
+/// Checks for `claim_with_capability`. Returns the claim, its escrow and the
+/// capability.
+pub fn claim_with_capability(
+    env: &Env,
+    bounty_id: u64,
+    holder: &Address,
+    capability_id: u64,
+    mode: Mode,
+) -> Result<(ClaimRecord, Escrow, Capability), Error> {
+    ensure_not_paused(env, symbol_short!("release"))?;
+    BountyEscrowContract::ensure_not_frozen(env, bounty_id)?;
+
+    let claim = load_claim(env, bounty_id)?;
+    ensure_claim_live(env, &claim)?;
+    ensure_claim_recipients_allowed(env, &claim)?;
+    let escrow = escrow_for_claim(env, &claim)?;
+    attestation::ensure_payout_attested(env, &claim.recipient, escrow.amount)?;
+
+    let capability = capability(
+        env,
+        holder,
+        capability_id,
+        CapabilityAction::Claim,
+        bounty_id,
+        claim.amount,
+        mode,
+    )?;
+    Ok((claim, escrow, capability))
+}
+
+/// Checks for `refund_with_capability`. Returns the escrow and capability.
+pub fn refund_with_capability(
+    env: &Env,
+    bounty_id: u64,
+    amount: i128,
+    holder: &Address,
+    capability_id: u64,
+    mode: Mode,
+) -> Result<(Escrow, Capability), Error> {
+    ensure_not_paused(env, symbol_short!("refund"))?;
+    BountyEscrowContract::ensure_not_frozen(env, bounty_id)?;
+    if amount <= 0 {
+        return Err(Error::InvalidAmount);
+    }
+
+    let escrow = load_escrow(env, bounty_id)?;
+    if !invariants::allows(&escrow.status, StatusAction::Refund) {
+        return Err(Error::FundsNotLocked);
+    }
+    if amount > escrow.remaining_amount {
+        return Err(Error::InvalidAmount);
+    }
+    deny_list::ensure_recipient_allowed(env, &escrow.depositor)?;
+    ensure_no_claim_blocks_refund(env, bounty_id)?;
+
+    let capability = capability(
+        env,
+        holder,
+        capability_id,
+        CapabilityAction::Refund,
+        bounty_id,
+        amount,
+        mode,
+    )?;
+    Ok((escrow, capability))
+}
+
+/// Checks that apply to a whole batch before any item is looked at.
+pub fn batch(env: &Env, operation: Symbol, batch_size: u32) -> Result<(), Error> {
+    ensure_not_paused(env, operation)?;
+    if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
+        return Err(Error::InvalidBatchSize);
+    }
+    if !env.storage().instance().has(&DataKey::Admin) {
+        return Err(Error::NotInitialized);
+    }
+    Ok(())
+}
+
+/// Checks an `import_escrows` batch. Unlike `batch`, the lock pause does
+/// not apply: an import carries over deposits made on the legacy contract,
+/// so pausing new locks must not stall the migration.
+pub fn import_batch(env: &Env, batch_size: u32) -> Result<(), Error> {
+    if batch_size == 0 || batch_size > MAX_BATCH_SIZE {
+        return Err(Error::InvalidBatchSize);
+    }
+    if !env.storage().instance().has(&DataKey::Admin) {
+        return Err(Error::NotInitialized);
+    }
+    Ok(())
+}
+
+/// Whether `bounty_id` appears more than once in a strict batch.
+pub fn is_repeated(ids: impl Iterator<Item = u64>, bounty_id: u64) -> bool {
+    ids.filter(|id| *id == bounty_id).count() > 1
+}
+
+/// Checks one item of a lock batch. `duplicate` says whether its bounty id
+/// is repeated within the batch.
+pub fn batch_lock_item(env: &Env, item: &LockFundsItem, duplicate: bool) -> Result<(), Error> {
+    if env
+        .storage()
+        .persistent()
+        .has(&DataKey::Escrow(item.bounty_id))
+    {
+        return Err(Error::BountyExists);
+    }
+    access_list::ensure_allowed(env, AccessRole::Depositor, &item.depositor)?;
+    if item.amount <= 0 || !leaves_deposit(env, &item.depositor, item.amount) {
+        return Err(Error::InvalidAmount);
+    }
+    if duplicate {
+        return Err(Error::DuplicateBountyId);
+    }
+    Ok(())
+}
+
+/// Checks one item of `batch_lock_funds_best_effort` against the items
+/// already accepted from the batch. `duplicate` says whether its bounty id
+/// appeared earlier in the batch, accepted or not. Its depositor must hold
+/// enough to fund this item on top of their accepted ones, so the transfer
+/// cannot fail.
+pub fn best_effort_lock_item(
+    env: &Env,
+    accepted: &Vec<LockFundsItem>,
+    item: &LockFundsItem,
+    duplicate: bool,
+) -> Result<(), Error> {
+    batch_lock_item(env, item, duplicate)?;
+
+    let committed: i128 = accepted
+        .iter()
+        .filter(|a| a.depositor == item.depositor)
+        .map(|a| a.amount)
+        .sum();
+    let token_addr: Address = env.storage().instance().get(&DataKey::Token).unwrap();
+    let balance = token::Client::new(env, &token_addr).balance(&item.depositor);
+    if committed + item.amount > balance {
+        return Err(Error::InsufficientFunds);
+    }
+    Ok(())
+}
+
+/// Checks one item of a release batch. `duplicate` says whether its bounty
+/// id is repeated within the batch (earlier in it, for best-effort batches). Returns the escrow to release.
+pub fn batch_release_item(
+    env: &Env,
+    item: &ReleaseFundsItem,
+    duplicate: bool,
+) -> Result<Escrow, Error> {
+    if !env
+        .storage()
+        .persistent()
+        .has(&DataKey::Escrow(item.bounty_id))
+    {
+        return Err(Error::BountyNotFound);
+    }
+    BountyEscrowContract::ensure_not_frozen(env, item.bounty_id)?;
+    ensure_payee_allowed(env, &item.contributor)?;
+
+    let escrow = load_escrow(env, item.bounty_id)?;
+    if !invariants::allows(&escrow.status, StatusAction::Release) {
+        return Err(Error::FundsNotLocked);
+    }
+    if duplicate {
+        return Err(Error::DuplicateBountyId);
+    }
+    attestation::ensure_payout_attested(env, &item.contributor, escrow.amount)?;
+    Ok(escrow)
+}
+
+/// Checks one item of `batch_refund`.
+pub fn batch_refund_item(env: &Env, bounty_id: u64, duplicate: bool) -> Result<RefundPlan, Error> {
+    let plan = refund(env, bounty_id)?;
+    if duplicate {
+        return Err(Error::DuplicateBountyId);
+    }
+    Ok(plan)
+}
+
+/// Checks one item of `batch_partial_release`. Returns the escrow.
+pub fn batch_partial_release_item(
+    env: &Env,
+    item: &PartialReleaseItem,
+    duplicate: bool,
+) -> Result<Escrow, Error> {
+    let escrow = partial_payout(env, item.bounty_id, &item.contributor, item.amount)?;
+    if duplicate {
+        return Err(Error::DuplicateBountyId);
+    }
+    Ok(escrow)
+}
+
+/// Checks one item of `batch_authorize_claims`. Returns the escrow.
+pub fn batch_claim_item(
+    env: &Env,
+    item: &ClaimAuthorizationItem,
+    duplicate: bool,
+) -> Result<Escrow, Error> {
+    BountyEscrowContract::ensure_not_frozen(env, item.bounty_id)?;
+    ensure_payee_allowed(env, &item.recipient)?;
+    let escrow = claimable_escrow(env, item.bounty_id)?;
+    if open_claim(env, item.bounty_id).is_some() {
+        return Err(Error::ClaimPending);
+    }
+    if duplicate {
+        return Err(Error::DuplicateBountyId);
+    }
+    Ok(escrow)
+}
@@ -0,0 +1,97 @@
+//! # Escrow Hook Mock
+//!
+//! Test double for the payout hook interface of `bounty-escrow`:
+//!
+//! ```text
+//! on_escrow_event(kind: Symbol, bounty_id: u64, recipient: Address, amount: i128)
+//! ```
+//!
+//! Depending on its `HookMode` the mock records each notification, panics,
+//! or tries to re-enter the escrow that called it by invoking `refund` on
+//! the notified bounty.
+
+#![no_std]
+
+use soroban_sdk::{contract, contractimpl, contracttype, vec, Address, Env, IntoVal, Symbol, Vec};
+
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum HookMode {
+    /// Append every notification to `calls`.
+    Record,
+    /// Panic on every notification.
+    Panic,
+    /// Call `refund(bounty_id)` on `escrow`, then record.
+    Reenter,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HookCall {
+    pub kind: Symbol,
+    pub bounty_id: u64,
+    pub recipient: Address,
+    pub amount: i128,
+}
+
+#[contracttype]
+pub enum DataKey {
+    Escrow,
+    Mode,
+    Calls,
+}
+
+#[contract]
+pub struct EscrowHookMock;
+
+#[contractimpl]
+impl EscrowHookMock {
+    /// Set the escrow targeted by `HookMode::Reenter` and the initial mode.
+    pub fn init(env: Env, escrow: Address, mode: HookMode) {
+        env.storage().instance().set(&DataKey::Escrow, &escrow);
+        env.storage().instance().set(&DataKey::Mode, &mode);
+    }
+
+    pub fn set_mode(env: Env, mode: HookMode) {
+        env.storage().instance().set(&DataKey::Mode, &mode);
+    }
+
+    pub fn on_escrow_event(
+        env: Env,
+        kind: Symbol,
+        bounty_id: u64,
+        recipient: Address,
+        amount: i128,
+    ) {
+        let escrow: Address = env.storage().instance().get(&DataKey::Escrow).unwrap();
+        let mode: HookMode = env.storage().instance().get(&DataKey::Mode).unwrap();
+        match mode {
+            HookMode::Record => {}
+            HookMode::Panic => panic!("hook failure"),
+            HookMode::Reenter => {
+                env.invoke_contract::<()>(
+                    &escrow,
+                    &Symbol::new(&env, "refund"),
+                    vec![&env, bounty_id.into_val(&env)],
+                );
+            }
+        }
+
+        let mut calls = Self::calls(env.clone());
+        calls.push_back(HookCall {
+            kind,
+            bounty_id,
+            recipient,
+            amount,
+        });
+        env.storage().instance().set(&DataKey::Calls, &calls);
+    }
+
+    /// View: notifications recorded so far, oldest first.
+    pub fn calls(env: Env) -> Vec<HookCall> {
+        env.storage()
+            .instance()
+            .get(&DataKey::Calls)
+            .unwrap_or(Vec::new(&env))
+    }
+}
@@ -0,0 +1,249 @@
+//! Reconstructing escrow state from bounty escrow events.
+//!
+//! v2 events describe a change (an amount moved), so they are applied as
+//! deltas. v3 lifecycle events carry the escrow's remaining amount and status
+//! after the change and overwrite them, which makes a `Dual` stream safe to
+//! fold: the v3 event that follows each v2 event lands on the same state.
+//!
+//! A v2-only stream cannot tell a full-mode refund approval for less than the
+//! remaining amount from a partial one; it is recorded as `PartiallyRefunded`
+//! where the contract reports `Refunded`. Enable v3 events when exact status
+//! matters.
+
+use std::collections::BTreeMap;
+
+use crate::bounty_escrow::{self, BountyEscrowEvent, EscrowStatus, LifecycleKind};
+use crate::{DecodeError, RawEvent};
+
+/// Reconstructed state of one escrow.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EscrowRecord {
+    pub depositor: String,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    pub status: EscrowStatus,
+    /// Known only from the v2 `f_lock` and `imported` events.
+    pub deadline: Option<u64>,
+    /// Recipient and amount of the pending claim, if any.
+    pub pending_claim: Option<(String, i128)>,
+    /// Status to restore when a frozen escrow is unfrozen.
+    pub frozen_from: Option<EscrowStatus>,
+}
+
+/// All escrows seen in an event stream, keyed by bounty id.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EscrowBook {
+    escrows: BTreeMap<u64, EscrowRecord>,
+}
+
+impl EscrowBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Decode and fold every event emitted by `contract_id`; events from other
+    /// contracts (such as the token) are skipped.
+    pub fn from_events<'a>(
+        contract_id: &str,
+        events: impl IntoIterator<Item = &'a RawEvent>,
+    ) -> Result<Self, DecodeError> {
+        let mut book = Self::new();
+        for event in events {
+            if event.is_from(contract_id) {
+                book.apply(&bounty_escrow::decode(event)?);
+            }
+        }
+        Ok(book)
+    }
+
+    pub fn get(&self, bounty_id: u64) -> Option<&EscrowRecord> {
+        self.escrows.get(&bounty_id)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (u64, &EscrowRecord)> {
+        self.escrows.iter().map(|(id, record)| (*id, record))
+    }
+
+    pub fn len(&self) -> usize {
+        self.escrows.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.escrows.is_empty()
+    }
+
+    /// Sum of remaining amounts still owed, mirroring the contract's
+    /// `verify_solvency().total_liabilities`.
+    pub fn total_liabilities(&self) -> i128 {
+        self.escrows
+            .values()
+            .filter(|record| {
+                matches!(
+                    record.status,
+                    EscrowStatus::Locked | EscrowStatus::PartiallyRefunded | EscrowStatus::Frozen
+                )
+            })
+            .map(|record| record.remaining_amount)
+            .sum()
+    }
+
+    /// Fold one event into the book. Events for a bounty whose lock was not
+    /// seen are ignored.
+    pub fn apply(&mut self, event: &BountyEscrowEvent) {
+        match event {
+            BountyEscrowEvent::FundsLocked {
+                bounty_id,
+                amount,
+                depositor,
+                deadline,
+                ..
+            } => {
+                self.escrows.insert(
+                    *bounty_id,
+                    EscrowRecord {
+                        depositor: depositor.clone(),
+                        amount: *amount,
+                        remaining_amount: *amount,
+                        status: EscrowStatus::Locked,
+                        deadline: Some(*deadline),
+                        pending_claim: None,
+                        frozen_from: None,
+                    },
+                );
+            }
+            BountyEscrowEvent::EscrowImported {
+                bounty_id,
+                depositor,
+                amount,
+                remaining_amount,
+                deadline,
+                ..
+            } => {
+                self.escrows.insert(
+                    *bounty_id,
+                    EscrowRecord {
+                        depositor: depositor.clone(),
+                        amount: *amount,
+                        remaining_amount: *remaining_amount,
+                        status: EscrowStatus::Locked,
+                        deadline: Some(*deadline),
+                        pending_claim: None,
+                        frozen_from: None,
+                    },
+                );
+            }
+            BountyEscrowEvent::FundsReleased {
+                bounty_id, amount, ..
+            } => {
+                if let Some(record) = self.escrows.get_mut(bounty_id) {
+                    record.remaining_amount = (record.remaining_amount - amount).max(0);
+                    if record.remaining_amount == 0 {
+                        record.status = EscrowStatus::Released;
+                    }
+                }
+            }
+            BountyEscrowEvent::FundsRefunded {
+                bounty_id, amount, ..
+            } => {
+                if let Some(record) = self.escrows.get_mut(bounty_id) {
+                    record.remaining_amount = (record.remaining_amount - amount).max(0);
+                    record.status = if record.remaining_amount == 0 {
+                        EscrowStatus::Refunded
+                    } else {
+                        EscrowStatus::PartiallyRefunded
+                    };
+                }
+            }
+            BountyEscrowEvent::ClaimCreated {
+                bounty_id,
+                recipient,
+                amount,
+                ..
+            } => {
+                if let Some(record) = self.escrows.get_mut(bounty_id) {
+                    record.pending_claim = Some((recipient.clone(), *amount));
+                }
+            }
+            BountyEscrowEvent::ClaimExecuted {
+                bounty_id, amount, ..
+            } => {
+                if let Some(record) = self.escrows.get_mut(bounty_id) {
+                    record.remaining_amount = (record.remaining_amount - amount).max(0);
+                    if record.remaining_amount == 0 {
+                        record.status = EscrowStatus::Released;
+                    }
+                    record.pending_claim = None;
+                }
+            }
+            BountyEscrowEvent::ClaimCancelled { bounty_id, .. }
+            | BountyEscrowEvent::ClaimExpired { bounty_id, .. } => {
+                if let Some(record) = self.escrows.get_mut(bounty_id) {
+                    record.pending_claim = None;
+                }
+            }
+            BountyEscrowEvent::EscrowFrozen { bounty_id, .. } => {
+                if let Some(record) = self.escrows.get_mut(bounty_id) {
+                    record.freeze();
+                }
+            }
+            BountyEscrowEvent::EscrowUnfrozen {
+                bounty_id,
+                restored_status,
+                ..
+            } => {
+                if let Some(record) = self.escrows.get_mut(bounty_id) {
+                    record.status = *restored_status;
+                    record.frozen_from = None;
+                }
+            }
+            BountyEscrowEvent::Lifecycle(event) => {
+                if event.kind == LifecycleKind::Locked {
+                    let record =
+                        self.escrows
+                            .entry(event.bounty_id)
+                            .or_insert_with(|| EscrowRecord {
+                                depositor: event.depositor.clone(),
+                                amount: event.amount,
+                                remaining_amount: event.amount,
+                                status: EscrowStatus::Locked,
+                                deadline: None,
+                                pending_claim: None,
+                                frozen_from: None,
+                            });
+                    record.amount = event.amount;
+                }
+                let Some(record) = self.escrows.get_mut(&event.bounty_id) else {
+                    return;
+                };
+                match event.kind {
+                    LifecycleKind::ClaimAuthorized => {
+                        if let Some(recipient) = &event.recipient {
+                            record.pending_claim = Some((recipient.clone(), event.amount));
+                        }
+                    }
+                    LifecycleKind::Claimed | LifecycleKind::ClaimCancelled => {
+                        record.pending_claim = None;
+                    }
+                    LifecycleKind::Frozen => record.freeze(),
+                    LifecycleKind::Unfrozen => record.frozen_from = None,
+                    _ => {}
+                }
+                record.depositor = event.depositor.clone();
+                record.remaining_amount = event.remaining_amount;
+                record.status = event.status;
+            }
+            // Capability use is followed by the release, refund or claim
+            // event it authorised, which carries the state change.
+            _ => {}
+        }
+    }
+}
+
+impl EscrowRecord {
+    fn freeze(&mut self) {
+        if self.status != EscrowStatus::Frozen {
+            self.frozen_from = Some(self.status);
+            self.status = EscrowStatus::Frozen;
+        }
+    }
+}
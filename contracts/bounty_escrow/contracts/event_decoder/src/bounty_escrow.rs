@@ -0,0 +1,1035 @@
+//! Events of the bounty escrow contract (`contracts/escrow/src/events.rs`).
+//!
+//! Addresses are kept in strkey form. Both the v2 events and the v3
+//! lifecycle events are decoded; a contract on the `Dual` schema publishes
+//! both for the same change.
+
+use stellar_xdr::curr::ScVal;
+
+use crate::scval::{self, Fields};
+use crate::{DecodeError, RawEvent};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EscrowStatus {
+    Locked,
+    Released,
+    Refunded,
+    PartiallyRefunded,
+    Frozen,
+}
+
+impl EscrowStatus {
+    fn parse(name: &str) -> Result<Self, DecodeError> {
+        Ok(match name {
+            "Locked" => EscrowStatus::Locked,
+            "Released" => EscrowStatus::Released,
+            "Refunded" => EscrowStatus::Refunded,
+            "PartiallyRefunded" => EscrowStatus::PartiallyRefunded,
+            "Frozen" => EscrowStatus::Frozen,
+            other => return Err(DecodeError::UnknownVariant(other.to_string())),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CapabilityAction {
+    Claim,
+    Release,
+    Refund,
+}
+
+impl CapabilityAction {
+    fn parse(name: &str) -> Result<Self, DecodeError> {
+        Ok(match name {
+            "Claim" => CapabilityAction::Claim,
+            "Release" => CapabilityAction::Release,
+            "Refund" => CapabilityAction::Refund,
+            other => return Err(DecodeError::UnknownVariant(other.to_string())),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeOperation {
+    Lock,
+    Release,
+}
+
+impl FeeOperation {
+    fn parse(name: &str) -> Result<Self, DecodeError> {
+        Ok(match name {
+            "Lock" => FeeOperation::Lock,
+            "Release" => FeeOperation::Release,
+            other => return Err(DecodeError::UnknownVariant(other.to_string())),
+        })
+    }
+}
+
+/// Party a share of a split fee is credited to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FeeParty {
+    Platform,
+    Maintainer,
+    Referrer,
+}
+
+impl FeeParty {
+    fn parse(name: &str) -> Result<Self, DecodeError> {
+        Ok(match name {
+            "Platform" => FeeParty::Platform,
+            "Maintainer" => FeeParty::Maintainer,
+            "Referrer" => FeeParty::Referrer,
+            other => return Err(DecodeError::UnknownVariant(other.to_string())),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DeadlineMode {
+    Absolute(u64),
+    OpenEnded,
+    AfterAssignment(u64),
+    Inactivity(u64),
+}
+
+impl DeadlineMode {
+    fn parse(val: &ScVal) -> Result<Self, DecodeError> {
+        let items = scval::tuple(val)?;
+        let name = scval::as_variant(val, "mode")?;
+        let period = || scval::as_u64(scval::element(items, 1)?, "mode");
+        Ok(match name.as_str() {
+            "Absolute" => DeadlineMode::Absolute(period()?),
+            "OpenEnded" => DeadlineMode::OpenEnded,
+            "AfterAssignment" => DeadlineMode::AfterAssignment(period()?),
+            "Inactivity" => DeadlineMode::Inactivity(period()?),
+            other => return Err(DecodeError::UnknownVariant(other.to_string())),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessRole {
+    Depositor,
+    Recipient,
+}
+
+impl AccessRole {
+    fn parse(name: &str) -> Result<Self, DecodeError> {
+        Ok(match name {
+            "Depositor" => AccessRole::Depositor,
+            "Recipient" => AccessRole::Recipient,
+            other => return Err(DecodeError::UnknownVariant(other.to_string())),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessMode {
+    Open,
+    AllowList,
+    DenyList,
+}
+
+impl AccessMode {
+    fn parse(name: &str) -> Result<Self, DecodeError> {
+        Ok(match name {
+            "Open" => AccessMode::Open,
+            "AllowList" => AccessMode::AllowList,
+            "DenyList" => AccessMode::DenyList,
+            other => return Err(DecodeError::UnknownVariant(other.to_string())),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AccessListKind {
+    Allow,
+    Deny,
+}
+
+impl AccessListKind {
+    fn parse(name: &str) -> Result<Self, DecodeError> {
+        Ok(match name {
+            "Allow" => AccessListKind::Allow,
+            "Deny" => AccessListKind::Deny,
+            other => return Err(DecodeError::UnknownVariant(other.to_string())),
+        })
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum EventSchema {
+    V2,
+    Dual,
+    V3,
+}
+
+/// Kind of a v3 lifecycle event, taken from the payload's `kind` field.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LifecycleKind {
+    Locked,
+    Released,
+    PartiallyReleased,
+    Refunded,
+    RefundApproved,
+    ClaimAuthorized,
+    Claimed,
+    ClaimCancelled,
+    Frozen,
+    Unfrozen,
+}
+
+impl LifecycleKind {
+    fn parse(name: &str) -> Result<Self, DecodeError> {
+        Ok(match name {
+            "Locked" => LifecycleKind::Locked,
+            "Released" => LifecycleKind::Released,
+            "PartiallyReleased" => LifecycleKind::PartiallyReleased,
+            "Refunded" => LifecycleKind::Refunded,
+            "RefundApproved" => LifecycleKind::RefundApproved,
+            "ClaimAuthorized" => LifecycleKind::ClaimAuthorized,
+            "Claimed" => LifecycleKind::Claimed,
+            "ClaimCancelled" => LifecycleKind::ClaimCancelled,
+            "Frozen" => LifecycleKind::Frozen,
+            "Unfrozen" => LifecycleKind::Unfrozen,
+            other => return Err(DecodeError::UnknownVariant(other.to_string())),
+        })
+    }
+}
+
+/// Payload of a v3 lifecycle event. `remaining_amount` and `status` are the
+/// escrow's state after the change.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LifecycleEvent {
+    pub version: u32,
+    pub kind: LifecycleKind,
+    pub bounty_id: u64,
+    pub depositor: String,
+    pub recipient: Option<String>,
+    /// `None` for a permissionless refund after the deadline.
+    pub actor: Option<String>,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    pub status: EscrowStatus,
+    pub timestamp: u64,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum BountyEscrowEvent {
+    Initialized {
+        version: u32,
+        admin: String,
+        token: String,
+        timestamp: u64,
+    },
+    FundsLocked {
+        version: u32,
+        bounty_id: u64,
+        amount: i128,
+        depositor: String,
+        deadline: u64,
+    },
+    FundsReleased {
+        version: u32,
+        bounty_id: u64,
+        amount: i128,
+        recipient: String,
+        timestamp: u64,
+    },
+    FundsRefunded {
+        version: u32,
+        bounty_id: u64,
+        amount: i128,
+        refund_to: String,
+        timestamp: u64,
+    },
+    FeeCollected {
+        operation: FeeOperation,
+        amount: i128,
+        fee_rate: i128,
+        recipient: String,
+        timestamp: u64,
+    },
+    FeeConfigUpdated {
+        lock_fee_rate: i128,
+        release_fee_rate: i128,
+        fee_recipient: String,
+        fee_enabled: bool,
+        timestamp: u64,
+    },
+    FeeScheduleUpdated {
+        version: u32,
+        /// Number of tiers; 0 when the schedule was cleared.
+        tier_count: u32,
+        min_fee: i128,
+        max_fee: Option<i128>,
+        cleared: bool,
+        updated_by: String,
+        timestamp: u64,
+    },
+    /// Rates are `None` when the override was removed.
+    FeeOverrideUpdated {
+        version: u32,
+        depositor: String,
+        lock_fee_rate: Option<i128>,
+        release_fee_rate: Option<i128>,
+        updated_by: String,
+        timestamp: u64,
+    },
+    /// Shares in basis points; all zero when the split was cleared.
+    FeeSplitUpdated {
+        version: u32,
+        platform_bps: i128,
+        maintainer_bps: i128,
+        referrer_bps: i128,
+        cleared: bool,
+        updated_by: String,
+        timestamp: u64,
+    },
+    /// A share of a fee credited to `owner`'s claimable balance.
+    FeeShareAccrued {
+        version: u32,
+        bounty_id: u64,
+        owner: String,
+        party: FeeParty,
+        amount: i128,
+        operation: FeeOperation,
+        timestamp: u64,
+    },
+    FeesWithdrawn {
+        version: u32,
+        owner: String,
+        amount: i128,
+        timestamp: u64,
+    },
+    /// `treasury` is `None` when the treasury was removed.
+    RepoTreasuryUpdated {
+        version: u32,
+        repo_id: u64,
+        treasury: Option<String>,
+        updated_by: String,
+        timestamp: u64,
+    },
+    ReferrerAttached {
+        version: u32,
+        bounty_id: u64,
+        referrer: String,
+        timestamp: u64,
+    },
+    BatchLocked {
+        count: u32,
+        total_amount: i128,
+        timestamp: u64,
+    },
+    BatchReleased {
+        count: u32,
+        total_amount: i128,
+        timestamp: u64,
+    },
+    BatchRefunded {
+        count: u32,
+        total_amount: i128,
+        timestamp: u64,
+    },
+    BatchPartiallyReleased {
+        count: u32,
+        total_amount: i128,
+        timestamp: u64,
+    },
+    /// `total_amount` is the sum of the authorised claim amounts.
+    BatchClaimsAuthorized {
+        count: u32,
+        total_amount: i128,
+        timestamp: u64,
+    },
+    /// One item of a best-effort batch; `operation` is `lock` or `release`.
+    BatchItemProcessed {
+        operation: String,
+        bounty_id: u64,
+        ok: bool,
+        error_code: u32,
+        timestamp: u64,
+    },
+    BatchSummary {
+        operation: String,
+        attempted: u32,
+        succeeded: u32,
+        failed: u32,
+        total_amount: i128,
+        timestamp: u64,
+    },
+    ApprovalAdded {
+        bounty_id: u64,
+        contributor: String,
+        approver: String,
+        timestamp: u64,
+    },
+    ClaimCreated {
+        bounty_id: u64,
+        recipient: String,
+        amount: i128,
+        expires_at: u64,
+    },
+    ClaimExecuted {
+        bounty_id: u64,
+        recipient: String,
+        amount: i128,
+        claimed_at: u64,
+    },
+    ClaimCancelled {
+        bounty_id: u64,
+        recipient: String,
+        amount: i128,
+        cancelled_at: u64,
+        cancelled_by: String,
+    },
+    ClaimRedirected {
+        bounty_id: u64,
+        recipient: String,
+        amount: i128,
+        payout_to: String,
+        redirected_at: u64,
+    },
+    ClaimExpired {
+        bounty_id: u64,
+        recipient: String,
+        amount: i128,
+        expires_at: u64,
+        swept_at: u64,
+    },
+    PauseChanged {
+        operation: String,
+        paused: bool,
+        admin: String,
+        timestamp: u64,
+    },
+    EmergencyWithdraw {
+        admin: String,
+        recipient: String,
+        amount: i128,
+        timestamp: u64,
+    },
+    CapabilityIssued {
+        capability_id: u64,
+        owner: String,
+        holder: String,
+        action: CapabilityAction,
+        bounty_id: u64,
+        amount_limit: i128,
+        expires_at: u64,
+        max_uses: u32,
+        timestamp: u64,
+    },
+    CapabilityUsed {
+        capability_id: u64,
+        holder: String,
+        action: CapabilityAction,
+        bounty_id: u64,
+        amount_used: i128,
+        remaining_amount: i128,
+        remaining_uses: u32,
+        used_at: u64,
+    },
+    CapabilityRevoked {
+        capability_id: u64,
+        owner: String,
+        revoked_at: u64,
+    },
+    EscrowFrozen {
+        version: u32,
+        bounty_id: u64,
+        reason: String,
+        frozen_by: String,
+        remaining_amount: i128,
+        timestamp: u64,
+    },
+    EscrowUnfrozen {
+        version: u32,
+        bounty_id: u64,
+        reason: String,
+        unfrozen_by: String,
+        restored_status: EscrowStatus,
+        frozen_at: u64,
+        timestamp: u64,
+    },
+    DenyListUpdated {
+        version: u32,
+        address: String,
+        listed: bool,
+        reason_code: u32,
+        updated_by: String,
+        timestamp: u64,
+    },
+    DeadlineModeUpdated {
+        version: u32,
+        bounty_id: u64,
+        mode: DeadlineMode,
+        updated_by: String,
+        timestamp: u64,
+    },
+    ContributorAssigned {
+        version: u32,
+        bounty_id: u64,
+        contributor: String,
+        assigned_by: String,
+        assigned_at: u64,
+    },
+    EscrowImported {
+        version: u32,
+        bounty_id: u64,
+        depositor: String,
+        amount: i128,
+        remaining_amount: i128,
+        deadline: u64,
+        /// Legacy escrow contract the escrow was imported from.
+        source: String,
+        imported_by: String,
+        timestamp: u64,
+    },
+    AccessModeUpdated {
+        version: u32,
+        role: AccessRole,
+        mode: AccessMode,
+        updated_by: String,
+        timestamp: u64,
+    },
+    AccessListUpdated {
+        version: u32,
+        role: AccessRole,
+        list: AccessListKind,
+        address: String,
+        listed: bool,
+        updated_by: String,
+        timestamp: u64,
+    },
+    HookFailed {
+        hook: String,
+        kind: String,
+        bounty_id: u64,
+        timestamp: u64,
+    },
+    EventSchemaUpdated {
+        version: u32,
+        schema: EventSchema,
+        updated_by: String,
+        timestamp: u64,
+    },
+    ContractUpgraded {
+        version: u32,
+        /// Hex-encoded hash of the new contract code.
+        wasm_hash: String,
+        from_version: u32,
+        upgraded_by: String,
+        timestamp: u64,
+    },
+    ContractMigrated {
+        version: u32,
+        from_version: u32,
+        to_version: u32,
+        total_liabilities: i128,
+        migrated_by: String,
+        timestamp: u64,
+    },
+    /// A v3 lifecycle event (`lock_v3`, `rel_v3`, ...).
+    Lifecycle(LifecycleEvent),
+    /// A topic this decoder does not model, such as configuration events.
+    Other { name: String, data: ScVal },
+}
+
+impl BountyEscrowEvent {
+    /// Bounty the event concerns, for per-escrow events.
+    pub fn bounty_id(&self) -> Option<u64> {
+        match self {
+            BountyEscrowEvent::FundsLocked { bounty_id, .. }
+            | BountyEscrowEvent::FundsReleased { bounty_id, .. }
+            | BountyEscrowEvent::FundsRefunded { bounty_id, .. }
+            | BountyEscrowEvent::ApprovalAdded { bounty_id, .. }
+            | BountyEscrowEvent::ClaimCreated { bounty_id, .. }
+            | BountyEscrowEvent::ClaimExecuted { bounty_id, .. }
+            | BountyEscrowEvent::ClaimCancelled { bounty_id, .. }
+            | BountyEscrowEvent::ClaimRedirected { bounty_id, .. }
+            | BountyEscrowEvent::ClaimExpired { bounty_id, .. }
+            | BountyEscrowEvent::CapabilityIssued { bounty_id, .. }
+            | BountyEscrowEvent::CapabilityUsed { bounty_id, .. }
+            | BountyEscrowEvent::EscrowFrozen { bounty_id, .. }
+            | BountyEscrowEvent::EscrowUnfrozen { bounty_id, .. }
+            | BountyEscrowEvent::BatchItemProcessed { bounty_id, .. }
+            | BountyEscrowEvent::DeadlineModeUpdated { bounty_id, .. }
+            | BountyEscrowEvent::ContributorAssigned { bounty_id, .. }
+            | BountyEscrowEvent::FeeShareAccrued { bounty_id, .. }
+            | BountyEscrowEvent::ReferrerAttached { bounty_id, .. }
+            | BountyEscrowEvent::EscrowImported { bounty_id, .. }
+            | BountyEscrowEvent::HookFailed { bounty_id, .. } => Some(*bounty_id),
+            BountyEscrowEvent::Lifecycle(event) => Some(event.bounty_id),
+            _ => None,
+        }
+    }
+}
+
+/// Decode one bounty escrow event.
+pub fn decode(event: &RawEvent) -> Result<BountyEscrowEvent, DecodeError> {
+    let name = event.name()?;
+    let data = &event.data;
+    match name.as_str() {
+        "init" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::Initialized {
+                version: f.version()?,
+                admin: f.address("admin")?,
+                token: f.address("token")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "f_lock" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::FundsLocked {
+                version: f.version()?,
+                bounty_id: f.u64("bounty_id")?,
+                amount: f.i128("amount")?,
+                depositor: f.address("depositor")?,
+                deadline: f.u64("deadline")?,
+            })
+        }
+        "f_rel" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::FundsReleased {
+                version: f.version()?,
+                bounty_id: f.u64("bounty_id")?,
+                amount: f.i128("amount")?,
+                recipient: f.address("recipient")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "f_ref" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::FundsRefunded {
+                version: f.version()?,
+                bounty_id: f.u64("bounty_id")?,
+                amount: f.i128("amount")?,
+                refund_to: f.address("refund_to")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "fee" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::FeeCollected {
+                operation: FeeOperation::parse(&f.variant("operation_type")?)?,
+                amount: f.i128("amount")?,
+                fee_rate: f.i128("fee_rate")?,
+                recipient: f.address("recipient")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "fee_cfg" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::FeeConfigUpdated {
+                lock_fee_rate: f.i128("lock_fee_rate")?,
+                release_fee_rate: f.i128("release_fee_rate")?,
+                fee_recipient: f.address("fee_recipient")?,
+                fee_enabled: f.bool("fee_enabled")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "fee_sched" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::FeeScheduleUpdated {
+                version: f.version()?,
+                tier_count: f.u32("tier_count")?,
+                min_fee: f.i128("min_fee")?,
+                max_fee: f.opt_i128("max_fee")?,
+                cleared: f.bool("cleared")?,
+                updated_by: f.address("updated_by")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "fee_ovr" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::FeeOverrideUpdated {
+                version: f.version()?,
+                depositor: f.address("depositor")?,
+                lock_fee_rate: f.opt_i128("lock_fee_rate")?,
+                release_fee_rate: f.opt_i128("release_fee_rate")?,
+                updated_by: f.address("updated_by")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "fee_split" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::FeeSplitUpdated {
+                version: f.version()?,
+                platform_bps: f.i128("platform_bps")?,
+                maintainer_bps: f.i128("maintainer_bps")?,
+                referrer_bps: f.i128("referrer_bps")?,
+                cleared: f.bool("cleared")?,
+                updated_by: f.address("updated_by")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "fee_share" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::FeeShareAccrued {
+                version: f.version()?,
+                bounty_id: f.u64("bounty_id")?,
+                owner: f.address("owner")?,
+                party: FeeParty::parse(&f.variant("party")?)?,
+                amount: f.i128("amount")?,
+                operation: FeeOperation::parse(&f.variant("operation_type")?)?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "fee_wdraw" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::FeesWithdrawn {
+                version: f.version()?,
+                owner: f.address("owner")?,
+                amount: f.i128("amount")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "repo_trsy" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::RepoTreasuryUpdated {
+                version: f.version()?,
+                repo_id: f.u64("repo_id")?,
+                treasury: f.opt_address("treasury")?,
+                updated_by: f.address("updated_by")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "referral" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::ReferrerAttached {
+                version: f.version()?,
+                bounty_id: f.u64("bounty_id")?,
+                referrer: f.address("referrer")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "b_lock" | "b_rel" | "b_ref" | "b_prel" | "b_claims" => {
+            let f = Fields::new(data)?;
+            let (count, total_amount, timestamp) = (
+                f.u32("count")?,
+                f.i128("total_amount")?,
+                f.u64("timestamp")?,
+            );
+            Ok(match name.as_str() {
+                "b_lock" => BountyEscrowEvent::BatchLocked {
+                    count,
+                    total_amount,
+                    timestamp,
+                },
+                "b_rel" => BountyEscrowEvent::BatchReleased {
+                    count,
+                    total_amount,
+                    timestamp,
+                },
+                "b_ref" => BountyEscrowEvent::BatchRefunded {
+                    count,
+                    total_amount,
+                    timestamp,
+                },
+                "b_prel" => BountyEscrowEvent::BatchPartiallyReleased {
+                    count,
+                    total_amount,
+                    timestamp,
+                },
+                _ => BountyEscrowEvent::BatchClaimsAuthorized {
+                    count,
+                    total_amount,
+                    timestamp,
+                },
+            })
+        }
+        "b_item" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::BatchItemProcessed {
+                operation: f.string("operation")?,
+                bounty_id: f.u64("bounty_id")?,
+                ok: f.bool("ok")?,
+                error_code: f.u32("error_code")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "b_summary" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::BatchSummary {
+                operation: f.string("operation")?,
+                attempted: f.u32("attempted")?,
+                succeeded: f.u32("succeeded")?,
+                failed: f.u32("failed")?,
+                total_amount: f.i128("total_amount")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "approval" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::ApprovalAdded {
+                bounty_id: f.u64("bounty_id")?,
+                contributor: f.address("contributor")?,
+                approver: f.address("approver")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "claim" => decode_claim(event),
+        "pause" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::PauseChanged {
+                operation: f.string("operation")?,
+                paused: f.bool("paused")?,
+                admin: f.address("admin")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "em_wtd" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::EmergencyWithdraw {
+                admin: f.address("admin")?,
+                recipient: f.address("recipient")?,
+                amount: f.i128("amount")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "cap_new" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::CapabilityIssued {
+                capability_id: f.u64("capability_id")?,
+                owner: f.address("owner")?,
+                holder: f.address("holder")?,
+                action: CapabilityAction::parse(&f.variant("action")?)?,
+                bounty_id: f.u64("bounty_id")?,
+                amount_limit: f.i128("amount_limit")?,
+                expires_at: f.u64("expires_at")?,
+                max_uses: f.u32("max_uses")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "cap_use" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::CapabilityUsed {
+                capability_id: f.u64("capability_id")?,
+                holder: f.address("holder")?,
+                action: CapabilityAction::parse(&f.variant("action")?)?,
+                bounty_id: f.u64("bounty_id")?,
+                amount_used: f.i128("amount_used")?,
+                remaining_amount: f.i128("remaining_amount")?,
+                remaining_uses: f.u32("remaining_uses")?,
+                used_at: f.u64("used_at")?,
+            })
+        }
+        "cap_rev" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::CapabilityRevoked {
+                capability_id: f.u64("capability_id")?,
+                owner: f.address("owner")?,
+                revoked_at: f.u64("revoked_at")?,
+            })
+        }
+        "frozen" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::EscrowFrozen {
+                version: f.version()?,
+                bounty_id: f.u64("bounty_id")?,
+                reason: f.string("reason")?,
+                frozen_by: f.address("frozen_by")?,
+                remaining_amount: f.i128("remaining_amount")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "unfrozen" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::EscrowUnfrozen {
+                version: f.version()?,
+                bounty_id: f.u64("bounty_id")?,
+                reason: f.string("reason")?,
+                unfrozen_by: f.address("unfrozen_by")?,
+                restored_status: EscrowStatus::parse(&f.variant("restored_status")?)?,
+                frozen_at: f.u64("frozen_at")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "deny" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::DenyListUpdated {
+                version: f.version()?,
+                address: f.address("address")?,
+                listed: f.bool("listed")?,
+                reason_code: f.u32("reason_code")?,
+                updated_by: f.address("updated_by")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "dl_mode" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::DeadlineModeUpdated {
+                version: f.version()?,
+                bounty_id: f.u64("bounty_id")?,
+                mode: DeadlineMode::parse(f.get("mode")?)?,
+                updated_by: f.address("updated_by")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "assigned" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::ContributorAssigned {
+                version: f.version()?,
+                bounty_id: f.u64("bounty_id")?,
+                contributor: f.address("contributor")?,
+                assigned_by: f.address("assigned_by")?,
+                assigned_at: f.u64("assigned_at")?,
+            })
+        }
+        "imported" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::EscrowImported {
+                version: f.version()?,
+                bounty_id: f.u64("bounty_id")?,
+                depositor: f.address("depositor")?,
+                amount: f.i128("amount")?,
+                remaining_amount: f.i128("remaining_amount")?,
+                deadline: f.u64("deadline")?,
+                source: f.address("source")?,
+                imported_by: f.address("imported_by")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "acc_mode" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::AccessModeUpdated {
+                version: f.version()?,
+                role: AccessRole::parse(&f.variant("role")?)?,
+                mode: AccessMode::parse(&f.variant("mode")?)?,
+                updated_by: f.address("updated_by")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "acc_list" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::AccessListUpdated {
+                version: f.version()?,
+                role: AccessRole::parse(&f.variant("role")?)?,
+                list: AccessListKind::parse(&f.variant("list")?)?,
+                address: f.address("address")?,
+                listed: f.bool("listed")?,
+                updated_by: f.address("updated_by")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "hook_fail" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::HookFailed {
+                hook: f.address("hook")?,
+                kind: f.string("kind")?,
+                bounty_id: f.u64("bounty_id")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "ev_schema" => {
+            let f = Fields::new(data)?;
+            let schema = match f.variant("schema")?.as_str() {
+                "V2" => EventSchema::V2,
+                "Dual" => EventSchema::Dual,
+                "V3" => EventSchema::V3,
+                other => return Err(DecodeError::UnknownVariant(other.to_string())),
+            };
+            Ok(BountyEscrowEvent::EventSchemaUpdated {
+                version: f.version()?,
+                schema,
+                updated_by: f.address("updated_by")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "upgraded" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::ContractUpgraded {
+                version: f.version()?,
+                wasm_hash: f.hex("wasm_hash")?,
+                from_version: f.u32("from_version")?,
+                upgraded_by: f.address("upgraded_by")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "migrated" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::ContractMigrated {
+                version: f.version()?,
+                from_version: f.u32("from_version")?,
+                to_version: f.u32("to_version")?,
+                total_liabilities: f.i128("total_liabilities")?,
+                migrated_by: f.address("migrated_by")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "lock_v3" | "rel_v3" | "prel_v3" | "ref_v3" | "refapr_v3" | "clmaut_v3" | "clm_v3"
+        | "clmcan_v3" | "frz_v3" | "unfrz_v3" => {
+            let f = Fields::new(data)?;
+            Ok(BountyEscrowEvent::Lifecycle(LifecycleEvent {
+                version: f.version()?,
+                kind: LifecycleKind::parse(&f.variant("kind")?)?,
+                bounty_id: f.u64("bounty_id")?,
+                depositor: f.address("depositor")?,
+                recipient: f.opt_address("recipient")?,
+                actor: f.opt_address("actor")?,
+                amount: f.i128("amount")?,
+                remaining_amount: f.i128("remaining_amount")?,
+                status: EscrowStatus::parse(&f.variant("status")?)?,
+                timestamp: f.u64("timestamp")?,
+            }))
+        }
+        _ => Ok(BountyEscrowEvent::Other {
+            name,
+            data: data.clone(),
+        }),
+    }
+}
+
+/// Claim events share the `claim` topic and are told apart by the second.
+fn decode_claim(event: &RawEvent) -> Result<BountyEscrowEvent, DecodeError> {
+    let stage = event
+        .topics
+        .get(1)
+        .and_then(scval::symbol)
+        .ok_or(DecodeError::UnexpectedShape("claim stage topic"))?;
+    let f = Fields::new(&event.data)?;
+    let bounty_id = f.u64("bounty_id")?;
+    let recipient = f.address("recipient")?;
+    let amount = f.i128("amount")?;
+    match stage.as_str() {
+        "created" => Ok(BountyEscrowEvent::ClaimCreated {
+            bounty_id,
+            recipient,
+            amount,
+            expires_at: f.u64("expires_at")?,
+        }),
+        "done" => Ok(BountyEscrowEvent::ClaimExecuted {
+            bounty_id,
+            recipient,
+            amount,
+            claimed_at: f.u64("claimed_at")?,
+        }),
+        "cancel" => Ok(BountyEscrowEvent::ClaimCancelled {
+            bounty_id,
+            recipient,
+            amount,
+            cancelled_at: f.u64("cancelled_at")?,
+            cancelled_by: f.address("cancelled_by")?,
+        }),
+        "redirect" => Ok(BountyEscrowEvent::ClaimRedirected {
+            bounty_id,
+            recipient,
+            amount,
+            payout_to: f.address("payout_to")?,
+            redirected_at: f.u64("redirected_at")?,
+        }),
+        "expired" => Ok(BountyEscrowEvent::ClaimExpired {
+            bounty_id,
+            recipient,
+            amount,
+            expires_at: f.u64("expires_at")?,
+            swept_at: f.u64("swept_at")?,
+        }),
+        _ => Ok(BountyEscrowEvent::Other {
+            name: format!("claim/{stage}"),
+            data: event.data.clone(),
+        }),
+    }
+}
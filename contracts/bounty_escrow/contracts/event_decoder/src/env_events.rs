@@ -0,0 +1,38 @@
+//! Reading events straight from a test `Env`.
+
+use soroban_sdk::testutils::Events;
+use soroban_sdk::xdr::ScVal;
+use soroban_sdk::{Address, Env, TryFromVal, Val};
+
+use crate::{scval, RawEvent};
+
+fn to_scval(env: &Env, val: &Val) -> ScVal {
+    ScVal::try_from_val(env, val).expect("event value converts to ScVal")
+}
+
+fn strkey(env: &Env, address: &Address) -> String {
+    scval::as_address(&to_scval(env, address.as_val()), "contract")
+        .expect("address converts to strkey")
+}
+
+/// Every contract event recorded by `env` so far, in emission order.
+pub fn all(env: &Env) -> Vec<RawEvent> {
+    env.events()
+        .all()
+        .iter()
+        .map(|(contract, topics, data)| RawEvent {
+            contract_id: Some(strkey(env, &contract)),
+            topics: topics.iter().map(|topic| to_scval(env, &topic)).collect(),
+            data: to_scval(env, &data),
+        })
+        .collect()
+}
+
+/// Events recorded by `env` that were emitted by `contract`.
+pub fn from_contract(env: &Env, contract: &Address) -> Vec<RawEvent> {
+    let id = strkey(env, contract);
+    all(env)
+        .into_iter()
+        .filter(|event| event.is_from(&id))
+        .collect()
+}
@@ -0,0 +1,159 @@
+//! Events of the grainlify-core contract: monitoring metrics, migrations,
+//! governance and multisig proposals.
+//!
+//! Enum values such as proposal status and vote type are returned as their
+//! variant names.
+
+use stellar_xdr::curr::ScVal;
+
+use crate::scval::{self, Fields};
+use crate::{DecodeError, RawEvent};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum GrainlifyCoreEvent {
+    OperationMetric {
+        operation: String,
+        caller: String,
+        timestamp: u64,
+        success: bool,
+    },
+    PerformanceMetric {
+        function: String,
+        duration: u64,
+        timestamp: u64,
+    },
+    Migration {
+        from_version: u32,
+        to_version: u32,
+        timestamp: u64,
+        /// Hex-encoded migration hash.
+        migration_hash: String,
+        success: bool,
+        error_message: Option<String>,
+    },
+    ProposalCreated {
+        id: u32,
+        proposer: String,
+        /// Hex-encoded wasm hash the proposal would upgrade to.
+        new_wasm_hash: String,
+        created_at: u64,
+        voting_end: u64,
+        status: String,
+    },
+    VoteCast {
+        voter: String,
+        proposal_id: u32,
+        vote_type: String,
+        voting_power: i128,
+        timestamp: u64,
+    },
+    ProposalFinalized {
+        proposal_id: u32,
+        status: String,
+        votes_for: i128,
+        votes_against: i128,
+        votes_abstain: i128,
+    },
+    MultisigProposed {
+        proposal_id: u64,
+    },
+    MultisigApproved {
+        proposal_id: u64,
+        signer: String,
+    },
+    MultisigExecuted {
+        proposal_id: u64,
+    },
+    Other {
+        name: String,
+        data: ScVal,
+    },
+}
+
+/// Decode one grainlify-core event.
+pub fn decode(event: &RawEvent) -> Result<GrainlifyCoreEvent, DecodeError> {
+    let name = event.name()?;
+    let data = &event.data;
+    match name.as_str() {
+        "metric" => {
+            let kind = event.topics.get(1).and_then(scval::symbol);
+            let f = Fields::new(data)?;
+            match kind.as_deref() {
+                Some("op") => Ok(GrainlifyCoreEvent::OperationMetric {
+                    operation: f.string("operation")?,
+                    caller: f.address("caller")?,
+                    timestamp: f.u64("timestamp")?,
+                    success: f.bool("success")?,
+                }),
+                Some("perf") => Ok(GrainlifyCoreEvent::PerformanceMetric {
+                    function: f.string("function")?,
+                    duration: f.u64("duration")?,
+                    timestamp: f.u64("timestamp")?,
+                }),
+                _ => Ok(GrainlifyCoreEvent::Other {
+                    name,
+                    data: data.clone(),
+                }),
+            }
+        }
+        "migration" => {
+            let f = Fields::new(data)?;
+            Ok(GrainlifyCoreEvent::Migration {
+                from_version: f.u32("from_version")?,
+                to_version: f.u32("to_version")?,
+                timestamp: f.u64("timestamp")?,
+                migration_hash: f.hex("migration_hash")?,
+                success: f.bool("success")?,
+                error_message: f.opt_string("error_message")?,
+            })
+        }
+        "gov_prop" => {
+            let f = Fields::new(data)?;
+            Ok(GrainlifyCoreEvent::ProposalCreated {
+                id: f.u32("id")?,
+                proposer: f.address("proposer")?,
+                new_wasm_hash: f.hex("new_wasm_hash")?,
+                created_at: f.u64("created_at")?,
+                voting_end: f.u64("voting_end")?,
+                status: f.variant("status")?,
+            })
+        }
+        "gov_vote" => {
+            let f = Fields::new(data)?;
+            Ok(GrainlifyCoreEvent::VoteCast {
+                voter: f.address("voter")?,
+                proposal_id: f.u32("proposal_id")?,
+                vote_type: f.variant("vote_type")?,
+                voting_power: f.i128("voting_power")?,
+                timestamp: f.u64("timestamp")?,
+            })
+        }
+        "gov_final" => {
+            let t = scval::tuple(data)?;
+            Ok(GrainlifyCoreEvent::ProposalFinalized {
+                proposal_id: scval::as_u32(scval::element(t, 0)?, "proposal_id")?,
+                status: scval::as_variant(scval::element(t, 1)?, "status")?,
+                votes_for: scval::as_i128(scval::element(t, 2)?, "votes_for")?,
+                votes_against: scval::as_i128(scval::element(t, 3)?, "votes_against")?,
+                votes_abstain: scval::as_i128(scval::element(t, 4)?, "votes_abstain")?,
+            })
+        }
+        "proposal" => Ok(GrainlifyCoreEvent::MultisigProposed {
+            proposal_id: scval::as_u64(data, "proposal_id")?,
+        }),
+        "approved" => {
+            let t = scval::tuple(data)?;
+            Ok(GrainlifyCoreEvent::MultisigApproved {
+                proposal_id: scval::as_u64(scval::element(t, 0)?, "proposal_id")?,
+                signer: scval::as_address(scval::element(t, 1)?, "signer")?,
+            })
+        }
+        "executed" => Ok(GrainlifyCoreEvent::MultisigExecuted {
+            proposal_id: scval::as_u64(data, "proposal_id")?,
+        }),
+        _ => Ok(GrainlifyCoreEvent::Other {
+            name,
+            data: data.clone(),
+        }),
+    }
+}
@@ -0,0 +1,145 @@
+//! # Escrow Event Decoder
+//!
+//! Off-chain decoding of the events published by the bounty escrow,
+//! program escrow and grainlify-core contracts.
+//!
+//! Events arrive as raw `ScVal` topics and data, either from a test `Env`
+//! (see [`env_events`], behind the `testutils` feature) or from exported JSON
+//! such as the `test_snapshots` files. [`bounty_escrow::decode`],
+//! [`program_escrow::decode`] and [`grainlify_core::decode`] turn them into
+//! typed enums, and [`book::EscrowBook`] folds bounty escrow events into a
+//! reconstructed view of every escrow.
+//!
+//! Struct payloads are read by field name, so fields added to an event in a
+//! later contract version do not break decoding. Topics a decoder does not
+//! recognise come back as an `Other` variant rather than an error.
+
+use std::fmt;
+
+use stellar_xdr::curr::{
+    ContractEvent, ContractEventBody, ContractEventType, Hash, ScAddress, ScVal,
+};
+
+pub mod book;
+pub mod bounty_escrow;
+#[cfg(any(test, feature = "testutils"))]
+pub mod env_events;
+pub mod grainlify_core;
+pub mod program_escrow;
+mod scval;
+
+#[cfg(test)]
+mod test;
+
+/// A contract event before decoding.
+#[derive(Clone, Debug, PartialEq)]
+pub struct RawEvent {
+    /// Strkey (`C...`) of the emitting contract, when known.
+    pub contract_id: Option<String>,
+    pub topics: Vec<ScVal>,
+    pub data: ScVal,
+}
+
+impl RawEvent {
+    /// First topic as a symbol, which every contract here uses as the event
+    /// name.
+    pub fn name(&self) -> Result<String, DecodeError> {
+        let first = self.topics.first().ok_or(DecodeError::NoTopics)?;
+        scval::symbol(first).ok_or(DecodeError::UnexpectedShape("symbol topic"))
+    }
+
+    /// Whether the event was emitted by `contract_id` (a `C...` strkey).
+    pub fn is_from(&self, contract_id: &str) -> bool {
+        self.contract_id.as_deref() == Some(contract_id)
+    }
+}
+
+impl From<&ContractEvent> for RawEvent {
+    fn from(event: &ContractEvent) -> Self {
+        let ContractEventBody::V0(body) = &event.body;
+        Self {
+            contract_id: event.contract_id.as_ref().map(contract_strkey),
+            topics: body.topics.to_vec(),
+            data: body.data.clone(),
+        }
+    }
+}
+
+pub(crate) fn contract_strkey(hash: &Hash) -> String {
+    ScAddress::Contract(hash.clone()).to_string()
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DecodeError {
+    /// The event has no topics, so it cannot be identified.
+    NoTopics,
+    /// A topic or payload was not the expected kind of value.
+    UnexpectedShape(&'static str),
+    /// A struct field or tuple element is absent.
+    MissingField(String),
+    /// A field holds a value of the wrong type.
+    WrongType {
+        field: String,
+        expected: &'static str,
+    },
+    /// An enum value names a variant this decoder does not know.
+    UnknownVariant(String),
+    /// The JSON input could not be parsed.
+    Json(String),
+}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DecodeError::NoTopics => write!(f, "event has no topics"),
+            DecodeError::UnexpectedShape(expected) => write!(f, "expected {expected}"),
+            DecodeError::MissingField(field) => write!(f, "missing field `{field}`"),
+            DecodeError::WrongType { field, expected } => {
+                write!(f, "field `{field}` is not a {expected}")
+            }
+            DecodeError::UnknownVariant(name) => write!(f, "unknown variant `{name}`"),
+            DecodeError::Json(message) => write!(f, "invalid event JSON: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for DecodeError {}
+
+/// Contract events from exported JSON.
+///
+/// Accepts either a test snapshot (an object whose `events` array holds
+/// `{ "event": ..., "failed_call": ... }` entries) or a plain array of
+/// `ContractEvent`s. Diagnostic and system events, and events from failed
+/// calls, are dropped.
+pub fn from_snapshot_json(json: &str) -> Result<Vec<RawEvent>, DecodeError> {
+    let value: serde_json::Value =
+        serde_json::from_str(json).map_err(|e| DecodeError::Json(e.to_string()))?;
+
+    let entries = match &value {
+        serde_json::Value::Object(map) => map
+            .get("events")
+            .and_then(|events| events.as_array())
+            .ok_or(DecodeError::Json("missing `events` array".into()))?,
+        serde_json::Value::Array(entries) => entries,
+        _ => return Err(DecodeError::Json("expected an object or array".into())),
+    };
+
+    let mut events = Vec::new();
+    for entry in entries {
+        let event = match entry.get("event") {
+            Some(event) => {
+                if entry.get("failed_call").and_then(|f| f.as_bool()) == Some(true) {
+                    continue;
+                }
+                event
+            }
+            None => entry,
+        };
+        let event: ContractEvent =
+            serde_json::from_value(event.clone()).map_err(|e| DecodeError::Json(e.to_string()))?;
+        if event.type_ == ContractEventType::Contract {
+            events.push(RawEvent::from(&event));
+        }
+    }
+    Ok(events)
+}
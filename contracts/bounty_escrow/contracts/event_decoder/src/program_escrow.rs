@@ -0,0 +1,243 @@
+//! Events of the program escrow contract (`contracts/program-escrow`).
+//!
+//! The contract has published the initialisation and lock events under two
+//! spellings (`PrgInit`/`ProgInit`, `FndsLock`/`FundLock`); both decode to the
+//! same variant. Tuple payloads are read by position.
+
+use stellar_xdr::curr::ScVal;
+
+use crate::scval::{self, Fields};
+use crate::{DecodeError, RawEvent};
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProgramEscrowEvent {
+    ProgramInitialized {
+        version: u32,
+        program_id: String,
+        authorized_payout_key: String,
+        token_address: String,
+        total_funds: i128,
+    },
+    ProgramRegistered {
+        program_id: String,
+        authorized_payout_key: String,
+        token_address: String,
+    },
+    FundsLocked {
+        version: u32,
+        program_id: String,
+        amount: i128,
+        remaining_balance: i128,
+    },
+    BatchPayout {
+        version: u32,
+        program_id: String,
+        recipient_count: u32,
+        total_amount: i128,
+        remaining_balance: i128,
+    },
+    Payout {
+        version: u32,
+        program_id: String,
+        recipient: String,
+        amount: i128,
+        remaining_balance: i128,
+    },
+    ClaimCreated {
+        program_id: String,
+        claim_id: u64,
+        recipient: String,
+        amount: i128,
+        deadline: u64,
+    },
+    ClaimExecuted {
+        program_id: String,
+        claim_id: u64,
+        recipient: String,
+        amount: i128,
+    },
+    ClaimCancelled {
+        program_id: String,
+        claim_id: u64,
+        recipient: String,
+        amount: i128,
+    },
+    PauseChanged {
+        operation: String,
+        paused: bool,
+        admin: String,
+        timestamp: u64,
+    },
+    EmergencyWithdraw {
+        admin: String,
+        target: String,
+        amount: i128,
+        timestamp: u64,
+    },
+    DependencyAdded {
+        program_id: String,
+        dependency_id: String,
+    },
+    DependencyCleared {
+        program_id: String,
+        dependency_id: String,
+    },
+    DependencyStatusUpdated {
+        dependency_id: String,
+        status: String,
+    },
+    /// Circuit breaker transition; `kind` is the second topic.
+    Circuit {
+        kind: String,
+        value: u32,
+        timestamp: u64,
+    },
+    Other {
+        name: String,
+        data: ScVal,
+    },
+}
+
+/// Decode one program escrow event.
+pub fn decode(event: &RawEvent) -> Result<ProgramEscrowEvent, DecodeError> {
+    let name = event.name()?;
+    let data = &event.data;
+    match name.as_str() {
+        "PrgInit" | "ProgInit" => {
+            let f = Fields::new(data)?;
+            Ok(ProgramEscrowEvent::ProgramInitialized {
+                version: f.version()?,
+                program_id: f.string("program_id")?,
+                authorized_payout_key: f.address("authorized_payout_key")?,
+                token_address: f.address("token_address")?,
+                total_funds: f.i128("total_funds")?,
+            })
+        }
+        "ProgRgd" => {
+            let t = scval::tuple(data)?;
+            Ok(ProgramEscrowEvent::ProgramRegistered {
+                program_id: scval::as_string(scval::element(t, 0)?, "program_id")?,
+                authorized_payout_key: scval::as_address(
+                    scval::element(t, 1)?,
+                    "authorized_payout_key",
+                )?,
+                token_address: scval::as_address(scval::element(t, 2)?, "token_address")?,
+            })
+        }
+        "FndsLock" | "FundLock" => {
+            let f = Fields::new(data)?;
+            Ok(ProgramEscrowEvent::FundsLocked {
+                version: f.version()?,
+                program_id: f.string("program_id")?,
+                amount: f.i128("amount")?,
+                remaining_balance: f.i128("remaining_balance")?,
+            })
+        }
+        "BatchPay" => {
+            let f = Fields::new(data)?;
+            Ok(ProgramEscrowEvent::BatchPayout {
+                version: f.version()?,
+                program_id: f.string("program_id")?,
+                recipient_count: f.u32("recipient_count")?,
+                total_amount: f.i128("total_amount")?,
+                remaining_balance: f.i128("remaining_balance")?,
+            })
+        }
+        "Payout" => {
+            let f = Fields::new(data)?;
+            Ok(ProgramEscrowEvent::Payout {
+                version: f.version()?,
+                program_id: f.string("program_id")?,
+                recipient: f.address("recipient")?,
+                amount: f.i128("amount")?,
+                remaining_balance: f.i128("remaining_balance")?,
+            })
+        }
+        "ClmCrtd" | "ClmExec" | "ClmCncl" => {
+            let t = scval::tuple(data)?;
+            let program_id = scval::as_string(scval::element(t, 0)?, "program_id")?;
+            let claim_id = scval::as_u64(scval::element(t, 1)?, "claim_id")?;
+            let recipient = scval::as_address(scval::element(t, 2)?, "recipient")?;
+            let amount = scval::as_i128(scval::element(t, 3)?, "amount")?;
+            Ok(match name.as_str() {
+                "ClmCrtd" => ProgramEscrowEvent::ClaimCreated {
+                    program_id,
+                    claim_id,
+                    recipient,
+                    amount,
+                    deadline: scval::as_u64(scval::element(t, 4)?, "deadline")?,
+                },
+                "ClmExec" => ProgramEscrowEvent::ClaimExecuted {
+                    program_id,
+                    claim_id,
+                    recipient,
+                    amount,
+                },
+                _ => ProgramEscrowEvent::ClaimCancelled {
+                    program_id,
+                    claim_id,
+                    recipient,
+                    amount,
+                },
+            })
+        }
+        "PauseSt" => {
+            let t = scval::tuple(data)?;
+            Ok(ProgramEscrowEvent::PauseChanged {
+                operation: scval::as_string(scval::element(t, 0)?, "operation")?,
+                paused: scval::as_bool(scval::element(t, 1)?, "paused")?,
+                admin: scval::as_address(scval::element(t, 2)?, "admin")?,
+                timestamp: scval::as_u64(scval::element(t, 4)?, "timestamp")?,
+            })
+        }
+        "em_wtd" => {
+            let t = scval::tuple(data)?;
+            Ok(ProgramEscrowEvent::EmergencyWithdraw {
+                admin: scval::as_address(scval::element(t, 0)?, "admin")?,
+                target: scval::as_address(scval::element(t, 1)?, "target")?,
+                amount: scval::as_i128(scval::element(t, 2)?, "amount")?,
+                timestamp: scval::as_u64(scval::element(t, 3)?, "timestamp")?,
+            })
+        }
+        "dep_add" | "dep_clr" => {
+            let t = scval::tuple(data)?;
+            let program_id = scval::as_string(scval::element(t, 0)?, "program_id")?;
+            let dependency_id = scval::as_string(scval::element(t, 1)?, "dependency_id")?;
+            Ok(if name == "dep_add" {
+                ProgramEscrowEvent::DependencyAdded {
+                    program_id,
+                    dependency_id,
+                }
+            } else {
+                ProgramEscrowEvent::DependencyCleared {
+                    program_id,
+                    dependency_id,
+                }
+            })
+        }
+        "dep_sts" => {
+            let t = scval::tuple(data)?;
+            Ok(ProgramEscrowEvent::DependencyStatusUpdated {
+                dependency_id: scval::as_string(scval::element(t, 0)?, "dependency_id")?,
+                status: scval::as_variant(scval::element(t, 1)?, "status")?,
+            })
+        }
+        "circuit" => {
+            let kind = event
+                .topics
+                .get(1)
+                .and_then(scval::symbol)
+                .ok_or(DecodeError::UnexpectedShape("circuit kind topic"))?;
+            let t = scval::tuple(data)?;
+            Ok(ProgramEscrowEvent::Circuit {
+                kind,
+                value: scval::as_u32(scval::element(t, 0)?, "value")?,
+                timestamp: scval::as_u64(scval::element(t, 1)?, "timestamp")?,
+            })
+        }
+        _ => Ok(ProgramEscrowEvent::Other {
+            name,
+            data: data.clone(),
+        }),
+    }
+}
@@ -0,0 +1,198 @@
+//! Field access over `ScVal` payloads.
+//!
+//! Contract structs are encoded as `ScVal::Map` keyed by field-name symbols,
+//! tuples as `ScVal::Vec` and unit enum variants as a one-element vector
+//! holding the variant name. Lookups are by name, so fields added to an event
+//! later are ignored instead of breaking decoding.
+
+use crate::DecodeError;
+use stellar_xdr::curr::{ScMap, ScVal};
+
+pub(crate) fn symbol(val: &ScVal) -> Option<String> {
+    match val {
+        ScVal::Symbol(s) => Some(s.to_utf8_string_lossy()),
+        _ => None,
+    }
+}
+
+fn wrong_type(field: &str, expected: &'static str) -> DecodeError {
+    DecodeError::WrongType {
+        field: field.to_string(),
+        expected,
+    }
+}
+
+pub(crate) fn as_u32(val: &ScVal, field: &str) -> Result<u32, DecodeError> {
+    match val {
+        ScVal::U32(v) => Ok(*v),
+        _ => Err(wrong_type(field, "u32")),
+    }
+}
+
+pub(crate) fn as_u64(val: &ScVal, field: &str) -> Result<u64, DecodeError> {
+    match val {
+        ScVal::U64(v) => Ok(*v),
+        _ => Err(wrong_type(field, "u64")),
+    }
+}
+
+pub(crate) fn as_i128(val: &ScVal, field: &str) -> Result<i128, DecodeError> {
+    match val {
+        ScVal::I128(parts) => Ok(((parts.hi as i128) << 64) | parts.lo as i128),
+        _ => Err(wrong_type(field, "i128")),
+    }
+}
+
+pub(crate) fn as_bool(val: &ScVal, field: &str) -> Result<bool, DecodeError> {
+    match val {
+        ScVal::Bool(v) => Ok(*v),
+        _ => Err(wrong_type(field, "bool")),
+    }
+}
+
+/// Strkey (`G...` / `C...`) form of an address value.
+pub(crate) fn as_address(val: &ScVal, field: &str) -> Result<String, DecodeError> {
+    match val {
+        ScVal::Address(addr) => Ok(addr.to_string()),
+        _ => Err(wrong_type(field, "address")),
+    }
+}
+
+pub(crate) fn as_opt_address(val: &ScVal, field: &str) -> Result<Option<String>, DecodeError> {
+    match val {
+        ScVal::Void => Ok(None),
+        other => as_address(other, field).map(Some),
+    }
+}
+
+pub(crate) fn as_string(val: &ScVal, field: &str) -> Result<String, DecodeError> {
+    match val {
+        ScVal::String(s) => Ok(s.to_utf8_string_lossy()),
+        ScVal::Symbol(s) => Ok(s.to_utf8_string_lossy()),
+        _ => Err(wrong_type(field, "string")),
+    }
+}
+
+/// Lower-case hex of a bytes value, such as a wasm hash.
+pub(crate) fn as_hex(val: &ScVal, field: &str) -> Result<String, DecodeError> {
+    match val {
+        ScVal::Bytes(bytes) => Ok(bytes.iter().map(|b| format!("{b:02x}")).collect()),
+        _ => Err(wrong_type(field, "bytes")),
+    }
+}
+
+/// Name of a `#[contracttype]` enum variant.
+pub(crate) fn as_variant(val: &ScVal, field: &str) -> Result<String, DecodeError> {
+    match val {
+        ScVal::Vec(Some(items)) => items
+            .first()
+            .and_then(symbol)
+            .ok_or_else(|| wrong_type(field, "enum variant")),
+        _ => Err(wrong_type(field, "enum variant")),
+    }
+}
+
+/// Elements of a tuple payload.
+pub(crate) fn tuple(val: &ScVal) -> Result<&[ScVal], DecodeError> {
+    match val {
+        ScVal::Vec(Some(items)) => Ok(items.as_slice()),
+        _ => Err(DecodeError::UnexpectedShape("tuple")),
+    }
+}
+
+pub(crate) fn element(items: &[ScVal], index: usize) -> Result<&ScVal, DecodeError> {
+    items
+        .get(index)
+        .ok_or_else(|| DecodeError::MissingField(format!("#{index}")))
+}
+
+/// Named fields of a struct payload.
+pub(crate) struct Fields<'a> {
+    map: &'a ScMap,
+}
+
+impl<'a> Fields<'a> {
+    pub(crate) fn new(val: &'a ScVal) -> Result<Self, DecodeError> {
+        match val {
+            ScVal::Map(Some(map)) => Ok(Self { map }),
+            _ => Err(DecodeError::UnexpectedShape("struct")),
+        }
+    }
+
+    fn find(&self, name: &str) -> Option<&'a ScVal> {
+        self.map
+            .iter()
+            .find(|entry| symbol(&entry.key).as_deref() == Some(name))
+            .map(|entry| &entry.val)
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Result<&'a ScVal, DecodeError> {
+        self.find(name)
+            .ok_or_else(|| DecodeError::MissingField(name.to_string()))
+    }
+
+    pub(crate) fn u32(&self, name: &str) -> Result<u32, DecodeError> {
+        as_u32(self.get(name)?, name)
+    }
+
+    pub(crate) fn u64(&self, name: &str) -> Result<u64, DecodeError> {
+        as_u64(self.get(name)?, name)
+    }
+
+    pub(crate) fn i128(&self, name: &str) -> Result<i128, DecodeError> {
+        as_i128(self.get(name)?, name)
+    }
+
+    /// Optional amount; `None` when the field is void or absent.
+    pub(crate) fn opt_i128(&self, name: &str) -> Result<Option<i128>, DecodeError> {
+        match self.find(name) {
+            Some(ScVal::Void) | None => Ok(None),
+            Some(val) => as_i128(val, name).map(Some),
+        }
+    }
+
+    pub(crate) fn bool(&self, name: &str) -> Result<bool, DecodeError> {
+        as_bool(self.get(name)?, name)
+    }
+
+    pub(crate) fn address(&self, name: &str) -> Result<String, DecodeError> {
+        as_address(self.get(name)?, name)
+    }
+
+    /// Optional address; a field absent from older event versions reads as
+    /// `None`.
+    pub(crate) fn opt_address(&self, name: &str) -> Result<Option<String>, DecodeError> {
+        match self.find(name) {
+            Some(val) => as_opt_address(val, name),
+            None => Ok(None),
+        }
+    }
+
+    pub(crate) fn string(&self, name: &str) -> Result<String, DecodeError> {
+        as_string(self.get(name)?, name)
+    }
+
+    pub(crate) fn hex(&self, name: &str) -> Result<String, DecodeError> {
+        as_hex(self.get(name)?, name)
+    }
+
+    /// Optional string; `None` when the field is void or absent.
+    pub(crate) fn opt_string(&self, name: &str) -> Result<Option<String>, DecodeError> {
+        match self.find(name) {
+            Some(ScVal::Void) | None => Ok(None),
+            Some(val) => as_string(val, name).map(Some),
+        }
+    }
+
+    pub(crate) fn variant(&self, name: &str) -> Result<String, DecodeError> {
+        as_variant(self.get(name)?, name)
+    }
+
+    /// `version` tag, or 1 for payloads that predate versioning.
+    pub(crate) fn version(&self) -> Result<u32, DecodeError> {
+        match self.find("version") {
+            Some(val) => as_u32(val, "version"),
+            None => Ok(1),
+        }
+    }
+}
@@ -0,0 +1,716 @@
+use bounty_escrow::{
+    BountyEscrowContract, BountyEscrowContractClient, EscrowStatus as ContractStatus, EventSchema,
+    RefundMode,
+};
+use soroban_sdk::{
+    contract, contractimpl, testutils::Address as _, token, xdr, Address, Env, String as SdkString,
+};
+use stellar_xdr::curr::{Int128Parts, ScMap, ScMapEntry, ScString, ScSymbol, ScVal};
+
+use crate::book::EscrowBook;
+use crate::bounty_escrow::{self as be, BountyEscrowEvent, EscrowStatus, LifecycleKind};
+use crate::grainlify_core::{self as gc, GrainlifyCoreEvent};
+use crate::program_escrow::{self as pe, ProgramEscrowEvent};
+use crate::{env_events, from_snapshot_json, DecodeError, RawEvent};
+
+/// Legacy escrow contract holding one `Locked` escrow, for imports.
+#[contract]
+struct LegacyEscrowStandIn;
+
+#[contractimpl]
+impl LegacyEscrowStandIn {
+    pub fn hold(env: Env, escrow: bounty_escrow::LegacyEscrowState) {
+        env.storage().instance().set(&0u32, &escrow);
+    }
+
+    pub fn get_escrow(env: Env, _bounty_id: u64) -> bounty_escrow::LegacyEscrowState {
+        env.storage().instance().get(&0u32).unwrap()
+    }
+
+    pub fn mark_migrated(env: Env, _bounty_ids: soroban_sdk::Vec<u64>, _to: Address) -> i128 {
+        Self::get_escrow(env, 0).remaining_amount
+    }
+}
+
+struct DecoderSetup {
+    env: Env,
+    client: BountyEscrowContractClient<'static>,
+    contract_id: Address,
+    depositor: Address,
+    contributor: Address,
+}
+
+impl DecoderSetup {
+    fn new() -> Self {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let depositor = Address::generate(&env);
+        let contributor = Address::generate(&env);
+
+        let token_admin_addr = Address::generate(&env);
+        let token_address = env
+            .register_stellar_asset_contract_v2(token_admin_addr.clone())
+            .address();
+        let token_admin = token::StellarAssetClient::new(&env, &token_address);
+
+        let contract_id = env.register_contract(None, BountyEscrowContract);
+        let client = BountyEscrowContractClient::new(&env, &contract_id);
+
+        client.init(&admin, &token_address);
+        client.set_claim_window(&3_600);
+        token_admin.mint(&depositor, &1_000_000);
+
+        Self {
+            env,
+            client,
+            contract_id,
+            depositor,
+            contributor,
+        }
+    }
+
+    fn lock(&self, bounty_id: u64, amount: i128) {
+        let deadline = self.env.ledger().timestamp() + 1_000;
+        self.client
+            .lock_funds(&self.depositor, &bounty_id, &amount, &deadline);
+    }
+
+    /// Run the same mix of payouts on bounties 1 to 4.
+    fn run_lifecycle(&self) {
+        self.lock(1, 1_000);
+        self.lock(2, 800);
+        self.lock(3, 500);
+        self.lock(4, 300);
+
+        self.client.partial_release(&1, &self.contributor, &400);
+
+        self.client
+            .approve_refund(&2, &300, &self.depositor, &RefundMode::Partial);
+        self.client.refund(&2);
+
+        self.client
+            .authorize_partial_claim(&3, &self.contributor, &200);
+        self.client.claim(&3);
+        self.client.authorize_claim(&3, &self.contributor);
+        self.client.claim(&3);
+
+        self.client
+            .freeze_escrow(&4, &SdkString::from_str(&self.env, "review"));
+    }
+
+    fn book(&self) -> EscrowBook {
+        let events = env_events::from_contract(&self.env, &self.contract_id);
+        let id = events[0].contract_id.clone().unwrap();
+        EscrowBook::from_events(&id, &events).unwrap()
+    }
+
+    /// Every escrow in the book matches the contract's own view of it.
+    fn assert_book_matches(&self, book: &EscrowBook) {
+        for (bounty_id, record) in book.iter() {
+            let escrow = self.client.get_escrow_info(&bounty_id);
+            assert_eq!(record.amount, escrow.amount, "bounty {bounty_id}");
+            assert_eq!(
+                record.remaining_amount, escrow.remaining_amount,
+                "bounty {bounty_id}"
+            );
+            assert_eq!(
+                record.status,
+                status_of(&escrow.status),
+                "bounty {bounty_id}"
+            );
+            assert_eq!(record.depositor, strkey(&self.env, &escrow.depositor));
+        }
+        assert_eq!(
+            book.total_liabilities(),
+            self.client.verify_solvency().total_liabilities
+        );
+    }
+}
+
+fn status_of(status: &ContractStatus) -> EscrowStatus {
+    match status {
+        ContractStatus::Locked => EscrowStatus::Locked,
+        ContractStatus::Released => EscrowStatus::Released,
+        ContractStatus::Refunded => EscrowStatus::Refunded,
+        ContractStatus::PartiallyRefunded => EscrowStatus::PartiallyRefunded,
+        ContractStatus::Frozen => EscrowStatus::Frozen,
+    }
+}
+
+fn strkey(env: &Env, address: &Address) -> String {
+    use soroban_sdk::TryFromVal;
+    match xdr::ScVal::try_from_val(env, address.as_val()).unwrap() {
+        xdr::ScVal::Address(address) => address.to_string(),
+        _ => unreachable!(),
+    }
+}
+
+fn sym(name: &str) -> ScVal {
+    ScVal::Symbol(ScSymbol(name.try_into().unwrap()))
+}
+
+fn i128_val(value: i128) -> ScVal {
+    ScVal::I128(Int128Parts {
+        hi: (value >> 64) as i64,
+        lo: value as u64,
+    })
+}
+
+fn struct_val(fields: Vec<(&str, ScVal)>) -> ScVal {
+    let entries: Vec<ScMapEntry> = fields
+        .into_iter()
+        .map(|(key, val)| ScMapEntry { key: sym(key), val })
+        .collect();
+    ScVal::Map(Some(ScMap(entries.try_into().unwrap())))
+}
+
+fn tuple_val(items: Vec<ScVal>) -> ScVal {
+    ScVal::Vec(Some(items.try_into().unwrap()))
+}
+
+fn string_val(value: &str) -> ScVal {
+    ScVal::String(ScString(value.try_into().unwrap()))
+}
+
+fn variant_val(name: &str) -> ScVal {
+    tuple_val(vec![sym(name)])
+}
+
+fn raw(topics: Vec<ScVal>, data: ScVal) -> RawEvent {
+    RawEvent {
+        contract_id: None,
+        topics,
+        data,
+    }
+}
+
+#[test]
+fn test_v2_events_rebuild_escrow_book() {
+    let setup = DecoderSetup::new();
+    setup.run_lifecycle();
+
+    let book = setup.book();
+    assert_eq!(book.len(), 4);
+    setup.assert_book_matches(&book);
+
+    assert_eq!(book.get(1).unwrap().remaining_amount, 600);
+    assert_eq!(book.get(2).unwrap().status, EscrowStatus::PartiallyRefunded);
+    assert_eq!(book.get(3).unwrap().pending_claim, None);
+    assert_eq!(book.get(4).unwrap().frozen_from, Some(EscrowStatus::Locked));
+    assert_eq!(
+        book.get(1).unwrap().deadline,
+        Some(setup.env.ledger().timestamp() + 1_000)
+    );
+}
+
+#[test]
+fn test_dual_schema_folds_to_the_same_book() {
+    let setup = DecoderSetup::new();
+    setup.client.set_event_schema(&EventSchema::Dual);
+    setup.run_lifecycle();
+
+    setup.client.unfreeze_escrow(&4);
+    setup.client.release_funds(&4, &setup.contributor);
+
+    let book = setup.book();
+    setup.assert_book_matches(&book);
+    assert_eq!(book.get(4).unwrap().status, EscrowStatus::Released);
+    assert_eq!(book.get(4).unwrap().frozen_from, None);
+}
+
+#[test]
+fn test_v3_events_carry_exact_refund_status() {
+    let setup = DecoderSetup::new();
+    setup.client.set_event_schema(&EventSchema::V3);
+    setup.lock(1, 1_000);
+
+    // A full-mode approval for part of the escrow closes it with funds left,
+    // which only the v3 payload can express.
+    setup
+        .client
+        .approve_refund(&1, &400, &setup.depositor, &RefundMode::Full);
+    setup.client.refund(&1);
+
+    let book = setup.book();
+    setup.assert_book_matches(&book);
+    let record = book.get(1).unwrap();
+    assert_eq!(record.status, EscrowStatus::Refunded);
+    assert_eq!(record.remaining_amount, 600);
+    assert_eq!(record.deadline, None);
+}
+
+#[test]
+fn test_decodes_typed_bounty_events() {
+    let setup = DecoderSetup::new();
+    setup.client.set_event_schema(&EventSchema::Dual);
+    setup.lock(7, 250);
+    setup.client.migrate();
+
+    let events = env_events::from_contract(&setup.env, &setup.contract_id);
+    let decoded: Vec<BountyEscrowEvent> = events.iter().map(|e| be::decode(e).unwrap()).collect();
+
+    let depositor = strkey(&setup.env, &setup.depositor);
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::FundsLocked { bounty_id: 7, amount: 250, depositor: d, version: 2, .. }
+            if *d == depositor
+    )));
+    let lifecycle = decoded
+        .iter()
+        .find_map(|event| match event {
+            BountyEscrowEvent::Lifecycle(event) => Some(event),
+            _ => None,
+        })
+        .unwrap();
+    assert_eq!(lifecycle.kind, LifecycleKind::Locked);
+    assert_eq!(lifecycle.version, 3);
+    assert_eq!(lifecycle.recipient, None);
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::ContractMigrated {
+            from_version: 2,
+            to_version: 2,
+            total_liabilities: 250,
+            ..
+        }
+    )));
+    assert!(decoded
+        .iter()
+        .all(|event| !matches!(event, BountyEscrowEvent::Other { .. })));
+}
+
+#[test]
+fn test_decodes_best_effort_batch_events() {
+    let setup = DecoderSetup::new();
+    setup.lock(1, 100);
+    let item = |bounty_id: u64, amount: i128| bounty_escrow::LockFundsItem {
+        bounty_id,
+        depositor: setup.depositor.clone(),
+        amount,
+        deadline: setup.env.ledger().timestamp() + 1_000,
+    };
+    setup
+        .client
+        .batch_lock_funds_best_effort(&soroban_sdk::vec![&setup.env, item(2, 400), item(1, 100),]);
+
+    let events = env_events::from_contract(&setup.env, &setup.contract_id);
+    let decoded: Vec<BountyEscrowEvent> = events.iter().map(|e| be::decode(e).unwrap()).collect();
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::BatchItemProcessed { operation, bounty_id: 1, ok: false, error_code: 3, .. }
+            if operation == "lock"
+    )));
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::BatchSummary {
+            attempted: 2,
+            succeeded: 1,
+            failed: 1,
+            total_amount: 400,
+            ..
+        }
+    )));
+    assert!(decoded
+        .iter()
+        .all(|event| !matches!(event, BountyEscrowEvent::Other { .. })));
+    setup.assert_book_matches(&setup.book());
+}
+
+#[test]
+fn test_decodes_deadline_mode_and_assignment_events() {
+    let setup = DecoderSetup::new();
+    setup.client.lock_funds_with_deadline_mode(
+        &setup.depositor,
+        &3,
+        &500,
+        &bounty_escrow::DeadlineMode::Inactivity(600),
+    );
+    setup.client.assign_contributor(&3, &setup.contributor);
+
+    let events = env_events::from_contract(&setup.env, &setup.contract_id);
+    let decoded: Vec<BountyEscrowEvent> = events.iter().map(|e| be::decode(e).unwrap()).collect();
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::DeadlineModeUpdated {
+            bounty_id: 3,
+            mode: be::DeadlineMode::Inactivity(600),
+            ..
+        }
+    )));
+    let contributor = strkey(&setup.env, &setup.contributor);
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::ContributorAssigned { bounty_id: 3, contributor: c, .. }
+            if *c == contributor
+    )));
+    setup.assert_book_matches(&setup.book());
+}
+
+#[test]
+fn test_imported_escrows_enter_the_book() {
+    let setup = DecoderSetup::new();
+    let source = setup.env.register_contract(None, LegacyEscrowStandIn);
+    let deadline = setup.env.ledger().timestamp() + 2_000;
+    LegacyEscrowStandInClient::new(&setup.env, &source).hold(&bounty_escrow::LegacyEscrowState {
+        depositor: setup.contributor.clone(),
+        amount: 900,
+        remaining_amount: 700,
+        status: bounty_escrow::LegacyEscrowStatus::Locked,
+        deadline,
+    });
+    setup.client.import_escrows(
+        &source,
+        &setup.depositor,
+        &soroban_sdk::vec![
+            &setup.env,
+            bounty_escrow::LegacyEscrow {
+                bounty_id: 11,
+                depositor: setup.contributor.clone(),
+                amount: 900,
+                remaining_amount: 700,
+                status: bounty_escrow::LegacyEscrowStatus::Locked,
+                deadline,
+            },
+        ],
+    );
+
+    let events = env_events::from_contract(&setup.env, &setup.contract_id);
+    let decoded: Vec<BountyEscrowEvent> = events.iter().map(|e| be::decode(e).unwrap()).collect();
+    let source = strkey(&setup.env, &source);
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::EscrowImported { bounty_id: 11, remaining_amount: 700, source: s, .. }
+            if *s == source
+    )));
+
+    let book = setup.book();
+    setup.assert_book_matches(&book);
+    assert_eq!(book.get(11).unwrap().deadline, Some(deadline));
+}
+
+#[test]
+fn test_decodes_fee_split_events() {
+    let setup = DecoderSetup::new();
+    let env = &setup.env;
+    let platform = Address::generate(env);
+    let treasury = Address::generate(env);
+    let referrer = Address::generate(env);
+    setup.client.update_fee_config(
+        &Some(1_000),
+        &Some(500),
+        &Some(platform.clone()),
+        &Some(true),
+    );
+    setup
+        .client
+        .set_fee_schedule(&Some(bounty_escrow::FeeSchedule {
+            tiers: soroban_sdk::vec![
+                env,
+                bounty_escrow::FeeTier {
+                    min_amount: 1_000_000,
+                    lock_fee_rate: 500,
+                    release_fee_rate: 200,
+                },
+            ],
+            min_fee: 1,
+            max_fee: None,
+        }));
+    setup.client.set_fee_override(
+        &setup.contributor,
+        &Some(bounty_escrow::FeeOverride {
+            lock_fee_rate: 0,
+            release_fee_rate: 0,
+        }),
+    );
+    setup.client.set_fee_split(&Some(bounty_escrow::FeeSplit {
+        platform_bps: 7_000,
+        maintainer_bps: 2_000,
+        referrer_bps: 1_000,
+    }));
+    setup.client.set_repo_treasury(&42, &Some(treasury.clone()));
+    setup
+        .client
+        .update_metadata(&platform, &5, &42, &1, &SdkString::from_str(env, "bug"));
+    setup.client.lock_funds_with_referrer(
+        &setup.depositor,
+        &5,
+        &10_000,
+        &(env.ledger().timestamp() + 1_000),
+        &referrer,
+    );
+    setup.client.release_funds(&5, &setup.contributor);
+    setup.client.withdraw_fees(&treasury);
+
+    let events = env_events::from_contract(env, &setup.contract_id);
+    let decoded: Vec<BountyEscrowEvent> = events.iter().map(|e| be::decode(e).unwrap()).collect();
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::FeeScheduleUpdated {
+            tier_count: 1,
+            min_fee: 1,
+            max_fee: None,
+            cleared: false,
+            ..
+        }
+    )));
+    let contributor = strkey(env, &setup.contributor);
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::FeeOverrideUpdated { depositor, lock_fee_rate: Some(0), release_fee_rate: Some(0), .. }
+            if *depositor == contributor
+    )));
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::FeeSplitUpdated {
+            platform_bps: 7_000,
+            maintainer_bps: 2_000,
+            referrer_bps: 1_000,
+            ..
+        }
+    )));
+    let treasury = strkey(env, &treasury);
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::RepoTreasuryUpdated { repo_id: 42, treasury: Some(t), .. } if *t == treasury
+    )));
+    let referrer = strkey(env, &referrer);
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::ReferrerAttached { bounty_id: 5, referrer: r, .. } if *r == referrer
+    )));
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::FeeShareAccrued {
+            bounty_id: 5,
+            owner,
+            party: be::FeeParty::Referrer,
+            amount: 100,
+            operation: be::FeeOperation::Lock,
+            ..
+        } if *owner == referrer
+    )));
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::FeeShareAccrued {
+            party: be::FeeParty::Maintainer,
+            operation: be::FeeOperation::Release,
+            ..
+        }
+    )));
+    assert!(decoded.iter().any(|event| matches!(
+        event,
+        BountyEscrowEvent::FeesWithdrawn { owner, .. } if *owner == treasury
+    )));
+    assert!(decoded
+        .iter()
+        .all(|event| !matches!(event, BountyEscrowEvent::Other { .. })));
+
+    let book = setup.book();
+    setup.assert_book_matches(&book);
+    assert_eq!(book.get(5).unwrap().status, EscrowStatus::Released);
+}
+
+#[test]
+fn test_struct_fields_are_read_by_name() {
+    let event = raw(
+        vec![sym("b_lock")],
+        struct_val(vec![
+            ("count", ScVal::U32(2)),
+            ("future_field", sym("ignored")),
+            ("timestamp", ScVal::U64(9)),
+            ("total_amount", i128_val(-5)),
+        ]),
+    );
+    assert_eq!(
+        be::decode(&event).unwrap(),
+        BountyEscrowEvent::BatchLocked {
+            count: 2,
+            total_amount: -5,
+            timestamp: 9,
+        }
+    );
+
+    let missing = raw(
+        vec![sym("b_rel")],
+        struct_val(vec![("count", ScVal::U32(2))]),
+    );
+    assert_eq!(
+        be::decode(&missing),
+        Err(DecodeError::MissingField("total_amount".into()))
+    );
+
+    let unknown = raw(vec![sym("new_evt")], ScVal::U32(1));
+    assert!(matches!(
+        be::decode(&unknown).unwrap(),
+        BountyEscrowEvent::Other { name, .. } if name == "new_evt"
+    ));
+    assert_eq!(
+        be::decode(&raw(vec![], ScVal::Void)),
+        Err(DecodeError::NoTopics)
+    );
+}
+
+#[test]
+fn test_snapshot_json_rebuilds_released_escrow() {
+    let json = include_str!("../../escrow/test_snapshots/test/test_release_funds_success.1.json");
+    let events = from_snapshot_json(json).unwrap();
+
+    let lock = events
+        .iter()
+        .find(|event| event.name().as_deref() == Ok("f_lock"))
+        .unwrap();
+    let escrow_id = lock.contract_id.clone().unwrap();
+    assert!(escrow_id.starts_with('C'));
+
+    let book = EscrowBook::from_events(&escrow_id, &events).unwrap();
+    let record = book.get(1).unwrap();
+    assert_eq!(record.amount, 1_000);
+    assert_eq!(record.remaining_amount, 0);
+    assert_eq!(record.status, EscrowStatus::Released);
+
+    assert!(matches!(
+        from_snapshot_json("{\"ledger\": {}}"),
+        Err(DecodeError::Json(_))
+    ));
+}
+
+#[test]
+fn test_decodes_program_escrow_events() {
+    let init = raw(
+        vec![sym("PrgInit")],
+        struct_val(vec![
+            ("authorized_payout_key", sym("not an address")),
+            ("program_id", string_val("hack-1")),
+            ("total_funds", i128_val(0)),
+            ("version", ScVal::U32(2)),
+        ]),
+    );
+    assert_eq!(
+        pe::decode(&init),
+        Err(DecodeError::WrongType {
+            field: "authorized_payout_key".into(),
+            expected: "address",
+        })
+    );
+
+    for topic in ["FndsLock", "FundLock"] {
+        let lock = raw(
+            vec![sym(topic)],
+            struct_val(vec![
+                ("amount", i128_val(5_000)),
+                ("program_id", string_val("hack-1")),
+                ("remaining_balance", i128_val(5_000)),
+                ("version", ScVal::U32(2)),
+            ]),
+        );
+        assert_eq!(
+            pe::decode(&lock).unwrap(),
+            ProgramEscrowEvent::FundsLocked {
+                version: 2,
+                program_id: "hack-1".into(),
+                amount: 5_000,
+                remaining_balance: 5_000,
+            }
+        );
+    }
+
+    let dependency = raw(
+        vec![sym("dep_sts")],
+        tuple_val(vec![string_val("hack-0"), variant_val("Completed")]),
+    );
+    assert_eq!(
+        pe::decode(&dependency).unwrap(),
+        ProgramEscrowEvent::DependencyStatusUpdated {
+            dependency_id: "hack-0".into(),
+            status: "Completed".into(),
+        }
+    );
+
+    let circuit = raw(
+        vec![sym("circuit"), sym("opened")],
+        tuple_val(vec![ScVal::U32(3), ScVal::U64(77)]),
+    );
+    assert_eq!(
+        pe::decode(&circuit).unwrap(),
+        ProgramEscrowEvent::Circuit {
+            kind: "opened".into(),
+            value: 3,
+            timestamp: 77,
+        }
+    );
+}
+
+#[test]
+fn test_decodes_grainlify_core_events() {
+    let perf = raw(
+        vec![sym("metric"), sym("perf")],
+        struct_val(vec![
+            ("duration", ScVal::U64(12)),
+            ("function", sym("upgrade")),
+            ("timestamp", ScVal::U64(100)),
+        ]),
+    );
+    assert_eq!(
+        gc::decode(&perf).unwrap(),
+        GrainlifyCoreEvent::PerformanceMetric {
+            function: "upgrade".into(),
+            duration: 12,
+            timestamp: 100,
+        }
+    );
+
+    let finalized = raw(
+        vec![sym("gov_final")],
+        tuple_val(vec![
+            ScVal::U32(4),
+            variant_val("Approved"),
+            i128_val(10),
+            i128_val(2),
+            i128_val(0),
+        ]),
+    );
+    assert_eq!(
+        gc::decode(&finalized).unwrap(),
+        GrainlifyCoreEvent::ProposalFinalized {
+            proposal_id: 4,
+            status: "Approved".into(),
+            votes_for: 10,
+            votes_against: 2,
+            votes_abstain: 0,
+        }
+    );
+
+    let migration = raw(
+        vec![sym("migration")],
+        struct_val(vec![
+            ("error_message", ScVal::Void),
+            ("from_version", ScVal::U32(1)),
+            (
+                "migration_hash",
+                ScVal::Bytes(vec![0xab, 0x01].try_into().unwrap()),
+            ),
+            ("success", ScVal::Bool(true)),
+            ("timestamp", ScVal::U64(5)),
+            ("to_version", ScVal::U32(2)),
+        ]),
+    );
+    assert_eq!(
+        gc::decode(&migration).unwrap(),
+        GrainlifyCoreEvent::Migration {
+            from_version: 1,
+            to_version: 2,
+            timestamp: 5,
+            migration_hash: "ab01".into(),
+            success: true,
+            error_message: None,
+        }
+    );
+
+    let executed = raw(vec![sym("executed")], ScVal::U64(9));
+    assert_eq!(
+        gc::decode(&executed).unwrap(),
+        GrainlifyCoreEvent::MultisigExecuted { proposal_id: 9 }
+    );
+}
@@ -0,0 +1,55 @@
+//! # Escrow Interface
+//!
+//! Cross-contract interface exposed by every Grainlify escrow contract
+//! (`bounty-escrow` and `program-escrow`), so other contracts and the
+//! backend can inspect either one through `EscrowClient` without knowing
+//! which kind it is.
+//!
+//! Discovery follows the ERC-165 idea: `supports_interface(id)` answers
+//! for [`ESCROW_INTERFACE_ID`] plus the contract's own kind
+//! ([`BOUNTY_ESCROW_INTERFACE_ID`] or [`PROGRAM_ESCROW_INTERFACE_ID`]),
+//! whose contract-specific entrypoints can then be called directly.
+//! `interface_version()` reports the revision of this trait the contract
+//! implements; it only changes when methods are added here.
+//!
+//! Funding and payout entrypoints take different identifiers and error
+//! types in each contract, so they stay contract-specific.
+
+use soroban_sdk::{contractclient, symbol_short, Address, Env, Symbol};
+
+/// Implemented by every escrow contract.
+pub const ESCROW_INTERFACE_ID: Symbol = symbol_short!("escrow");
+/// Bounty escrow entrypoints (`lock_funds`, `release_funds`, ...).
+pub const BOUNTY_ESCROW_INTERFACE_ID: Symbol = symbol_short!("bounty");
+/// Program escrow entrypoints (`lock_program_funds`, `batch_payout`, ...).
+pub const PROGRAM_ESCROW_INTERFACE_ID: Symbol = symbol_short!("program");
+
+/// Revision of [`EscrowInterface`] described by this crate.
+pub const ESCROW_INTERFACE_VERSION: u32 = 1;
+
+/// Shared entrypoints of the escrow contracts.
+#[contractclient(name = "EscrowClient")]
+pub trait EscrowInterface {
+    /// Whether the contract implements the interface `interface_id`.
+    fn supports_interface(env: Env, interface_id: Symbol) -> bool;
+
+    /// Revision of `EscrowInterface` the contract implements.
+    fn interface_version(env: Env) -> u32;
+
+    /// Version of the contract code.
+    fn get_version(env: Env) -> u32;
+
+    /// Token held in escrow, or `None` before initialization.
+    fn escrow_token(env: Env) -> Option<Address>;
+
+    /// Token balance currently held by the contract.
+    fn escrow_balance(env: Env) -> i128;
+
+    /// Funds the contract still owes to depositors and recipients.
+    fn escrow_liabilities(env: Env) -> i128;
+}
+
+/// `supports_interface` for a contract whose own kind is `kind`.
+pub fn supports(interface_id: &Symbol, kind: &Symbol) -> bool {
+    *interface_id == ESCROW_INTERFACE_ID || interface_id == kind
+}
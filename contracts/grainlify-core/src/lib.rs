@@ -153,6 +153,7 @@
 #![no_std]
 
 pub mod asset;
+pub mod escrow_interface;
 pub mod nonce;
 
 mod governance;
@@ -139,121 +139,6 @@
 //! 5. **Balance Checks**: Verify remaining balance matches expectations
 //! 6. **Token Approval**: Ensure contract has token allowance before locking funds
 
-#![no_std]
-use soroban_sdk::{
-    contract, contractimpl, contracttype, symbol_short, token, vec, Address, Env, String, Symbol,
-    Vec,
-};
-
-// Event types
-const PROGRAM_INITIALIZED: Symbol = symbol_short!("ProgInit");
-const FUNDS_LOCKED: Symbol = symbol_short!("FundLock");
-const BATCH_PAYOUT: Symbol = symbol_short!("BatchPay");
-const PAYOUT: Symbol = symbol_short!("Payout");
-const DEPENDENCY_CREATED: Symbol = symbol_short!("dep_add");
-const DEPENDENCY_CLEARED: Symbol = symbol_short!("dep_clr");
-const DEPENDENCY_STATUS_UPDATED: Symbol = symbol_short!("dep_sts");
-
-// Storage keys
-const PROGRAM_DATA: Symbol = symbol_short!("ProgData");
-const FEE_CONFIG: Symbol = symbol_short!("FeeCfg");
-
-// Fee rate is stored in basis points (1 basis point = 0.01%)
-// Example: 100 basis points = 1%, 1000 basis points = 10%
-const BASIS_POINTS: i128 = 10_000;
-const MAX_FEE_RATE: i128 = 1_000; // Maximum 10% fee
-
-#[contracttype]
-#[derive(Clone, Debug, Eq, PartialEq)]
-pub struct FeeConfig {
-    pub lock_fee_rate: i128,      // Fee rate for lock operations (basis points)
-    pub payout_fee_rate: i128,     // Fee rate for payout operations (basis points)
-    pub fee_recipient: Address,    // Address to receive fees
-    pub fee_enabled: bool,         // Global fee enable/disable flag
-}
-// ==================== MONITORING MODULE ====================
-mod monitoring {
-    use soroban_sdk::{contracttype, symbol_short, Address, Env, String, Symbol};
-
-    // Storage keys
-    const OPERATION_COUNT: &str = "op_count";
-    const USER_COUNT: &str = "usr_count";
-    const ERROR_COUNT: &str = "err_count";
-
-    // Event: Operation metric
-    #[contracttype]
-    #[derive(Clone, Debug)]
-    pub struct OperationMetric {
-        pub operation: Symbol,
-        pub caller: Address,
-        pub timestamp: u64,
-        pub success: bool,
-    }
-
-    // Event: Performance metric
-    #[contracttype]
-    #[derive(Clone, Debug)]
-    pub struct PerformanceMetric {
-        pub function: Symbol,
-        pub duration: u64,
-        pub timestamp: u64,
-    }
-
-    // Data: Health status
-    #[contracttype]
-    #[derive(Clone, Debug)]
-    pub struct HealthStatus {
-        pub is_healthy: bool,
-        pub last_operation: u64,
-        pub total_operations: u64,
-        pub contract_version: String,
-    }
-
-    // Data: Analytics
-    #[contracttype]
-    #[derive(Clone, Debug)]
-    pub struct Analytics {
-        pub operation_count: u64,
-        pub unique_users: u64,
-        pub error_count: u64,
-        pub error_rate: u32,
-    }
-
-    // Data: State snapshot
-    #[contracttype]
-    #[derive(Clone, Debug)]
-    pub struct StateSnapshot {
-        pub timestamp: u64,
-        pub total_operations: u64,
-        pub total_users: u64,
-        pub total_errors: u64,
-    }
-
-    // Data: Performance stats
-    #[contracttype]
-    #[derive(Clone, Debug)]
-    pub struct PerformanceStats {
-        pub function_name: Symbol,
-        pub call_count: u64,
-        pub total_time: u64,
-        pub avg_time: u64,
-        pub last_called: u64,
-    }
-
-    // Track operation
-    pub fn track_operation(env: &Env, operation: Symbol, caller: Address, success: bool) {
-        let key = Symbol::new(env, OPERATION_COUNT);
-        let count: u64 = env.storage().persistent().get(&key).unwrap_or(0);
-        env.storage().persistent().set(&key, &(count + 1));
-
-        if !success {
-            let err_key = Symbol::new(env, ERROR_COUNT);
-            let err_count: u64 = env.storage().persistent().get(&err_key).unwrap_or(0);
-            env.storage().persistent().set(&err_key, &(err_count + 1));
-        }
-// ── Step 1: Add module declarations near the top of lib.rs ──────────────
-// (after `mod anti_abuse;` and before the contract struct)
-
 mod claim_period;
 pub mod token_math;
 pub use claim_period::{ClaimRecord, ClaimStatus};
@@ -291,103 +176,13 @@ mod test_lifecycle;
 #[cfg(test)]
 mod test_full_lifecycle;
 
-// ── Step 2: Add these public contract functions to the ProgramEscrowContract
-//    impl block (alongside the existing admin functions) ──────────────────
-
-// ========================================================================
-// Circuit Breaker Management
-// ========================================================================
-
-/// Register the circuit breaker admin. Can only be set once, or changed
-/// by the existing admin.
-///
-/// # Arguments
-/// * `new_admin` - Address to register as circuit breaker admin
-/// * `caller`    - Existing admin (None if setting for the first time)
-pub fn set_circuit_admin(env: Env, new_admin: Address, caller: Option<Address>) {
-    error_recovery::set_circuit_admin(&env, new_admin, caller);
-}
-
-/// Returns the registered circuit breaker admin, if any.
-pub fn get_circuit_admin(env: Env) -> Option<Address> {
-    error_recovery::get_circuit_admin(&env)
-}
-
-/// Returns the full circuit breaker status snapshot.
-///
-/// # Returns
-/// * `CircuitBreakerStatus` with state, failure/success counts, timestamps
-pub fn get_circuit_status(env: Env) -> error_recovery::CircuitBreakerStatus {
-    error_recovery::get_status(&env)
-}
-
-/// Admin resets the circuit breaker.
-///
-/// Transitions:
-/// - Open     → HalfOpen  (probe mode)
-/// - HalfOpen → Closed    (hard reset)
-/// - Closed   → Closed    (no-op reset)
-///
-/// # Panics
-/// * If caller is not the registered circuit breaker admin
-pub fn reset_circuit_breaker(env: Env, admin: Address) {
-    error_recovery::reset_circuit_breaker(&env, &admin);
-}
-
-/// Updates the circuit breaker configuration. Admin only.
-///
-/// # Arguments
-/// * `failure_threshold` - Consecutive failures needed to open circuit
-/// * `success_threshold` - Consecutive successes in HalfOpen to close it
-/// * `max_error_log`     - Maximum error log entries to retain
-pub fn configure_circuit_breaker(
-    env: Env,
-    admin: Address,
-    failure_threshold: u32,
-    success_threshold: u32,
-    max_error_log: u32,
-) {
-    let stored = error_recovery::get_circuit_admin(&env);
-    match stored {
-        Some(ref a) if a == &admin => {
-            admin.require_auth();
-        }
-        _ => panic!("Unauthorized: only circuit breaker admin can configure"),
-    }
-    error_recovery::set_config(
-        &env,
-        error_recovery::CircuitBreakerConfig {
-            failure_threshold,
-            success_threshold,
-            max_error_log,
-        },
-    );
-}
-
-/// Returns the error log (last N failures recorded by the circuit breaker).
-pub fn get_circuit_error_log(env: Env) -> soroban_sdk::Vec<error_recovery::ErrorEntry> {
-    error_recovery::get_error_log(&env)
-}
-
-/// Directly open the circuit (emergency lockout). Admin only.
-pub fn emergency_open_circuit(env: Env, admin: Address) {
-    let stored = error_recovery::get_circuit_admin(&env);
-    match stored {
-        Some(ref a) if a == &admin => {
-            admin.require_auth();
-        }
-        _ => panic!("Unauthorized"),
-    }
-    error_recovery::open_circuit(&env);
-}
-
 use soroban_sdk::{
     contract, contracterror, contractimpl, contracttype, symbol_short, token, vec, Address, Env,
     String, Symbol, Vec,
 };
 
 extern crate grainlify_core;
-use grainlify_core::{asset, nonce};
+use grainlify_core::escrow_interface::{self, EscrowInterface};
 
 // Event types
 const PROGRAM_INITIALIZED: Symbol = symbol_short!("PrgInit");
@@ -399,15 +194,14 @@ const PAUSE_STATE_CHANGED: Symbol = symbol_short!("PauseSt");
 const PROGRAM_REGISTRY: Symbol = symbol_short!("ProgReg");
 const PROGRAM_REGISTERED: Symbol = symbol_short!("ProgRgd");
 const FEE_CONFIG: Symbol = symbol_short!("FeeCfg");
-const BASIS_POINTS: i128 = token_math::BASIS_POINTS;
+/// Version of this contract code, reported by `get_version`.
+const CONTRACT_VERSION: u32 = 1;
 
 // Storage keys
 const PROGRAM_DATA: Symbol = symbol_short!("ProgData");
 const SCHEDULES: Symbol = symbol_short!("Scheds");
 const RELEASE_HISTORY: Symbol = symbol_short!("RelHist");
 const NEXT_SCHEDULE_ID: Symbol = symbol_short!("NxtSched");
-const PROGRAM_INDEX: Symbol = symbol_short!("ProgIdx");
-const AUTH_KEY_INDEX: Symbol = symbol_short!("AuthIdx");
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -587,62 +381,6 @@ pub struct ProgramAggregateStats {
 /// Input item for batch program registration.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub enum DataKey {
-    Program(String), // program_id -> ProgramData
-    ReleaseSchedule(String, u64), // program_id, schedule_id -> ProgramReleaseSchedule
-    ReleaseHistory(String), // program_id -> Vec<ProgramReleaseHistory>
-    NextScheduleId(String), // program_id -> next schedule_id
-    ProgramDependencies(String), // program_id -> Vec<dependency_id>
-    DependencyStatus(String), // dependency_id -> DependencyStatus
-}
-
-fn vec_contains(values: &Vec<String>, target: &String) -> bool {
-    for value in values.iter() {
-        if value == *target {
-            return true;
-        }
-    }
-    false
-}
-
-fn get_program_dependencies_internal(env: &Env, program_id: &String) -> Vec<String> {
-    env.storage()
-        .instance()
-        .get(&DataKey::ProgramDependencies(program_id.clone()))
-        .unwrap_or(vec![env])
-}
-
-fn dependency_status_internal(env: &Env, dependency_id: &String) -> DependencyStatus {
-    env.storage()
-        .instance()
-        .get(&DataKey::DependencyStatus(dependency_id.clone()))
-        .unwrap_or(DependencyStatus::Pending)
-}
-
-fn path_exists_to_target(
-    env: &Env,
-    from_program: &String,
-    target_program: &String,
-    visited: &mut Vec<String>,
-) -> bool {
-    if *from_program == *target_program {
-        return true;
-    }
-    if vec_contains(visited, from_program) {
-        return false;
-    }
-
-    visited.push_back(from_program.clone());
-    let deps = get_program_dependencies_internal(env, from_program);
-    for dep in deps.iter() {
-        if env.storage().instance().has(&DataKey::Program(dep.clone()))
-            && path_exists_to_target(env, &dep, target_program, visited)
-        {
-            return true;
-        }
-    }
-
-    false
 pub struct ProgramInitItem {
     pub program_id: String,
     pub authorized_payout_key: Address,
@@ -752,15 +490,6 @@ impl ProgramEscrowContract {
         };
 
         // Store program data
-        env.storage().instance().set(&program_key, &program_data);
-        let empty_dependencies: Vec<String> = vec![&env];
-        env.storage()
-            .instance()
-            .set(&DataKey::ProgramDependencies(program_id.clone()), &empty_dependencies);
-        env.storage().instance().set(
-            &DataKey::DependencyStatus(program_id.clone()),
-            &DependencyStatus::Pending,
-        );
         env.storage().instance().set(&PROGRAM_DATA, &program_data);
         env.storage()
             .instance()
@@ -867,23 +596,6 @@ impl ProgramEscrowContract {
         Ok(batch_size as u32)
     }
 
-    /// Calculate fee using floor rounding. Delegates to `token_math::calculate_fee`.
-    fn calculate_fee(amount: i128, fee_rate: i128) -> i128 {
-        token_math::calculate_fee(amount, fee_rate)
-    }
-
-    /// Get fee configuration (internal helper)
-    fn get_fee_config_internal(env: &Env) -> FeeConfig {
-        env.storage()
-            .instance()
-            .get(&FEE_CONFIG)
-            .unwrap_or_else(|| FeeConfig {
-                lock_fee_rate: 0,
-                payout_fee_rate: 0,
-                fee_recipient: env.current_contract_address(),
-                fee_enabled: false,
-            })
-    }
     /// Check if a program exists (legacy single-program check)
     ///
     /// # Returns
@@ -898,174 +610,6 @@ impl ProgramEscrowContract {
         env.storage().instance().has(&DataKey::Program(program_id))
     }
 
-    fn assert_dependencies_satisfied(env: &Env, program_id: &String) {
-        let dependencies = get_program_dependencies_internal(env, program_id);
-        for dependency_id in dependencies.iter() {
-            match dependency_status_internal(env, &dependency_id) {
-                DependencyStatus::Completed => {}
-                DependencyStatus::Pending => panic!("Dependency not satisfied"),
-                DependencyStatus::Failed => panic!("Dependency failed"),
-            }
-        }
-    }
-
-    /// Defines explicit dependencies for a program.
-    ///
-    /// Dependencies can point to:
-    /// - another registered program id; or
-    /// - an externally managed dependency id with a pre-registered status.
-    ///
-    /// Cycle checks are applied for program-to-program edges.
-    pub fn set_program_dependencies(
-        env: Env,
-        program_id: String,
-        dependency_ids: Vec<String>,
-    ) -> Vec<String> {
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
-
-        program_data.authorized_payout_key.require_auth();
-
-        let old_dependencies = get_program_dependencies_internal(&env, &program_id);
-        let mut validated_dependencies = vec![&env];
-
-        for dependency_id in dependency_ids.iter() {
-            if dependency_id.len() == 0 {
-                panic!("Dependency id cannot be empty");
-            }
-            if dependency_id == program_id {
-                panic!("Program cannot depend on itself");
-            }
-            if vec_contains(&validated_dependencies, &dependency_id) {
-                panic!("Duplicate dependency");
-            }
-
-            let is_program_dependency = env
-                .storage()
-                .instance()
-                .has(&DataKey::Program(dependency_id.clone()));
-            let is_registered_external = env
-                .storage()
-                .instance()
-                .has(&DataKey::DependencyStatus(dependency_id.clone()));
-            if !is_program_dependency && !is_registered_external {
-                panic!("Dependency not registered");
-            }
-
-            if is_program_dependency {
-                let mut visited = Vec::new(&env);
-                if path_exists_to_target(&env, &dependency_id, &program_id, &mut visited) {
-                    panic!("Dependency cycle detected");
-                }
-            }
-
-            validated_dependencies.push_back(dependency_id.clone());
-        }
-
-        env.storage().instance().set(
-            &DataKey::ProgramDependencies(program_id.clone()),
-            &validated_dependencies,
-        );
-
-        for dependency_id in validated_dependencies.iter() {
-            if !vec_contains(&old_dependencies, &dependency_id) {
-                env.events().publish(
-                    (DEPENDENCY_CREATED,),
-                    (program_id.clone(), dependency_id.clone()),
-                );
-            }
-        }
-        for dependency_id in old_dependencies.iter() {
-            if !vec_contains(&validated_dependencies, &dependency_id) {
-                env.events().publish(
-                    (DEPENDENCY_CLEARED,),
-                    (program_id.clone(), dependency_id.clone()),
-                );
-            }
-        }
-
-        validated_dependencies
-    }
-
-    /// Clears all dependencies for a program.
-    pub fn clear_program_dependencies(env: Env, program_id: String) {
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
-
-        program_data.authorized_payout_key.require_auth();
-
-        let old_dependencies = get_program_dependencies_internal(&env, &program_id);
-        let empty_dependencies: Vec<String> = vec![&env];
-        env.storage()
-            .instance()
-            .set(&DataKey::ProgramDependencies(program_id.clone()), &empty_dependencies);
-
-        for dependency_id in old_dependencies.iter() {
-            env.events().publish(
-                (DEPENDENCY_CLEARED,),
-                (program_id.clone(), dependency_id.clone()),
-            );
-        }
-    }
-
-    /// Reads all dependencies configured for a program.
-    pub fn get_program_dependencies(env: Env, program_id: String) -> Vec<String> {
-        if !env
-            .storage()
-            .instance()
-            .has(&DataKey::Program(program_id.clone()))
-        {
-            panic!("Program not found");
-        }
-        get_program_dependencies_internal(&env, &program_id)
-    }
-
-    /// Updates dependency status.
-    ///
-    /// For registered programs, only that program's authorized payout key can update status.
-    /// For external dependency ids, anti-abuse admin authorization is required.
-    pub fn set_dependency_status(env: Env, dependency_id: String, status: DependencyStatus) {
-        if dependency_id.len() == 0 {
-            panic!("Dependency id cannot be empty");
-        }
-
-        if env
-            .storage()
-            .instance()
-            .has(&DataKey::Program(dependency_id.clone()))
-        {
-            let program_data: ProgramData = env
-                .storage()
-                .instance()
-                .get(&DataKey::Program(dependency_id.clone()))
-                .unwrap();
-            program_data.authorized_payout_key.require_auth();
-        } else {
-            let admin = anti_abuse::get_admin(&env)
-                .unwrap_or_else(|| panic!("Admin not set for external dependency status update"));
-            admin.require_auth();
-        }
-
-        env.storage()
-            .instance()
-            .set(&DataKey::DependencyStatus(dependency_id.clone()), &status.clone());
-        env.events()
-            .publish((DEPENDENCY_STATUS_UPDATED,), (dependency_id, status));
-    }
-
-    /// Reads dependency status; defaults to pending if no explicit status exists.
-    pub fn get_dependency_status(env: Env, dependency_id: String) -> DependencyStatus {
-        dependency_status_internal(&env, &dependency_id)
-    }
-
     // ========================================================================
     // Fund Management
     // ========================================================================
@@ -1076,14 +620,6 @@ impl ProgramEscrowContract {
     /// * `amount` - Amount of funds to lock (in native token units)
     ///
     /// # Returns
-    /// * `ProgramData` - Updated program data with new balance
-
-    pub fn lock_program_funds(env: Env, program_id: String, amount: i128) -> ProgramData {
-        // Apply rate limiting
-        anti_abuse::check_rate_limit(&env, env.current_contract_address());
-
-        let caller = env.current_contract_address();
-    /// Updated ProgramData with locked funds
     pub fn lock_program_funds(env: Env, amount: i128) -> ProgramData {
         if Self::check_paused(&env, symbol_short!("lock")) {
             panic!("Funds Paused");
@@ -1293,36 +829,88 @@ impl ProgramEscrowContract {
 
     // --- Circuit Breaker & Rate Limit ---
 
+
+    /// Register the circuit breaker admin. Can only be set once, or changed
+    /// by the existing admin.
+    ///
+    /// # Arguments
+    /// * `new_admin` - Address to register as circuit breaker admin
+    /// * `caller`    - Existing admin (None if setting for the first time)
     pub fn set_circuit_admin(env: Env, new_admin: Address, caller: Option<Address>) {
         error_recovery::set_circuit_admin(&env, new_admin, caller);
     }
 
+    /// Returns the registered circuit breaker admin, if any.
     pub fn get_circuit_admin(env: Env) -> Option<Address> {
         error_recovery::get_circuit_admin(&env)
     }
 
-    pub fn reset_circuit_breaker(env: Env, caller: Address) {
-        caller.require_auth();
-        let admin = error_recovery::get_circuit_admin(&env).expect("Circuit admin not set");
-        if caller != admin {
-            panic!("Unauthorized: only circuit admin can reset");
-        }
+    /// Returns the full circuit breaker status snapshot.
+    ///
+    /// # Returns
+    /// * `CircuitBreakerStatus` with state, failure/success counts, timestamps
+    pub fn get_circuit_status(env: Env) -> error_recovery::CircuitBreakerStatus {
+        error_recovery::get_status(&env)
+    }
+
+    /// Admin resets the circuit breaker.
+    ///
+    /// Transitions:
+    /// - Open     → HalfOpen  (probe mode)
+    /// - HalfOpen → Closed    (hard reset)
+    /// - Closed   → Closed    (no-op reset)
+    ///
+    /// # Panics
+    /// * If caller is not the registered circuit breaker admin
+    pub fn reset_circuit_breaker(env: Env, admin: Address) {
         error_recovery::reset_circuit_breaker(&env, &admin);
     }
 
+    /// Updates the circuit breaker configuration. Admin only.
+    ///
+    /// # Arguments
+    /// * `failure_threshold` - Consecutive failures needed to open circuit
+    /// * `success_threshold` - Consecutive successes in HalfOpen to close it
+    /// * `max_error_log`     - Maximum error log entries to retain
     pub fn configure_circuit_breaker(
         env: Env,
-        caller: Address,
-        _threshold: u32,
-        _lookback: u32,
-        _cooldown: u32,
+        admin: Address,
+        failure_threshold: u32,
+        success_threshold: u32,
+        max_error_log: u32,
     ) {
-        caller.require_auth();
-        let admin = error_recovery::get_circuit_admin(&env).expect("Circuit admin not set");
-        if caller != admin {
-            panic!("Unauthorized: only circuit admin can configure");
+        let stored = error_recovery::get_circuit_admin(&env);
+        match stored {
+            Some(ref a) if a == &admin => {
+                admin.require_auth();
+            }
+            _ => panic!("Unauthorized: only circuit breaker admin can configure"),
+        }
+        error_recovery::set_config(
+            &env,
+            error_recovery::CircuitBreakerConfig {
+                failure_threshold,
+                success_threshold,
+                max_error_log,
+            },
+        );
+    }
+
+    /// Returns the error log (last N failures recorded by the circuit breaker).
+    pub fn get_circuit_error_log(env: Env) -> soroban_sdk::Vec<error_recovery::ErrorEntry> {
+        error_recovery::get_error_log(&env)
+    }
+
+    /// Directly open the circuit (emergency lockout). Admin only.
+    pub fn emergency_open_circuit(env: Env, admin: Address) {
+        let stored = error_recovery::get_circuit_admin(&env);
+        match stored {
+            Some(ref a) if a == &admin => {
+                admin.require_auth();
+            }
+            _ => panic!("Unauthorized"),
         }
-        // Logic to update config in storage would go here
+        error_recovery::open_circuit(&env);
     }
 
     pub fn update_rate_limit_config(
@@ -1397,10 +985,6 @@ impl ProgramEscrowContract {
             panic!("Funds Paused");
         }
 
-        Self::assert_dependencies_satisfied(&env, &program_id);
-
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
         // Verify authorization
         let program_data: ProgramData =
             env.storage()
@@ -1497,25 +1081,6 @@ impl ProgramEscrowContract {
     /// * `recipient` - Address of the recipient
     /// * `amount` - Amount to transfer
     ///
-    pub fn single_payout(
-        env: Env,
-        program_id: String,
-        recipient: Address,
-        amount: i128,
-    ) -> ProgramData {
-        // Get program data
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
-
-        Self::assert_dependencies_satisfied(&env, &program_id);
-
-        program_data.authorized_payout_key.require_auth();
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
     pub fn single_payout(env: Env, recipient: Address, amount: i128) -> ProgramData {
         // Reentrancy guard: Check and set
         reentrancy_guard::check_not_entered(&env);
@@ -1685,21 +1250,6 @@ impl ProgramEscrowContract {
         let mut schedules: Vec<ProgramReleaseSchedule> = env
             .storage()
             .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
-
-        Self::assert_dependencies_satisfied(&env, &program_id);
-
-        // Get schedule
-        if !env
-            .storage()
-            .persistent()
-            .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-        {
-            panic!("Schedule not found");
-        }
-
-        let mut schedule: ProgramReleaseSchedule = env
             .get(&SCHEDULES)
             .unwrap_or_else(|| Vec::new(&env));
         let mut release_history: Vec<ProgramReleaseHistory> = env
@@ -1742,60 +1292,6 @@ impl ProgramEscrowContract {
                 amount: schedule.amount,
                 released_at: now,
                 release_type: ReleaseType::Automatic,
-            },
-        );
-
-        // Track successful operation
-        monitoring::track_operation(&env, symbol_short!("rel_auto"), caller, true);
-
-        // Track performance
-        let duration = env.ledger().timestamp().saturating_sub(start);
-        monitoring::emit_performance(&env, symbol_short!("rel_auto"), duration);
-    }
-
-    /// Manually releases funds for a program schedule (authorized payout key only).
-    /// Can be called before the release timestamp by authorized key
-    pub fn release_program_schedule_manual(
-        env: Env,
-        program_id: String,
-        schedule_id: u64,
-    ) {
-        let start = env.ledger().timestamp();
-
-        // Get program data
-        let program_key = DataKey::Program(program_id.clone());
-        let program_data: ProgramData = env
-            .storage()
-            .instance()
-            .get(&program_key)
-            .unwrap_or_else(|| panic!("Program not found"));
-
-        Self::assert_dependencies_satisfied(&env, &program_id);
-
-        // Apply rate limiting to the authorized payout key
-        anti_abuse::check_rate_limit(&env, program_data.authorized_payout_key.clone());
-
-        // Verify authorization
-        program_data.authorized_payout_key.require_auth();
-
-        // Get schedule
-        if !env
-            .storage()
-            .persistent()
-            .has(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-        {
-            panic!("Schedule not found");
-        }
-
-        let mut schedule: ProgramReleaseSchedule = env
-            .storage()
-            .persistent()
-            .get(&DataKey::ReleaseSchedule(program_id.clone(), schedule_id))
-            .unwrap();
-
-        // Check if already released
-        if schedule.released {
-            panic!("Schedule already released");
             });
             released_count += 1;
         }
@@ -2186,26 +1682,6 @@ impl ProgramEscrowContract {
         total
     }
 
-    total
-}
-
-/// ============================================================================
-// Tests
-// ============================================================================
-
-#[cfg(test)]
-mod test {
-    use super::*;
-    use soroban_sdk::{
-        testutils::{Address as _, Ledger},
-        token, Address, Env, String,
-    };
-
-    // Test helper to create a mock token contract
-    fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::Client<'a> {
-        let token_contract = env.register_stellar_asset_contract_v2(admin.clone());
-        let token_address = token_contract.address();
-        token::Client::new(env, &token_address)
     pub fn get_program_count(env: Env) -> u32 {
         if env.storage().instance().has(&PROGRAM_DATA) {
             1
@@ -2342,223 +1818,6 @@ mod test {
         }
 
         env.storage().instance().set(&SCHEDULES, &schedules);
-
-    #[test]
-    #[should_panic(expected = "Program not found")]
-    fn test_get_nonexistent_program() {
-        let env = Env::default();
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
-
-        let prog_id = String::from_str(&env, "DoesNotExist");
-        client.get_program_info(&prog_id);
-    }
-
-    #[test]
-    fn test_dependency_gated_release_flow() {
-        let env = Env::default();
-        env.mock_all_auths();
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
-
-        let token_admin = Address::generate(&env);
-        let token_client = create_token_contract(&env, &token_admin);
-        let token_asset = token::StellarAssetClient::new(&env, &token_client.address);
-
-        let dep_backend = Address::generate(&env);
-        let target_backend = Address::generate(&env);
-        let dependency_program = String::from_str(&env, "dependency-program");
-        let target_program = String::from_str(&env, "target-program");
-        let winner = Address::generate(&env);
-        let amount = 10_000_000i128;
-
-        token_asset.mint(&token_admin, &amount);
-        token_client.transfer(&token_admin, &contract_id, &amount);
-
-        client.initialize_program(&dependency_program, &dep_backend, &token_client.address);
-        client.initialize_program(&target_program, &target_backend, &token_client.address);
-        client.lock_program_funds(&target_program, &amount);
-        client.create_program_release_schedule(&target_program, &amount, &1000, &winner);
-
-        let dependencies = soroban_sdk::vec![&env, dependency_program.clone()];
-        client.set_program_dependencies(&target_program, &dependencies);
-
-        env.ledger().set_timestamp(1001);
-        let blocked = client.try_release_prog_schedule_automatic(&target_program, &1);
-        assert!(blocked.is_err());
-
-        client.set_dependency_status(&dependency_program, &DependencyStatus::Completed);
-        client.release_prog_schedule_automatic(&target_program, &1);
-
-        let schedule = client.get_program_release_schedule(&target_program, &1);
-        assert!(schedule.released);
-    }
-
-    #[test]
-    #[should_panic(expected = "Dependency failed")]
-    fn test_dependency_failed_blocks_release() {
-        let env = Env::default();
-        env.mock_all_auths();
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
-
-        let token_admin = Address::generate(&env);
-        let token_client = create_token_contract(&env, &token_admin);
-        let token_asset = token::StellarAssetClient::new(&env, &token_client.address);
-
-        let dep_backend = Address::generate(&env);
-        let target_backend = Address::generate(&env);
-        let dependency_program = String::from_str(&env, "dependency-failed");
-        let target_program = String::from_str(&env, "target-failed");
-        let winner = Address::generate(&env);
-        let amount = 5_000_000i128;
-
-        token_asset.mint(&token_admin, &amount);
-        token_client.transfer(&token_admin, &contract_id, &amount);
-
-        client.initialize_program(&dependency_program, &dep_backend, &token_client.address);
-        client.initialize_program(&target_program, &target_backend, &token_client.address);
-        client.lock_program_funds(&target_program, &amount);
-        client.create_program_release_schedule(&target_program, &amount, &1000, &winner);
-        client.set_program_dependencies(
-            &target_program,
-            &soroban_sdk::vec![&env, dependency_program.clone()],
-        );
-
-        client.set_dependency_status(&dependency_program, &DependencyStatus::Failed);
-        env.ledger().set_timestamp(1001);
-        client.release_prog_schedule_automatic(&target_program, &1);
-    }
-
-    #[test]
-    #[should_panic(expected = "Dependency cycle detected")]
-    fn test_dependency_cycle_rejection() {
-        let env = Env::default();
-        env.mock_all_auths();
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
-
-        let backend_a = Address::generate(&env);
-        let backend_b = Address::generate(&env);
-        let token = Address::generate(&env);
-        let program_a = String::from_str(&env, "cycle-a");
-        let program_b = String::from_str(&env, "cycle-b");
-
-        client.initialize_program(&program_a, &backend_a, &token);
-        client.initialize_program(&program_b, &backend_b, &token);
-        client.set_program_dependencies(&program_a, &soroban_sdk::vec![&env, program_b.clone()]);
-        client.set_program_dependencies(&program_b, &soroban_sdk::vec![&env, program_a.clone()]);
-    }
-
-    #[test]
-    fn test_dependency_events_created_and_cleared() {
-        let env = Env::default();
-        env.mock_all_auths();
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
-
-        let backend_a = Address::generate(&env);
-        let backend_b = Address::generate(&env);
-        let token = Address::generate(&env);
-        let program_a = String::from_str(&env, "event-a");
-        let program_b = String::from_str(&env, "event-b");
-
-        client.initialize_program(&program_a, &backend_a, &token);
-        client.initialize_program(&program_b, &backend_b, &token);
-
-        client.set_program_dependencies(&program_a, &soroban_sdk::vec![&env, program_b.clone()]);
-        let dependencies = client.get_program_dependencies(&program_a);
-        assert_eq!(dependencies.len(), 1);
-
-        client.clear_program_dependencies(&program_a);
-        let cleared_dependencies = client.get_program_dependencies(&program_a);
-        assert_eq!(cleared_dependencies.len(), 0);
-    }
-
-    // ========================================================================
-    // Fund Locking Tests
-    // ========================================================================
-
-    #[test]
-    fn test_lock_funds_single_program() {
-        let env = Env::default();
-        env.mock_all_auths();
-
-        let admin = Address::generate(&env);
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
-        let token_client = create_token_contract(&env, &admin);
-
-        let backend = Address::generate(&env);
-        let prog_id = String::from_str(&env, "Hackathon2024");
-
-        // Register program
-        client.initialize_program(&prog_id, &backend, &token_client.address);
-
-        // Lock funds
-        let amount = 10_000_0000000i128; // 10,000 USDC
-        let updated = client.lock_program_funds(&prog_id, &amount);
-
-        assert_eq!(updated.total_funds, amount);
-        assert_eq!(updated.remaining_balance, amount);
-    }
-
-    #[test]
-    fn test_lock_funds_multiple_programs_isolation() {
-        let env = Env::default();
-        env.mock_all_auths();
-
-        let admin = Address::generate(&env);
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
-        let token_client = create_token_contract(&env, &admin);
-
-        let backend1 = Address::generate(&env);
-        let backend2 = Address::generate(&env);
-
-        let prog1 = String::from_str(&env, "Program1");
-        let prog2 = String::from_str(&env, "Program2");
-
-        // Register programs
-        client.initialize_program(&prog1, &backend1, &token_client.address);
-        client.initialize_program(&prog2, &backend2, &token_client.address);
-
-        // Lock different amounts in each program
-        let amount1 = 5_000_0000000i128;
-        let amount2 = 10_000_0000000i128;
-
-        client.lock_program_funds(&prog1, &amount1);
-        client.lock_program_funds(&prog2, &amount2);
-
-        // Verify isolation - funds don't mix
-        let info1 = client.get_program_info(&prog1);
-        let info2 = client.get_program_info(&prog2);
-
-        assert_eq!(info1.total_funds, amount1);
-        assert_eq!(info1.remaining_balance, amount1);
-        assert_eq!(info2.total_funds, amount2);
-        assert_eq!(info2.remaining_balance, amount2);
-    }
-
-    #[test]
-    fn test_lock_funds_cumulative() {
-        let env = Env::default();
-        env.mock_all_auths();
-
-        let admin = Address::generate(&env);
-        let contract_id = env.register_contract(None, ProgramEscrowContract);
-        let client = ProgramEscrowContractClient::new(&env, &contract_id);
-        let token_client = create_token_contract(&env, &admin);
-
-        let backend = Address::generate(&env);
-        let prog_id = String::from_str(&env, "Hackathon2024");
-
-        client.initialize_program(&prog_id, &backend, &token_client.address);
-
-        // Lock funds multiple times
-        client.lock_program_funds(&prog_id, &1_000_0000000);
-        client.lock_program_funds(&prog_id, &2_000_0000000);
-        client.lock_program_funds(&prog_id, &3_000_0000000);
         // Write to release history
         if let Some(s) = released_schedule {
             let mut updated_program_data = program_data.clone();
@@ -2614,6 +1873,78 @@ mod test {
     }
 }
 
+#[contractimpl]
+impl EscrowInterface for ProgramEscrowContract {
+    /// True for the shared escrow interface and the program escrow interface.
+    fn supports_interface(_env: Env, interface_id: Symbol) -> bool {
+        escrow_interface::supports(
+            &interface_id,
+            &escrow_interface::PROGRAM_ESCROW_INTERFACE_ID,
+        )
+    }
+
+    fn interface_version(_env: Env) -> u32 {
+        escrow_interface::ESCROW_INTERFACE_VERSION
+    }
+
+    fn get_version(_env: Env) -> u32 {
+        CONTRACT_VERSION
+    }
+
+    /// The single program's token, else that of the first registered program.
+    fn escrow_token(env: Env) -> Option<Address> {
+        env.storage()
+            .instance()
+            .get::<_, ProgramData>(&PROGRAM_DATA)
+            .or_else(|| registered_programs(&env).first())
+            .map(|program_data| program_data.token_address)
+    }
+
+    fn escrow_balance(env: Env) -> i128 {
+        match Self::escrow_token(env.clone()) {
+            Some(token_address) => {
+                token::Client::new(&env, &token_address).balance(&env.current_contract_address())
+            }
+            None => 0,
+        }
+    }
+
+    /// The `remaining_balance` still owed to winners, summed over the single
+    /// program and every program registered by `batch_initialize_programs`.
+    fn escrow_liabilities(env: Env) -> i128 {
+        let single = env
+            .storage()
+            .instance()
+            .get::<_, ProgramData>(&PROGRAM_DATA)
+            .map_or(0, |program_data| program_data.remaining_balance);
+        registered_programs(&env)
+            .iter()
+            .fold(single, |total, program_data| {
+                total + program_data.remaining_balance
+            })
+    }
+}
+
+/// Programs registered by `batch_initialize_programs`, in registry order.
+fn registered_programs(env: &Env) -> Vec<ProgramData> {
+    let registry: Vec<String> = env
+        .storage()
+        .instance()
+        .get(&PROGRAM_REGISTRY)
+        .unwrap_or(vec![env]);
+    let mut programs = Vec::new(env);
+    for program_id in registry.iter() {
+        if let Some(program_data) = env
+            .storage()
+            .instance()
+            .get::<_, ProgramData>(&DataKey::Program(program_id))
+        {
+            programs.push_back(program_data);
+        }
+    }
+    programs
+}
+
 #[cfg(test)]
 mod test;
 
@@ -2622,4 +1953,4 @@ mod test_pause;
 
 #[cfg(test)]
 #[cfg(any())]
-mod rbac_tests;
+mod rbac_tests;
\ No newline at end of file
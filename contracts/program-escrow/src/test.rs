@@ -2181,3 +2181,59 @@ fn test_release_schedules_persist_after_simulated_upgrade() {
     assert_eq!(stats_final.scheduled_count, 0);
     assert_eq!(stats_final.remaining_balance, 100_000);
 }
+
+#[test]
+fn test_escrow_interface_discovery_and_views() {
+    let env = Env::default();
+    let (client, _admin, token_client, _token_admin) = setup_program(&env, 50_000);
+    let escrow = grainlify_core::escrow_interface::EscrowClient::new(&env, &client.address);
+
+    assert!(escrow.supports_interface(&symbol_short!("escrow")));
+    assert!(escrow.supports_interface(&symbol_short!("program")));
+    assert!(!escrow.supports_interface(&symbol_short!("bounty")));
+    assert_eq!(escrow.interface_version(), 1);
+    assert_eq!(escrow.get_version(), 1);
+    assert_eq!(escrow.escrow_token(), Some(token_client.address.clone()));
+    assert_eq!(escrow.escrow_balance(), 50_000);
+    assert_eq!(escrow.escrow_liabilities(), 50_000);
+
+    let winner = Address::generate(&env);
+    client.single_payout(&winner, &20_000);
+    assert_eq!(escrow.escrow_balance(), 30_000);
+    assert_eq!(escrow.escrow_liabilities(), 30_000);
+}
+
+#[test]
+fn test_escrow_views_cover_registered_programs() {
+    let env = Env::default();
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, ProgramEscrowContract);
+    let client = ProgramEscrowContractClient::new(&env, &contract_id);
+    let escrow = grainlify_core::escrow_interface::EscrowClient::new(&env, &contract_id);
+    let payout_key = Address::generate(&env);
+    let token = Address::generate(&env);
+    assert_eq!(escrow.escrow_token(), None);
+
+    let mut items = Vec::new(&env);
+    for id in ["prog-1", "prog-2"] {
+        items.push_back(ProgramInitItem {
+            program_id: String::from_str(&env, id),
+            authorized_payout_key: payout_key.clone(),
+            token_address: token.clone(),
+        });
+    }
+    client.batch_initialize_programs(&items);
+    assert_eq!(escrow.escrow_token(), Some(token));
+    assert_eq!(escrow.escrow_liabilities(), 0);
+
+    // Balances owed by registered programs count alongside each other.
+    env.as_contract(&contract_id, || {
+        for (id, owed) in [("prog-1", 3_000i128), ("prog-2", 4_500)] {
+            let key = DataKey::Program(String::from_str(&env, id));
+            let mut program: ProgramData = env.storage().instance().get(&key).unwrap();
+            program.remaining_balance = owed;
+            env.storage().instance().set(&key, &program);
+        }
+    });
+    assert_eq!(escrow.escrow_liabilities(), 7_500);
+}
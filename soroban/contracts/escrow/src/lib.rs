@@ -2,7 +2,7 @@
 //! Minimal Soroban escrow demo: lock, release, and refund.
 //! Parity with main contracts/bounty_escrow where applicable; see soroban/PARITY.md.
 
-use soroban_sdk::{contract, contracterror, contractimpl, contracttype, token, Address, Env};
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, token, Address, Env, Vec};
 
 mod reentrancy_guard;
 
@@ -26,6 +26,9 @@ pub enum EscrowStatus {
     Locked,
     Released,
     Refunded,
+    /// Moved to contracts/bounty_escrow by `mark_migrated`; the funds left
+    /// with it and it can no longer be released or refunded here.
+    Migrated,
 }
 
 #[contracttype]
@@ -38,12 +41,42 @@ pub struct Escrow {
     pub deadline: u64,
 }
 
+/// Escrow with its bounty id, as returned by `export_all`. Field and variant
+/// names match `LegacyEscrow` in contracts/bounty_escrow, which imports it.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExportedEscrow {
+    pub bounty_id: u64,
+    pub depositor: Address,
+    pub amount: i128,
+    pub remaining_amount: i128,
+    pub status: EscrowStatus,
+    pub deadline: u64,
+}
+
+/// One page of `export_all`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ExportPage {
+    pub escrows: Vec<ExportedEscrow>,
+    /// Cursor for the following call, or `None` when the index is exhausted.
+    pub next_cursor: Option<u32>,
+}
+
+/// Upper bound on index positions read by one `export_all` call. Matches the
+/// batch size `import_escrows` accepts in contracts/bounty_escrow.
+pub const MAX_EXPORT_LIMIT: u32 = 20;
+
 #[contracttype]
 pub enum DataKey {
     Admin,
     Token,
     Escrow(u64),
     ReentrancyGuard,
+    /// Bounty ids in index order, for `export_all`.
+    EscrowIndex,
+    /// Marks a bounty id as present in `EscrowIndex`.
+    Indexed(u64),
 }
 
 #[contract]
@@ -98,6 +131,7 @@ impl EscrowContract {
         env.storage()
             .persistent()
             .set(&DataKey::Escrow(bounty_id), &escrow);
+        Self::index_escrow(&env, bounty_id);
 
         // INTERACTION: external token transfer is last
         let token = env
@@ -224,6 +258,150 @@ impl EscrowContract {
             .get(&DataKey::Escrow(bounty_id))
             .ok_or(Error::BountyNotFound)
     }
+
+    /// Migration step for escrows locked before `export_all` existed: add
+    /// `bounty_ids` to the export index (admin only). Ids already indexed are
+    /// skipped, so overlapping batches are harmless. Fails with
+    /// `BountyNotFound` if any id has no escrow.
+    ///
+    /// Returns the number of ids added.
+    pub fn index_escrows(env: Env, bounty_ids: Vec<u64>) -> Result<u32, Error> {
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        let mut added = 0;
+        for bounty_id in bounty_ids.iter() {
+            if !env.storage().persistent().has(&DataKey::Escrow(bounty_id)) {
+                return Err(Error::BountyNotFound);
+            }
+            if Self::index_escrow(&env, bounty_id) {
+                added += 1;
+            }
+        }
+        Ok(added)
+    }
+
+    /// Close `bounty_ids` after their import into contracts/bounty_escrow
+    /// (admin only): each must be `Locked`, becomes `Migrated` with nothing
+    /// remaining, and its remaining amount is paid to `to`, which funds the
+    /// import. From then on it cannot be released or refunded here.
+    /// `import_escrows` there calls this in the same transaction.
+    ///
+    /// Returns the total paid to `to`.
+    ///
+    /// # Reentrancy
+    /// Protected by reentrancy guard. Every escrow is updated before the
+    /// outbound token transfer (CEI pattern).
+    pub fn mark_migrated(env: Env, bounty_ids: Vec<u64>, to: Address) -> Result<i128, Error> {
+        // GUARD: acquire reentrancy lock
+        reentrancy_guard::acquire(&env);
+
+        let admin: Address = env
+            .storage()
+            .instance()
+            .get(&DataKey::Admin)
+            .ok_or(Error::NotInitialized)?;
+        admin.require_auth();
+
+        // EFFECTS: close every escrow before external call (CEI)
+        let mut total: i128 = 0;
+        for bounty_id in bounty_ids.iter() {
+            let mut escrow: Escrow = env
+                .storage()
+                .persistent()
+                .get(&DataKey::Escrow(bounty_id))
+                .ok_or(Error::BountyNotFound)?;
+            // A repeated id finds its escrow already migrated.
+            if escrow.status != EscrowStatus::Locked {
+                return Err(Error::FundsNotLocked);
+            }
+            total += escrow.remaining_amount;
+            escrow.remaining_amount = 0;
+            escrow.status = EscrowStatus::Migrated;
+            env.storage()
+                .persistent()
+                .set(&DataKey::Escrow(bounty_id), &escrow);
+        }
+
+        // INTERACTION: external token transfer is last
+        if total > 0 {
+            let token = env
+                .storage()
+                .instance()
+                .get::<_, Address>(&DataKey::Token)
+                .unwrap();
+            let contract = env.current_contract_address();
+            let token_client = token::Client::new(&env, &token);
+            token_client.transfer(&contract, &to, &total);
+        }
+
+        // GUARD: release reentrancy lock
+        reentrancy_guard::release(&env);
+        Ok(total)
+    }
+
+    /// Escrows at positions `cursor..cursor + limit` of the export index, for
+    /// migrating to contracts/bounty_escrow. `limit` is capped at
+    /// `MAX_EXPORT_LIMIT`; resume with `next_cursor` until it is `None`.
+    /// Feed the `Locked` entries of each page to its `import_escrows`.
+    pub fn export_all(env: Env, cursor: u32, limit: u32) -> ExportPage {
+        let limit = limit.min(MAX_EXPORT_LIMIT);
+        let index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(&env));
+
+        let end = index.len().min(cursor.saturating_add(limit));
+        let mut escrows = Vec::new(&env);
+        for i in cursor..end {
+            let bounty_id = index.get(i).unwrap();
+            let escrow: Option<Escrow> =
+                env.storage().persistent().get(&DataKey::Escrow(bounty_id));
+            // An index entry without an escrow has nothing to migrate.
+            if let Some(escrow) = escrow {
+                escrows.push_back(ExportedEscrow {
+                    bounty_id,
+                    depositor: escrow.depositor,
+                    amount: escrow.amount,
+                    remaining_amount: escrow.remaining_amount,
+                    status: escrow.status,
+                    deadline: escrow.deadline,
+                });
+            }
+        }
+
+        ExportPage {
+            escrows,
+            next_cursor: if end < index.len() { Some(end) } else { None },
+        }
+    }
+
+    /// Append `bounty_id` to the export index unless it is already there.
+    /// Returns whether it was added.
+    fn index_escrow(env: &Env, bounty_id: u64) -> bool {
+        let marker = DataKey::Indexed(bounty_id);
+        if env.storage().persistent().has(&marker) {
+            return false;
+        }
+        let mut index: Vec<u64> = env
+            .storage()
+            .persistent()
+            .get(&DataKey::EscrowIndex)
+            .unwrap_or(Vec::new(env));
+        index.push_back(bounty_id);
+        env.storage()
+            .persistent()
+            .set(&DataKey::EscrowIndex, &index);
+        env.storage().persistent().set(&marker, &true);
+        true
+    }
 }
 
 mod test;
+#[cfg(feature = "bounty-escrow-import")]
+mod test_bounty_escrow_import;
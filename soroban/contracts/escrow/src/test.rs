@@ -4,7 +4,7 @@
 
 use super::*;
 use soroban_sdk::testutils::{Address as _, Ledger};
-use soroban_sdk::{token, Address, Env};
+use soroban_sdk::{token, vec, Address, Env};
 
 fn create_token<'a>(
     env: &'a Env,
@@ -156,3 +156,153 @@ fn parity_refund_before_deadline_fails() {
     let res = client.try_refund(&bounty_id);
     assert!(res.is_err());
 }
+
+// --- Migration: export_all lists every escrow with its bounty id ---
+#[test]
+fn export_all_lists_escrows_in_lock_order() {
+    let env = Env::default();
+    let amount = 10_000i128;
+    let (client, _cid, _admin, depositor, contributor, _token_client) = setup(&env, amount);
+
+    let empty = client.export_all(&0, &10);
+    assert_eq!(empty.escrows.len(), 0);
+    assert_eq!(empty.next_cursor, None);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &7, &4_000, &deadline);
+    client.lock_funds(&depositor, &3, &6_000, &(deadline + 500));
+    client.release_funds(&7, &contributor);
+
+    let page = client.export_all(&0, &10);
+    assert_eq!(page.next_cursor, None);
+    let exported = page.escrows;
+    assert_eq!(exported.len(), 2);
+
+    let released = exported.get(0).unwrap();
+    assert_eq!(released.bounty_id, 7);
+    assert_eq!(released.status, EscrowStatus::Released);
+    assert_eq!(released.remaining_amount, 0);
+
+    let locked = exported.get(1).unwrap();
+    assert_eq!(
+        locked,
+        ExportedEscrow {
+            bounty_id: 3,
+            depositor: depositor.clone(),
+            amount: 6_000,
+            remaining_amount: 6_000,
+            status: EscrowStatus::Locked,
+            deadline: deadline + 500,
+        }
+    );
+}
+
+// --- Migration: export_all is paged and capped ---
+#[test]
+fn export_all_pages_through_the_index() {
+    let env = Env::default();
+    let (client, _cid, _admin, depositor, _contributor, _token_client) = setup(&env, 100_000);
+    let deadline = env.ledger().timestamp() + 1000;
+    for bounty_id in 1..=25u64 {
+        client.lock_funds(&depositor, &bounty_id, &1_000, &deadline);
+    }
+
+    let first = client.export_all(&0, &u32::MAX);
+    assert_eq!(first.escrows.len(), MAX_EXPORT_LIMIT);
+    assert_eq!(first.next_cursor, Some(MAX_EXPORT_LIMIT));
+
+    let second = client.export_all(&first.next_cursor.unwrap(), &10);
+    assert_eq!(second.escrows.len(), 5);
+    assert_eq!(second.escrows.get(0).unwrap().bounty_id, 21);
+    assert_eq!(second.next_cursor, None);
+
+    assert_eq!(client.export_all(&30, &10).escrows.len(), 0);
+}
+
+// --- Migration: escrows locked before the index existed ---
+#[test]
+fn index_escrows_adds_pre_index_escrows_once() {
+    let env = Env::default();
+    let (client, contract_id, _admin, depositor, _contributor, _token_client) = setup(&env, 10_000);
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+
+    // An escrow written by the contract before `EscrowIndex` was added.
+    let old = Escrow {
+        depositor: depositor.clone(),
+        amount: 2_000,
+        remaining_amount: 2_000,
+        status: EscrowStatus::Locked,
+        deadline,
+    };
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().set(&DataKey::Escrow(9), &old);
+    });
+    assert_eq!(client.export_all(&0, &10).escrows.len(), 1);
+
+    assert_eq!(client.index_escrows(&vec![&env, 9u64, 1u64]), 1);
+    assert_eq!(client.index_escrows(&vec![&env, 9u64]), 0);
+    assert_eq!(
+        client.try_index_escrows(&vec![&env, 4u64]),
+        Err(Ok(Error::BountyNotFound))
+    );
+
+    let exported = client.export_all(&0, &10).escrows;
+    assert_eq!(exported.len(), 2);
+    assert_eq!(exported.get(1).unwrap().bounty_id, 9);
+    assert_eq!(exported.get(1).unwrap().amount, 2_000);
+}
+
+// --- Migration: index entries without an escrow are skipped ---
+#[test]
+fn export_all_skips_missing_escrows() {
+    let env = Env::default();
+    let (client, contract_id, _admin, depositor, _contributor, _token_client) = setup(&env, 10_000);
+    let deadline = env.ledger().timestamp() + 1000;
+    client.lock_funds(&depositor, &1, &1_000, &deadline);
+    client.lock_funds(&depositor, &2, &1_000, &deadline);
+    env.as_contract(&contract_id, || {
+        env.storage().persistent().remove(&DataKey::Escrow(1));
+    });
+
+    let page = client.export_all(&0, &10);
+    assert_eq!(page.escrows.len(), 1);
+    assert_eq!(page.escrows.get(0).unwrap().bounty_id, 2);
+    assert_eq!(page.next_cursor, None);
+}
+
+// --- Migration: migrated escrows leave with their funds ---
+#[test]
+fn mark_migrated_closes_escrows_and_pays_out() {
+    let env = Env::default();
+    let (client, contract_id, _admin, depositor, contributor, token_client) = setup(&env, 10_000);
+    let funder = Address::generate(&env);
+    let deadline = env.ledger().timestamp() + 10;
+    client.lock_funds(&depositor, &1, &4_000, &deadline);
+    client.lock_funds(&depositor, &2, &3_000, &deadline);
+
+    assert_eq!(
+        client.mark_migrated(&vec![&env, 1u64, 2u64], &funder),
+        7_000
+    );
+    assert_eq!(token_client.balance(&funder), 7_000);
+    assert_eq!(token_client.balance(&contract_id), 0);
+    let escrow = client.get_escrow(&1);
+    assert_eq!(escrow.status, EscrowStatus::Migrated);
+    assert_eq!(escrow.remaining_amount, 0);
+
+    env.ledger().set_timestamp(deadline + 1);
+    assert_eq!(client.try_refund(&1), Err(Ok(Error::FundsNotLocked)));
+    assert_eq!(
+        client.try_release_funds(&2, &contributor),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(
+        client.try_mark_migrated(&vec![&env, 1u64], &funder),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(
+        client.try_mark_migrated(&vec![&env, 5u64], &funder),
+        Err(Ok(Error::BountyNotFound))
+    );
+}
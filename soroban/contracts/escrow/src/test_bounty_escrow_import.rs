@@ -0,0 +1,129 @@
+#![cfg(test)]
+//! End-to-end migration: escrows exported by this contract are imported,
+//! unchanged, by the contracts/bounty_escrow WASM. Needs that WASM built
+//! first; run with `make test-import`.
+
+use super::*;
+use soroban_sdk::testutils::{Address as _, Ledger};
+use soroban_sdk::{token, vec, Address, Env, IntoVal, Symbol};
+
+mod bounty_escrow {
+    soroban_sdk::contractimport!(
+        file = "../../../contracts/bounty_escrow/target/wasm32v1-none/release/bounty_escrow.wasm"
+    );
+}
+
+#[test]
+fn exported_escrows_import_into_bounty_escrow() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let depositor = Address::generate(&env);
+    let contributor = Address::generate(&env);
+    let token_id = env
+        .register_stellar_asset_contract_v2(admin.clone())
+        .address();
+    let token_client = token::Client::new(&env, &token_id);
+    let token_admin = token::StellarAssetClient::new(&env, &token_id);
+    token_admin.mint(&depositor, &10_000);
+    token_admin.mint(&admin, &10_000);
+
+    let legacy_id = env.register(EscrowContract, ());
+    let legacy = EscrowContractClient::new(&env, &legacy_id);
+    legacy.init(&admin, &token_id);
+
+    let deadline = env.ledger().timestamp() + 1000;
+    legacy.lock_funds(&depositor, &1, &4_000, &deadline);
+    legacy.lock_funds(&depositor, &2, &3_000, &deadline);
+    legacy.lock_funds(&depositor, &3, &2_000, &(deadline + 500));
+    legacy.release_funds(&2, &contributor);
+
+    // An escrow from before the export index, indexed by the migration step.
+    let old = Escrow {
+        depositor: depositor.clone(),
+        amount: 1_000,
+        remaining_amount: 1_000,
+        status: EscrowStatus::Locked,
+        deadline,
+    };
+    env.as_contract(&legacy_id, || {
+        env.storage().persistent().set(&DataKey::Escrow(9), &old);
+    });
+    // Its deposit, held by the contract since before the index.
+    token_admin.mint(&legacy_id, &1_000);
+    legacy.index_escrows(&vec![&env, 9u64]);
+
+    let target_id = env.register(bounty_escrow::WASM, ());
+    let target = bounty_escrow::Client::new(&env, &target_id);
+    target.init(&admin, &token_id);
+    // Pausing new locks must not stall the migration.
+    target.set_paused(&Some(true), &None, &None, &None);
+
+    let mut imported = 0u32;
+    let mut cursor = Some(0u32);
+    while let Some(at) = cursor {
+        let page = legacy.export_all(&at, &2);
+        let mut locked = Vec::new(&env);
+        for escrow in page.escrows.iter() {
+            if escrow.status == EscrowStatus::Locked {
+                locked.push_back(escrow);
+            }
+        }
+        if !locked.is_empty() {
+            // Passed through as raw values, as a migration script would.
+            imported += env.invoke_contract::<u32>(
+                &target_id,
+                &Symbol::new(&env, "import_escrows"),
+                vec![
+                    &env,
+                    legacy_id.into_val(&env),
+                    admin.into_val(&env),
+                    locked.into_val(&env),
+                ],
+            );
+        }
+        cursor = page.next_cursor;
+    }
+    assert_eq!(imported, 3);
+
+    for (bounty_id, amount, bounty_deadline) in [
+        (1u64, 4_000i128, deadline),
+        (3, 2_000, deadline + 500),
+        (9, 1_000, deadline),
+    ] {
+        let escrow = target.get_escrow_info(&bounty_id);
+        assert_eq!(escrow.depositor, depositor);
+        assert_eq!(escrow.amount, amount);
+        assert_eq!(escrow.remaining_amount, amount);
+        assert_eq!(escrow.deadline, bounty_deadline);
+        assert_eq!(escrow.status, bounty_escrow::EscrowStatus::Locked);
+        assert_eq!(
+            target.get_import_record(&bounty_id).unwrap().source,
+            legacy_id
+        );
+    }
+    assert!(target.try_get_escrow_info(&2).is_err());
+    // The legacy contract paid the admin what the admin paid in.
+    assert_eq!(token_client.balance(&target_id), 7_000);
+    assert_eq!(token_client.balance(&legacy_id), 0);
+    assert_eq!(token_client.balance(&admin), 10_000);
+
+    for bounty_id in [1u64, 3, 9] {
+        let closed = legacy.get_escrow(&bounty_id);
+        assert_eq!(closed.status, EscrowStatus::Migrated);
+        assert_eq!(closed.remaining_amount, 0);
+    }
+
+    // Past the deadline the depositor is refunded once, by the new contract;
+    // the legacy escrow is closed and cannot pay out a second time.
+    env.ledger().set_timestamp(deadline + 1);
+    target.refund(&1);
+    assert_eq!(token_client.balance(&depositor), 1_000 + 4_000);
+    assert_eq!(legacy.try_refund(&1), Err(Ok(Error::FundsNotLocked)));
+    assert_eq!(
+        legacy.try_release_funds(&3, &contributor),
+        Err(Ok(Error::FundsNotLocked))
+    );
+    assert_eq!(token_client.balance(&depositor), 5_000);
+}